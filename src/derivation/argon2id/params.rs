@@ -25,11 +25,16 @@ impl Argon2Params {
             return Err(Argon2ParamError::TooFewPasses);
         }
 
-        if self.mem_kib < 8 * self.lanes {
+        // Lanes come from untrusted headers, so the products are computed
+        // in 64 bits to avoid overflow.
+        let lanes = u64::from(self.lanes);
+        let mem_kib = u64::from(self.mem_kib);
+
+        if mem_kib < 8 * lanes {
             return Err(Argon2ParamError::MemoryTooSmall);
         }
 
-        if !self.mem_kib.is_multiple_of(2 * self.lanes) {
+        if !mem_kib.is_multiple_of(2 * lanes) {
             return Err(Argon2ParamError::MemoryNotMultipleOfLanes);
         }
 
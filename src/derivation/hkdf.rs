@@ -0,0 +1,104 @@
+//! HKDF-SHA256 key derivation (RFC 5869).
+//!
+//! HKDF derives cryptographically strong keys from input keying material
+//! that is already high-entropy but possibly non-uniform (e.g. a
+//! Diffie–Hellman shared secret). It follows an *extract-then-expand*
+//! design:
+//!
+//! - `extract`: condenses the input into a fixed-size pseudorandom key
+//! - `expand`: stretches that key into any number of output bytes,
+//!   bound to a context string (`info`)
+//!
+//! Unlike Argon2id, HKDF is **fast** and must never be used directly on
+//! passwords or other low-entropy secrets.
+
+use crate::hash::hmac_sha256;
+
+/// Maximum HKDF-SHA256 output length (255 blocks of 32 bytes).
+pub const HKDF_MAX_OUTPUT: usize = 255 * 32;
+
+/// Errors that can occur during HKDF expansion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HkdfError {
+    /// The requested output exceeds 255 × 32 bytes.
+    OutputTooLong,
+}
+
+/// HKDF-Extract: derives a pseudorandom key from input keying material.
+///
+/// # Parameters
+///
+/// - `salt`: Optional non-secret random value (may be empty)
+/// - `ikm`: Input keying material
+///
+/// # Returns
+///
+/// A 32-byte pseudorandom key (`PRK`).
+pub fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; 32] {
+    hmac_sha256(salt, ikm)
+}
+
+/// HKDF-Expand: expands a pseudorandom key into output keying material.
+///
+/// # Parameters
+///
+/// - `prk`: Pseudorandom key, usually the output of [`hkdf_extract`]
+/// - `info`: Context and application-specific information (may be empty)
+/// - `len`: Number of output bytes
+///
+/// # Returns
+///
+/// - `Ok(okm)` with `len` bytes of output keying material
+/// - `Err(OutputTooLong)` if `len` exceeds [`HKDF_MAX_OUTPUT`]
+pub fn hkdf_expand(prk: &[u8], info: &[u8], len: usize) -> Result<Vec<u8>, HkdfError> {
+    if len > HKDF_MAX_OUTPUT {
+        return Err(HkdfError::OutputTooLong);
+    }
+
+    let mut okm = Vec::with_capacity(len);
+    let mut input = Vec::with_capacity(32 + info.len() + 1);
+    let mut block = [0u8; 32];
+    let mut counter = 1u8;
+
+    while okm.len() < len {
+        // T(i) = HMAC(PRK, T(i - 1) || info || i)
+        input.clear();
+        if counter > 1 {
+            input.extend_from_slice(&block);
+        }
+        input.extend_from_slice(info);
+        input.push(counter);
+
+        block = hmac_sha256(prk, &input);
+
+        let take = (len - okm.len()).min(32);
+        okm.extend_from_slice(&block[..take]);
+        counter = counter.wrapping_add(1);
+    }
+
+    input.fill(0);
+    block.fill(0);
+
+    Ok(okm)
+}
+
+/// Full HKDF: extract followed by expand.
+///
+/// # Parameters
+///
+/// - `salt`: Optional non-secret random value (may be empty)
+/// - `ikm`: Input keying material
+/// - `info`: Context and application-specific information (may be empty)
+/// - `len`: Number of output bytes
+///
+/// # Returns
+///
+/// - `Ok(okm)` with `len` bytes of output keying material
+/// - `Err(OutputTooLong)` if `len` exceeds [`HKDF_MAX_OUTPUT`]
+pub fn hkdf(salt: &[u8], ikm: &[u8], info: &[u8], len: usize) -> Result<Vec<u8>, HkdfError> {
+    let mut prk = hkdf_extract(salt, ikm);
+    let okm = hkdf_expand(&prk, info, len);
+    prk.fill(0);
+
+    okm
+}
@@ -1,4 +1,8 @@
 pub mod argon2id;
+pub mod hkdf;
+pub mod scrypt;
 
 pub use argon2id::core::{Argon2Error, argon2id};
 pub use argon2id::params::{Argon2ParamError, Argon2Params};
+pub use hkdf::{HKDF_MAX_OUTPUT, HkdfError, hkdf, hkdf_expand, hkdf_extract};
+pub use scrypt::{ScryptError, ScryptParams, scrypt};
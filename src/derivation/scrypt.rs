@@ -0,0 +1,209 @@
+//! scrypt password-based key derivation (RFC 7914).
+//!
+//! scrypt is a memory-hard KDF built from three layers:
+//!
+//! - `PBKDF2-HMAC-SHA256` with a single iteration, to expand the password
+//!   into `p` independent blocks and to compress the result
+//! - `ROMix`, which fills and then randomly reads a table of `N` blocks
+//! - `BlockMix`, a sponge-like mixing function over the Salsa20/8 core
+//!
+//! It is provided for compatibility with existing formats (such as age
+//! passphrase recipients). New designs in this crate should prefer
+//! Argon2id.
+//!
+//! ## Notes
+//!
+//! - Memory usage is `128 · r · N` bytes per lane, allocated up front.
+//! - `ROMix` reads the table at password-dependent indices, so scrypt is
+//!   not resistant to cache-timing side channels. This is inherent to the
+//!   algorithm.
+
+use crate::hash::hmac_sha256;
+use crate::rng::chacha20::salsa20_rounds;
+
+/// scrypt cost parameters.
+#[derive(Clone, Debug)]
+pub struct ScryptParams {
+    /// Base-2 logarithm of the CPU/memory cost `N`.
+    pub log_n: u8,
+
+    /// Block size parameter.
+    pub r: u32,
+
+    /// Parallelization parameter.
+    pub p: u32,
+
+    /// Output length, in bytes.
+    pub len: usize,
+}
+
+/// Errors that can occur during scrypt derivation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScryptError {
+    /// The parameters violate RFC 7914 or exceed addressable memory.
+    InvalidParams,
+}
+
+impl ScryptParams {
+    /// Checks the RFC 7914 constraints and that the table fits in memory.
+    fn validate(&self) -> Result<(), ScryptError> {
+        let r = self.r as u64;
+        let p = self.p as u64;
+
+        if self.log_n == 0 || self.r == 0 || self.p == 0 || self.len == 0 {
+            return Err(ScryptError::InvalidParams);
+        }
+
+        // N < 2^(128 · r / 8) and r · p < 2^30.
+        if self.log_n as u64 >= 16 * r || r * p >= 1 << 30 {
+            return Err(ScryptError::InvalidParams);
+        }
+
+        if self.len as u64 > (u32::MAX as u64) * 32 {
+            return Err(ScryptError::InvalidParams);
+        }
+
+        // 128 · r · N bytes must be addressable.
+        if self.log_n >= 64 || 128 * r * p > isize::MAX as u64 {
+            return Err(ScryptError::InvalidParams);
+        }
+
+        let table = 128u128 * r as u128 * (1u128 << self.log_n);
+        if table > isize::MAX as u128 {
+            return Err(ScryptError::InvalidParams);
+        }
+
+        Ok(())
+    }
+}
+
+/// Derives a key from a password with scrypt.
+///
+/// # Parameters
+///
+/// - `password`: Secret input
+/// - `salt`: Salt (may be empty, but should be random and unique)
+/// - `params`: Cost parameters and output length
+///
+/// # Returns
+///
+/// - `Ok(key)` with `params.len` bytes
+/// - `Err(InvalidParams)` if the parameters are out of range
+pub fn scrypt(password: &[u8], salt: &[u8], params: &ScryptParams) -> Result<Vec<u8>, ScryptError> {
+    params.validate()?;
+
+    let r = params.r as usize;
+    let block_len = 128 * r;
+    let n = 1usize << params.log_n;
+
+    let mut b = pbkdf2_hmac_sha256(password, salt, block_len * params.p as usize);
+
+    let mut x = vec![0u32; 32 * r];
+    let mut v = vec![0u32; 32 * r * n];
+    let mut scratch = vec![0u32; 32 * r];
+
+    for chunk in b.chunks_exact_mut(block_len) {
+        for (w, bytes) in x.iter_mut().zip(chunk.chunks_exact(4)) {
+            *w = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+
+        ro_mix(&mut x, &mut v, &mut scratch, n);
+
+        for (w, bytes) in x.iter().zip(chunk.chunks_exact_mut(4)) {
+            bytes.copy_from_slice(&w.to_le_bytes());
+        }
+    }
+
+    let out = pbkdf2_hmac_sha256(password, &b, params.len);
+
+    b.fill(0);
+    x.fill(0);
+    v.fill(0);
+    scratch.fill(0);
+
+    Ok(out)
+}
+
+/// PBKDF2-HMAC-SHA256 with a single iteration.
+fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(len);
+    let mut input = Vec::with_capacity(salt.len() + 4);
+    let mut index = 1u32;
+
+    while out.len() < len {
+        input.clear();
+        input.extend_from_slice(salt);
+        input.extend_from_slice(&index.to_be_bytes());
+
+        let mut block = hmac_sha256(password, &input);
+        let take = (len - out.len()).min(32);
+        out.extend_from_slice(&block[..take]);
+        block.fill(0);
+
+        index += 1;
+    }
+
+    out
+}
+
+/// `scryptROMix`: fills `v` with successive `BlockMix` outputs, then mixes
+/// `x` with entries selected by its own contents.
+fn ro_mix(x: &mut [u32], v: &mut [u32], scratch: &mut [u32], n: usize) {
+    let len = x.len();
+
+    for i in 0..n {
+        v[i * len..(i + 1) * len].copy_from_slice(x);
+        block_mix(x, scratch);
+    }
+
+    for _ in 0..n {
+        let j = integerify(x) & (n - 1);
+
+        for (a, b) in x.iter_mut().zip(&v[j * len..(j + 1) * len]) {
+            *a ^= b;
+        }
+
+        block_mix(x, scratch);
+    }
+}
+
+/// Returns the low 64 bits of the last 64-byte sub-block, as an index.
+fn integerify(x: &[u32]) -> usize {
+    let last = x.len() - 16;
+    (x[last] as u64 | ((x[last + 1] as u64) << 32)) as usize
+}
+
+/// `scryptBlockMix` over `2r` 64-byte sub-blocks, using Salsa20/8.
+///
+/// Outputs are written in the shuffled order `Y0, Y2, …, Y1, Y3, …`.
+fn block_mix(b: &mut [u32], y: &mut [u32]) {
+    let blocks = b.len() / 16;
+    let r = blocks / 2;
+
+    let mut t = [0u32; 16];
+    t.copy_from_slice(&b[(blocks - 1) * 16..]);
+
+    for i in 0..blocks {
+        for (a, w) in t.iter_mut().zip(&b[i * 16..(i + 1) * 16]) {
+            *a ^= w;
+        }
+
+        salsa20_8(&mut t);
+
+        let dst = if i % 2 == 0 { i / 2 } else { r + i / 2 };
+        y[dst * 16..(dst + 1) * 16].copy_from_slice(&t);
+    }
+
+    b.copy_from_slice(y);
+    t.fill(0);
+}
+
+/// Salsa20/8 core: eight rounds plus the feed-forward addition.
+fn salsa20_8(block: &mut [u32; 16]) {
+    let mut x = *block;
+    salsa20_rounds(&mut x, 8);
+
+    for (b, v) in block.iter_mut().zip(x) {
+        *b = b.wrapping_add(v);
+    }
+}
@@ -0,0 +1,353 @@
+//! Generic AEAD interface.
+//!
+//! The [`Aead`] trait exposes the authenticated ciphers of this crate
+//! behind one API, so that formats and protocols can be written once and
+//! parameterized by cipher:
+//!
+//! | Type                 | Key | Nonce | Tag |
+//! |----------------------|-----|-------|-----|
+//! | [`ChaCha20Poly1305`] | 32  | 12    | 16  |
+//! | [`Aes128Gcm`]        | 16  | 12    | 16  |
+//! | [`Aes256Gcm`]        | 32  | 12    | 16  |
+//! | [`Aes128GcmSiv`]     | 16  | 12    | 16  |
+//! | [`Aes256GcmSiv`]     | 32  | 12    | 16  |
+//!
+//! Each cipher provides the detached in-place operations; the attached
+//! forms (`ciphertext || tag`) and the allocating forms are derived from
+//! them.
+//!
+//! ## Notes
+//!
+//! - Keys, nonces and tags are passed as slices and their lengths checked
+//!   against the cipher's constants.
+//! - Key objects wipe their key on drop.
+//! - Nonce uniqueness remains the caller's responsibility; see
+//!   [`nonce`](super::nonce) for a managed sequence.
+
+use super::aes::{gcm, gcm_siv};
+use super::poly1305::core::{decrypt_in_place_with_aad, encrypt_in_place_with_aad};
+
+/// Errors returned by [`Aead`] operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AeadError {
+    /// The key length does not match `KEY_LEN`.
+    InvalidKeyLength,
+    /// The nonce length does not match `NONCE_LEN`.
+    InvalidNonceLength,
+    /// A buffer or tag has the wrong length, or the input exceeds the
+    /// cipher's limit.
+    InvalidLength,
+    /// The authentication tag verification failed.
+    AuthenticationFailed,
+}
+
+/// An authenticated cipher with associated data.
+pub trait Aead: Sized {
+    /// Key length, in bytes.
+    const KEY_LEN: usize;
+
+    /// Nonce length, in bytes.
+    const NONCE_LEN: usize;
+
+    /// Tag length, in bytes.
+    const TAG_LEN: usize;
+
+    /// Creates a cipher instance from a key.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(cipher)` on success
+    /// - `Err(InvalidKeyLength)` if `key.len() != KEY_LEN`
+    fn new(key: &[u8]) -> Result<Self, AeadError>;
+
+    /// Encrypts `buffer` in place and writes the tag to `tag`.
+    ///
+    /// # Parameters
+    ///
+    /// - `nonce`: `NONCE_LEN` bytes, unique per message for this key
+    /// - `aad`: Additional authenticated data
+    /// - `buffer`: Plaintext, replaced by the ciphertext
+    /// - `tag`: Output, `TAG_LEN` bytes
+    fn seal_in_place_detached(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), AeadError>;
+
+    /// Verifies `tag` and decrypts `buffer` in place.
+    ///
+    /// # Parameters
+    ///
+    /// - `nonce`: Nonce used for encryption
+    /// - `aad`: Additional authenticated data supplied by the sender
+    /// - `buffer`: Ciphertext, replaced by the plaintext on success
+    /// - `tag`: Authentication tag, `TAG_LEN` bytes
+    ///
+    /// # Security Notes
+    ///
+    /// - On failure, no plaintext is left in `buffer`.
+    fn open_in_place_detached(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError>;
+
+    /// Encrypts `buffer` in place and appends the tag.
+    fn seal_in_place(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), AeadError> {
+        let len = buffer.len();
+        buffer.resize(len + Self::TAG_LEN, 0);
+
+        let (data, tag) = buffer.split_at_mut(len);
+        let result = self.seal_in_place_detached(nonce, aad, data, tag);
+
+        if result.is_err() {
+            buffer.truncate(len);
+        }
+
+        result
+    }
+
+    /// Verifies and decrypts `ciphertext || tag` in place, removing the
+    /// tag.
+    fn open_in_place(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut Vec<u8>,
+    ) -> Result<(), AeadError> {
+        let len = buffer
+            .len()
+            .checked_sub(Self::TAG_LEN)
+            .ok_or(AeadError::InvalidLength)?;
+
+        let (data, tag) = buffer.split_at_mut(len);
+        self.open_in_place_detached(nonce, aad, data, tag)?;
+
+        buffer.truncate(len);
+        Ok(())
+    }
+
+    /// Encrypts `plaintext` into `ciphertext` and writes the tag to `tag`.
+    fn seal_detached(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        plaintext: &[u8],
+        ciphertext: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), AeadError> {
+        if plaintext.len() != ciphertext.len() {
+            return Err(AeadError::InvalidLength);
+        }
+
+        ciphertext.copy_from_slice(plaintext);
+        self.seal_in_place_detached(nonce, aad, ciphertext, tag)
+    }
+
+    /// Verifies `tag` and decrypts `ciphertext` into `plaintext`.
+    fn open_detached(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8],
+        plaintext: &mut [u8],
+    ) -> Result<(), AeadError> {
+        if plaintext.len() != ciphertext.len() {
+            return Err(AeadError::InvalidLength);
+        }
+
+        plaintext.copy_from_slice(ciphertext);
+        self.open_in_place_detached(nonce, aad, plaintext, tag)
+    }
+
+    /// Encrypts `plaintext` and returns `ciphertext || tag`.
+    fn seal(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, AeadError> {
+        let mut buffer = plaintext.to_vec();
+        self.seal_in_place(nonce, aad, &mut buffer)?;
+
+        Ok(buffer)
+    }
+
+    /// Verifies and decrypts `ciphertext || tag`.
+    fn open(&self, nonce: &[u8], aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, AeadError> {
+        let mut buffer = ciphertext.to_vec();
+        self.open_in_place(nonce, aad, &mut buffer)?;
+
+        Ok(buffer)
+    }
+}
+
+/// ChaCha20-Poly1305 (RFC 8439).
+pub struct ChaCha20Poly1305 {
+    key: [u8; 32],
+}
+
+/// AES-128-GCM (NIST SP 800-38D).
+pub struct Aes128Gcm {
+    key: [u8; 16],
+}
+
+/// AES-256-GCM (NIST SP 800-38D).
+pub struct Aes256Gcm {
+    key: [u8; 32],
+}
+
+/// AES-128-GCM-SIV (RFC 8452).
+pub struct Aes128GcmSiv {
+    key: [u8; 16],
+}
+
+/// AES-256-GCM-SIV (RFC 8452).
+pub struct Aes256GcmSiv {
+    key: [u8; 32],
+}
+
+impl Aead for ChaCha20Poly1305 {
+    const KEY_LEN: usize = 32;
+    const NONCE_LEN: usize = 12;
+    const TAG_LEN: usize = 16;
+
+    fn new(key: &[u8]) -> Result<Self, AeadError> {
+        Ok(Self {
+            key: key.try_into().map_err(|_| AeadError::InvalidKeyLength)?,
+        })
+    }
+
+    fn seal_in_place_detached(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), AeadError> {
+        let nonce = nonce_array(nonce)?;
+        let tag: &mut [u8; 16] = tag.try_into().map_err(|_| AeadError::InvalidLength)?;
+
+        encrypt_in_place_with_aad(&self.key, nonce, aad, buffer, tag);
+        Ok(())
+    }
+
+    fn open_in_place_detached(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError> {
+        let nonce = nonce_array(nonce)?;
+        let tag: &[u8; 16] = tag.try_into().map_err(|_| AeadError::InvalidLength)?;
+
+        decrypt_in_place_with_aad(&self.key, nonce, aad, buffer, tag)
+            .map_err(|_| AeadError::AuthenticationFailed)
+    }
+}
+
+/// Implements [`Aead`] for an AES-based cipher on top of its
+/// `encrypt_in_place_with_aad` / `decrypt_in_place_with_aad` functions.
+macro_rules! impl_aes_aead {
+    ($ty:ty, $key_len:expr, $module:ident, $error:path) => {
+        impl Aead for $ty {
+            const KEY_LEN: usize = $key_len;
+            const NONCE_LEN: usize = 12;
+            const TAG_LEN: usize = 16;
+
+            fn new(key: &[u8]) -> Result<Self, AeadError> {
+                Ok(Self {
+                    key: key.try_into().map_err(|_| AeadError::InvalidKeyLength)?,
+                })
+            }
+
+            fn seal_in_place_detached(
+                &self,
+                nonce: &[u8],
+                aad: &[u8],
+                buffer: &mut [u8],
+                tag: &mut [u8],
+            ) -> Result<(), AeadError> {
+                use $error as E;
+
+                let nonce = nonce_array(nonce)?;
+                let tag: &mut [u8; 16] = tag.try_into().map_err(|_| AeadError::InvalidLength)?;
+
+                $module::encrypt_in_place_with_aad(&self.key, nonce, aad, buffer, tag).map_err(
+                    |e| match e {
+                        E::InvalidKeyLength => AeadError::InvalidKeyLength,
+                        E::InvalidLength => AeadError::InvalidLength,
+                        E::AuthenticationFailed => AeadError::AuthenticationFailed,
+                    },
+                )
+            }
+
+            fn open_in_place_detached(
+                &self,
+                nonce: &[u8],
+                aad: &[u8],
+                buffer: &mut [u8],
+                tag: &[u8],
+            ) -> Result<(), AeadError> {
+                use $error as E;
+
+                let nonce = nonce_array(nonce)?;
+                let tag: &[u8; 16] = tag.try_into().map_err(|_| AeadError::InvalidLength)?;
+
+                $module::decrypt_in_place_with_aad(&self.key, nonce, aad, buffer, tag).map_err(
+                    |e| match e {
+                        E::InvalidKeyLength => AeadError::InvalidKeyLength,
+                        E::InvalidLength => AeadError::InvalidLength,
+                        E::AuthenticationFailed => AeadError::AuthenticationFailed,
+                    },
+                )
+            }
+        }
+    };
+}
+
+impl_aes_aead!(Aes128Gcm, 16, gcm, gcm::AesGcmError);
+impl_aes_aead!(Aes256Gcm, 32, gcm, gcm::AesGcmError);
+impl_aes_aead!(Aes128GcmSiv, 16, gcm_siv, gcm_siv::AesGcmSivError);
+impl_aes_aead!(Aes256GcmSiv, 32, gcm_siv, gcm_siv::AesGcmSivError);
+
+impl Drop for ChaCha20Poly1305 {
+    fn drop(&mut self) {
+        self.key.fill(0);
+    }
+}
+
+impl Drop for Aes128Gcm {
+    fn drop(&mut self) {
+        self.key.fill(0);
+    }
+}
+
+impl Drop for Aes256Gcm {
+    fn drop(&mut self) {
+        self.key.fill(0);
+    }
+}
+
+impl Drop for Aes128GcmSiv {
+    fn drop(&mut self) {
+        self.key.fill(0);
+    }
+}
+
+impl Drop for Aes256GcmSiv {
+    fn drop(&mut self) {
+        self.key.fill(0);
+    }
+}
+
+/// Converts a nonce slice to the 96-bit nonce used by all ciphers here.
+fn nonce_array(nonce: &[u8]) -> Result<&[u8; 12], AeadError> {
+    nonce.try_into().map_err(|_| AeadError::InvalidNonceLength)
+}
@@ -0,0 +1,589 @@
+//! Constant-time AES block cipher (FIPS 197).
+//!
+//! This module implements the AES-128 and AES-256 block cipher using a
+//! **bitsliced** representation, following the structure of the
+//! BearSSL `aes_ct` implementation by Thomas Pornin.
+//!
+//! ## Bitsliced representation
+//!
+//! Two 128-bit blocks are processed at once and spread across eight 32-bit
+//! words, so that word `i` holds bit `i` of every state byte. The S-box is
+//! then evaluated as a Boolean circuit (Boyar–Peralta) on whole words:
+//!
+//! - no lookup table is indexed by secret data,
+//! - no branch depends on the key or the plaintext,
+//! - every block costs the same sequence of instructions.
+//!
+//! ## Hardware acceleration
+//!
+//! On `x86_64`, when the CPU advertises AES-NI, the key schedule is also
+//! kept in its standard byte form and blocks are encrypted with the
+//! dedicated instructions instead (see the `ni` module). Detection happens
+//! at runtime, once per key, and can be overridden on the current thread
+//! with `force_software_backend` for testing.
+//!
+//! The counter-based modes (GCM, GCM-SIV) only use the forward cipher.
+//! The inverse cipher exists for key wrapping (RFC 3394), which needs
+//! single-block decryption.
+
+/// AES round constants used by the key schedule.
+const RCON: [u32; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
+
+/// Maximum number of round keys (AES-256 uses 14 rounds, hence 15 keys).
+const MAX_ROUND_KEYS: usize = 15;
+
+/// Expanded AES key.
+///
+/// The key schedule is stored twice:
+///
+/// - in bitsliced form, ready to be XORed into a bitsliced state,
+/// - in standard byte form, used by the hardware backend when available.
+///
+/// The structure is wiped on drop.
+pub(crate) struct Aes {
+    /// Number of rounds (10 for AES-128, 14 for AES-256).
+    rounds: usize,
+
+    /// Round keys in bitsliced form, eight words per round key.
+    bitsliced: [u32; 8 * MAX_ROUND_KEYS],
+
+    /// Round keys in standard FIPS 197 byte order.
+    round_keys: [[u8; 16]; MAX_ROUND_KEYS],
+
+    /// Whether the AES-NI backend is used for this key.
+    accelerated: bool,
+}
+
+impl Aes {
+    /// Expands a 128-bit or 256-bit key.
+    ///
+    /// Returns `None` if `key` is neither 16 nor 32 bytes long.
+    pub(crate) fn new(key: &[u8]) -> Option<Self> {
+        let (nk, rounds) = match key.len() {
+            16 => (4, 10),
+            32 => (8, 14),
+            _ => return None,
+        };
+
+        // Expanded key schedule as little-endian words, so that the byte
+        // layout in memory matches the standard round keys.
+        let total = 4 * (rounds + 1);
+        let mut w = [0u32; 4 * MAX_ROUND_KEYS];
+
+        for (word, chunk) in w.iter_mut().zip(key.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        let mut tmp = w[nk - 1];
+        let (mut j, mut k) = (0, 0);
+
+        for i in nk..total {
+            if j == 0 {
+                tmp = tmp.rotate_right(8);
+                tmp = sub_word(tmp) ^ RCON[k];
+            } else if nk > 6 && j == 4 {
+                tmp = sub_word(tmp);
+            }
+
+            tmp ^= w[i - nk];
+            w[i] = tmp;
+
+            j += 1;
+            if j == nk {
+                j = 0;
+                k += 1;
+            }
+        }
+
+        let mut round_keys = [[0u8; 16]; MAX_ROUND_KEYS];
+        let mut bitsliced = [0u32; 8 * MAX_ROUND_KEYS];
+
+        for round in 0..=rounds {
+            let words = &w[4 * round..4 * round + 4];
+
+            for (chunk, word) in round_keys[round].chunks_exact_mut(4).zip(words) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+
+            // The same key is applied to both blocks of a bitsliced state.
+            let q = &mut bitsliced[8 * round..8 * round + 8];
+            for (pair, &word) in q.chunks_exact_mut(2).zip(words) {
+                pair[0] = word;
+                pair[1] = word;
+            }
+            ortho(q.try_into().unwrap());
+        }
+
+        w.fill(0);
+
+        Some(Self {
+            rounds,
+            bitsliced,
+            round_keys,
+            accelerated: hardware_available(),
+        })
+    }
+
+    /// Returns the standard round keys actually used by this key size.
+    pub(crate) fn round_keys(&self) -> &[[u8; 16]] {
+        &self.round_keys[..=self.rounds]
+    }
+
+    /// Encrypts a single block in place.
+    pub(crate) fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let mut unused = [0u8; 16];
+        self.encrypt_pair(block, &mut unused);
+    }
+
+    /// Encrypts an arbitrary number of blocks in place.
+    ///
+    /// The software backend processes the blocks two at a time.
+    pub(crate) fn encrypt_blocks(&self, blocks: &mut [[u8; 16]]) {
+        let mut pairs = blocks.chunks_exact_mut(2);
+
+        for pair in &mut pairs {
+            let (a, b) = pair.split_at_mut(1);
+            self.encrypt_pair(&mut a[0], &mut b[0]);
+        }
+
+        if let [last] = pairs.into_remainder() {
+            self.encrypt_block(last);
+        }
+    }
+
+    /// Decrypts a single block in place.
+    pub(crate) fn decrypt_block(&self, block: &mut [u8; 16]) {
+        #[cfg(target_arch = "x86_64")]
+        if self.accelerated {
+            // SAFETY: `accelerated` is only set when the CPU supports AES-NI.
+            unsafe { super::ni::decrypt_block(self.round_keys(), block) };
+            return;
+        }
+
+        let mut q = [0u32; 8];
+        for i in 0..4 {
+            q[2 * i] = u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap());
+        }
+
+        ortho(&mut q);
+        self.decrypt_bitsliced(&mut q);
+        ortho(&mut q);
+
+        for i in 0..4 {
+            block[4 * i..4 * i + 4].copy_from_slice(&q[2 * i].to_le_bytes());
+        }
+    }
+
+    /// Encrypts two blocks in place.
+    fn encrypt_pair(&self, a: &mut [u8; 16], b: &mut [u8; 16]) {
+        #[cfg(target_arch = "x86_64")]
+        if self.accelerated {
+            // SAFETY: `accelerated` is only set when the CPU supports AES-NI.
+            unsafe {
+                super::ni::encrypt_block(self.round_keys(), a);
+                super::ni::encrypt_block(self.round_keys(), b);
+            }
+            return;
+        }
+
+        let mut q = [0u32; 8];
+        for i in 0..4 {
+            q[2 * i] = u32::from_le_bytes(a[4 * i..4 * i + 4].try_into().unwrap());
+            q[2 * i + 1] = u32::from_le_bytes(b[4 * i..4 * i + 4].try_into().unwrap());
+        }
+
+        ortho(&mut q);
+        self.encrypt_bitsliced(&mut q);
+        ortho(&mut q);
+
+        for i in 0..4 {
+            a[4 * i..4 * i + 4].copy_from_slice(&q[2 * i].to_le_bytes());
+            b[4 * i..4 * i + 4].copy_from_slice(&q[2 * i + 1].to_le_bytes());
+        }
+    }
+
+    /// Runs all AES rounds on a bitsliced state.
+    fn encrypt_bitsliced(&self, q: &mut [u32; 8]) {
+        add_round_key(q, &self.bitsliced[..8]);
+
+        for round in 1..self.rounds {
+            sbox(q);
+            shift_rows(q);
+            mix_columns(q);
+            add_round_key(q, &self.bitsliced[8 * round..8 * round + 8]);
+        }
+
+        sbox(q);
+        shift_rows(q);
+        add_round_key(q, &self.bitsliced[8 * self.rounds..8 * self.rounds + 8]);
+    }
+
+    /// Runs all inverse AES rounds on a bitsliced state.
+    fn decrypt_bitsliced(&self, q: &mut [u32; 8]) {
+        add_round_key(q, &self.bitsliced[8 * self.rounds..8 * self.rounds + 8]);
+
+        for round in (1..self.rounds).rev() {
+            inv_shift_rows(q);
+            inv_sbox(q);
+            add_round_key(q, &self.bitsliced[8 * round..8 * round + 8]);
+            inv_mix_columns(q);
+        }
+
+        inv_shift_rows(q);
+        inv_sbox(q);
+        add_round_key(q, &self.bitsliced[..8]);
+    }
+}
+
+impl Drop for Aes {
+    fn drop(&mut self) {
+        self.bitsliced.fill(0);
+        self.round_keys.iter_mut().for_each(|k| k.fill(0));
+    }
+}
+
+/// Returns `true` if the AES-NI backend can be used on this CPU.
+#[cfg(target_arch = "x86_64")]
+fn hardware_available() -> bool {
+    !super::software_only()
+        && std::is_x86_feature_detected!("aes")
+        && std::is_x86_feature_detected!("sse2")
+}
+
+/// Returns `true` if the AES-NI backend can be used on this CPU.
+#[cfg(not(target_arch = "x86_64"))]
+fn hardware_available() -> bool {
+    false
+}
+
+/// Applies the S-box to the four bytes of a key schedule word.
+fn sub_word(x: u32) -> u32 {
+    let mut q = [0u32; 8];
+    q[0] = x;
+
+    ortho(&mut q);
+    sbox(&mut q);
+    ortho(&mut q);
+
+    q[0]
+}
+
+/// Swaps bit groups between two words according to a pair of masks.
+#[inline(always)]
+fn swap_n(x: &mut u32, y: &mut u32, low: u32, high: u32, shift: u32) {
+    let a = *x;
+    let b = *y;
+
+    *x = (a & low) | ((b & low) << shift);
+    *y = ((a & high) >> shift) | (b & high);
+}
+
+/// Converts between the natural and the bitsliced representations.
+///
+/// This transformation is an involution: applying it twice restores the
+/// original words.
+fn ortho(q: &mut [u32; 8]) {
+    let [q0, q1, q2, q3, q4, q5, q6, q7] = q;
+
+    swap_n(q0, q1, 0x5555_5555, 0xaaaa_aaaa, 1);
+    swap_n(q2, q3, 0x5555_5555, 0xaaaa_aaaa, 1);
+    swap_n(q4, q5, 0x5555_5555, 0xaaaa_aaaa, 1);
+    swap_n(q6, q7, 0x5555_5555, 0xaaaa_aaaa, 1);
+
+    swap_n(q0, q2, 0x3333_3333, 0xcccc_cccc, 2);
+    swap_n(q1, q3, 0x3333_3333, 0xcccc_cccc, 2);
+    swap_n(q4, q6, 0x3333_3333, 0xcccc_cccc, 2);
+    swap_n(q5, q7, 0x3333_3333, 0xcccc_cccc, 2);
+
+    swap_n(q0, q4, 0x0f0f_0f0f, 0xf0f0_f0f0, 4);
+    swap_n(q1, q5, 0x0f0f_0f0f, 0xf0f0_f0f0, 4);
+    swap_n(q2, q6, 0x0f0f_0f0f, 0xf0f0_f0f0, 4);
+    swap_n(q3, q7, 0x0f0f_0f0f, 0xf0f0_f0f0, 4);
+}
+
+/// XORs a bitsliced round key into the state.
+#[inline(always)]
+fn add_round_key(q: &mut [u32; 8], key: &[u32]) {
+    q.iter_mut().zip(key).for_each(|(s, k)| *s ^= k);
+}
+
+/// ShiftRows on a bitsliced state.
+fn shift_rows(q: &mut [u32; 8]) {
+    for x in q.iter_mut() {
+        *x = (*x & 0x0000_00ff)
+            | ((*x & 0x0000_fc00) >> 2)
+            | ((*x & 0x0000_0300) << 6)
+            | ((*x & 0x00f0_0000) >> 4)
+            | ((*x & 0x000f_0000) << 4)
+            | ((*x & 0xc000_0000) >> 6)
+            | ((*x & 0x3f00_0000) << 2);
+    }
+}
+
+/// MixColumns on a bitsliced state.
+fn mix_columns(q: &mut [u32; 8]) {
+    let [q0, q1, q2, q3, q4, q5, q6, q7] = *q;
+
+    let r0 = q0.rotate_right(8);
+    let r1 = q1.rotate_right(8);
+    let r2 = q2.rotate_right(8);
+    let r3 = q3.rotate_right(8);
+    let r4 = q4.rotate_right(8);
+    let r5 = q5.rotate_right(8);
+    let r6 = q6.rotate_right(8);
+    let r7 = q7.rotate_right(8);
+
+    q[0] = q7 ^ r7 ^ r0 ^ (q0 ^ r0).rotate_right(16);
+    q[1] = q0 ^ r0 ^ q7 ^ r7 ^ r1 ^ (q1 ^ r1).rotate_right(16);
+    q[2] = q1 ^ r1 ^ r2 ^ (q2 ^ r2).rotate_right(16);
+    q[3] = q2 ^ r2 ^ q7 ^ r7 ^ r3 ^ (q3 ^ r3).rotate_right(16);
+    q[4] = q3 ^ r3 ^ q7 ^ r7 ^ r4 ^ (q4 ^ r4).rotate_right(16);
+    q[5] = q4 ^ r4 ^ r5 ^ (q5 ^ r5).rotate_right(16);
+    q[6] = q5 ^ r5 ^ r6 ^ (q6 ^ r6).rotate_right(16);
+    q[7] = q6 ^ r6 ^ r7 ^ (q7 ^ r7).rotate_right(16);
+}
+
+/// InvShiftRows on a bitsliced state.
+fn inv_shift_rows(q: &mut [u32; 8]) {
+    for x in q.iter_mut() {
+        *x = (*x & 0x0000_00ff)
+            | ((*x & 0x0000_3f00) << 2)
+            | ((*x & 0x0000_c000) >> 6)
+            | ((*x & 0x000f_0000) << 4)
+            | ((*x & 0x00f0_0000) >> 4)
+            | ((*x & 0x0300_0000) << 6)
+            | ((*x & 0xfc00_0000) >> 2);
+    }
+}
+
+/// InvMixColumns on a bitsliced state.
+fn inv_mix_columns(q: &mut [u32; 8]) {
+    let [q0, q1, q2, q3, q4, q5, q6, q7] = *q;
+
+    let r0 = q0.rotate_right(8);
+    let r1 = q1.rotate_right(8);
+    let r2 = q2.rotate_right(8);
+    let r3 = q3.rotate_right(8);
+    let r4 = q4.rotate_right(8);
+    let r5 = q5.rotate_right(8);
+    let r6 = q6.rotate_right(8);
+    let r7 = q7.rotate_right(8);
+
+    q[0] = q5 ^ q6 ^ q7 ^ r0 ^ r5 ^ r7 ^ (q0 ^ q5 ^ q6 ^ r0 ^ r5).rotate_right(16);
+    q[1] = q0 ^ q5 ^ r0 ^ r1 ^ r5 ^ r6 ^ r7 ^ (q1 ^ q5 ^ q7 ^ r1 ^ r5 ^ r6).rotate_right(16);
+    q[2] = q0 ^ q1 ^ q6 ^ r1 ^ r2 ^ r6 ^ r7 ^ (q0 ^ q2 ^ q6 ^ r2 ^ r6 ^ r7).rotate_right(16);
+    q[3] = q0
+        ^ q1
+        ^ q2
+        ^ q5
+        ^ q6
+        ^ r0
+        ^ r2
+        ^ r3
+        ^ r5
+        ^ (q0 ^ q1 ^ q3 ^ q5 ^ q6 ^ q7 ^ r0 ^ r3 ^ r5 ^ r7).rotate_right(16);
+    q[4] = q1
+        ^ q2
+        ^ q3
+        ^ q5
+        ^ r1
+        ^ r3
+        ^ r4
+        ^ r5
+        ^ r6
+        ^ r7
+        ^ (q1 ^ q2 ^ q4 ^ q5 ^ q7 ^ r1 ^ r4 ^ r5 ^ r6).rotate_right(16);
+    q[5] = q2
+        ^ q3
+        ^ q4
+        ^ q6
+        ^ r2
+        ^ r4
+        ^ r5
+        ^ r6
+        ^ r7
+        ^ (q2 ^ q3 ^ q5 ^ q6 ^ r2 ^ r5 ^ r6 ^ r7).rotate_right(16);
+    q[6] =
+        q3 ^ q4 ^ q5 ^ q7 ^ r3 ^ r5 ^ r6 ^ r7 ^ (q3 ^ q4 ^ q6 ^ q7 ^ r3 ^ r6 ^ r7).rotate_right(16);
+    q[7] = q4 ^ q5 ^ q6 ^ r4 ^ r6 ^ r7 ^ (q4 ^ q5 ^ q7 ^ r4 ^ r7).rotate_right(16);
+}
+
+/// Bitsliced inverse AES S-box.
+///
+/// With `S(x) = A(I(x)) ^ 0x63`, where `I` is inversion in GF(2⁸) and `A`
+/// the affine map, the inverse is `B(S(B(x ^ 0x63)) ^ 0x63)` with `B` the
+/// inverse of `A`. This reuses the forward circuit instead of a second one.
+fn inv_sbox(q: &mut [u32; 8]) {
+    inv_affine(q);
+    sbox(q);
+    inv_affine(q);
+}
+
+/// Applies `x -> B(x ^ 0x63)` to a bitsliced state.
+fn inv_affine(q: &mut [u32; 8]) {
+    let q0 = !q[0];
+    let q1 = !q[1];
+    let q2 = q[2];
+    let q3 = q[3];
+    let q4 = q[4];
+    let q5 = !q[5];
+    let q6 = !q[6];
+    let q7 = q[7];
+
+    q[7] = q1 ^ q4 ^ q6;
+    q[6] = q0 ^ q3 ^ q5;
+    q[5] = q7 ^ q2 ^ q4;
+    q[4] = q6 ^ q1 ^ q3;
+    q[3] = q5 ^ q0 ^ q2;
+    q[2] = q4 ^ q7 ^ q1;
+    q[1] = q3 ^ q6 ^ q0;
+    q[0] = q2 ^ q5 ^ q7;
+}
+
+/// Bitsliced AES S-box.
+///
+/// This is the 113-gate circuit by Boyar and Peralta: a top linear layer,
+/// a shared non-linear inversion in GF(2⁸) and a bottom linear layer that
+/// also folds in the affine transformation.
+fn sbox(q: &mut [u32; 8]) {
+    let x0 = q[7];
+    let x1 = q[6];
+    let x2 = q[5];
+    let x3 = q[4];
+    let x4 = q[3];
+    let x5 = q[2];
+    let x6 = q[1];
+    let x7 = q[0];
+
+    // Top linear transformation
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    q[7] = s0;
+    q[6] = s1;
+    q[5] = s2;
+    q[4] = s3;
+    q[3] = s4;
+    q[2] = s5;
+    q[1] = s6;
+    q[0] = s7;
+}
@@ -0,0 +1,333 @@
+//! AES-GCM authenticated encryption (NIST SP 800-38D).
+//!
+//! This module implements the AES-GCM AEAD construction with 128-bit and
+//! 256-bit keys, a 96-bit nonce and a 128-bit tag. It combines:
+//!
+//! - AES in counter mode for confidentiality
+//! - GHASH, a universal hash over GF(2¹²⁸), for authentication
+//!
+//! ## Design goals
+//!
+//! - Dependency-free
+//! - Constant-time block cipher and GHASH (bitsliced software fallback)
+//! - Optional AES-NI / PCLMULQDQ acceleration, detected at runtime
+//! - Same call shape as `chacha20poly1305`
+//!
+//! ## Notes
+//!
+//! - `encrypt` / `decrypt` authenticate an empty AAD.
+//! - `encrypt_with_aad` / `decrypt_with_aad` accept caller-provided
+//!   associated data, which is authenticated but not encrypted.
+//! - The key length selects the variant: 16 bytes for AES-128-GCM,
+//!   32 bytes for AES-256-GCM.
+//! - The caller must ensure `(key, nonce)` uniqueness. Reusing a pair
+//!   leaks the XOR of plaintexts **and** the GHASH key, which allows
+//!   forgeries.
+
+use super::core::Aes;
+use super::ghash::Ghash;
+
+#[doc(hidden)]
+pub use super::force_software_backend;
+
+/// Maximum plaintext length accepted by GCM, in bytes (2³⁶ − 32).
+const MAX_PLAINTEXT_LEN: u64 = (1 << 36) - 32;
+
+/// Errors that can occur during AES-GCM encryption or decryption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AesGcmError {
+    /// The key is neither 16 nor 32 bytes long.
+    InvalidKeyLength,
+    /// The input and output buffers have mismatched lengths, or the
+    /// message exceeds the GCM length limit.
+    InvalidLength,
+    /// The authentication tag verification failed.
+    AuthenticationFailed,
+}
+
+/// Encrypts and authenticates a message using AES-GCM.
+///
+/// This is equivalent to [`encrypt_with_aad`] with an empty AAD.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit secret key
+/// - `nonce`: 96-bit nonce
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+///
+/// # Returns
+///
+/// - `Ok(())` on success
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+///
+/// # Security Notes
+///
+/// - `(key, nonce)` MUST be unique per encryption.
+pub fn encrypt(
+    key: &[u8],
+    nonce: &[u8; 12],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+) -> Result<(), AesGcmError> {
+    encrypt_with_aad(key, nonce, &[], plaintext, ciphertext, tag)
+}
+
+/// Encrypts and authenticates a message and its associated data using
+/// AES-GCM.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit secret key
+/// - `nonce`: 96-bit nonce
+/// - `aad`: Additional authenticated data (authenticated, not encrypted)
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+///
+/// # Returns
+///
+/// - `Ok(())` on success
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+///
+/// # Algorithm
+///
+/// 1. Derive the hash key `H = AES(K, 0¹²⁸)`
+/// 2. Build the initial counter block `J₀ = nonce || 0x00000001`
+/// 3. Encrypt the plaintext in counter mode starting at `inc32(J₀)`
+/// 4. Compute `S = GHASH(H, AAD || pad || C || pad || len(AAD) || len(C))`
+/// 5. Output the tag `T = AES(K, J₀) ⊕ S`
+///
+/// # Security Notes
+///
+/// - `(key, nonce)` MUST be unique per encryption.
+pub fn encrypt_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+) -> Result<(), AesGcmError> {
+    check_lengths(plaintext, ciphertext)?;
+
+    let aes = Aes::new(key).ok_or(AesGcmError::InvalidKeyLength)?;
+    let j0 = initial_counter(nonce);
+
+    ciphertext.copy_from_slice(plaintext);
+    ctr32(&aes, &j0, ciphertext);
+    *tag = compute_tag(&aes, &j0, aad, ciphertext);
+
+    Ok(())
+}
+
+/// Encrypts a buffer in place and computes its tag.
+///
+/// Same construction as [`encrypt_with_aad`], without an intermediate
+/// copy of the plaintext.
+pub(crate) fn encrypt_in_place_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &mut [u8; 16],
+) -> Result<(), AesGcmError> {
+    check_lengths(buffer, buffer)?;
+
+    let aes = Aes::new(key).ok_or(AesGcmError::InvalidKeyLength)?;
+    let j0 = initial_counter(nonce);
+
+    ctr32(&aes, &j0, buffer);
+    *tag = compute_tag(&aes, &j0, aad, buffer);
+
+    Ok(())
+}
+
+/// Decrypts and authenticates a message using AES-GCM.
+///
+/// This is equivalent to [`decrypt_with_aad`] with an empty AAD.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit secret key
+/// - `nonce`: 96-bit nonce
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// - `Ok(())` if authentication succeeds and decryption is successful
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+/// - `Err(AuthenticationFailed)` if tag verification fails
+pub fn decrypt(
+    key: &[u8],
+    nonce: &[u8; 12],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    plaintext: &mut [u8],
+) -> Result<(), AesGcmError> {
+    decrypt_with_aad(key, nonce, &[], ciphertext, tag, plaintext)
+}
+
+/// Decrypts and authenticates a message and its associated data using
+/// AES-GCM.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit secret key
+/// - `nonce`: 96-bit nonce
+/// - `aad`: Additional authenticated data supplied at encryption time
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// - `Ok(())` if authentication succeeds and decryption is successful
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+/// - `Err(AuthenticationFailed)` if tag verification fails
+///
+/// # Security Notes
+///
+/// - Decryption is only performed after successful authentication
+/// - Tag comparison is constant-time
+pub fn decrypt_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    plaintext: &mut [u8],
+) -> Result<(), AesGcmError> {
+    check_lengths(ciphertext, plaintext)?;
+
+    let aes = Aes::new(key).ok_or(AesGcmError::InvalidKeyLength)?;
+    let j0 = initial_counter(nonce);
+
+    verify_tag(&aes, &j0, aad, ciphertext, tag)?;
+
+    plaintext.copy_from_slice(ciphertext);
+    ctr32(&aes, &j0, plaintext);
+    Ok(())
+}
+
+/// Verifies a tag and decrypts a buffer in place.
+///
+/// Same construction as [`decrypt_with_aad`]. The buffer is left
+/// untouched if authentication fails.
+pub(crate) fn decrypt_in_place_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &[u8; 16],
+) -> Result<(), AesGcmError> {
+    check_lengths(buffer, buffer)?;
+
+    let aes = Aes::new(key).ok_or(AesGcmError::InvalidKeyLength)?;
+    let j0 = initial_counter(nonce);
+
+    verify_tag(&aes, &j0, aad, buffer, tag)?;
+
+    ctr32(&aes, &j0, buffer);
+    Ok(())
+}
+
+/// Validates buffer lengths against each other and the GCM limit.
+fn check_lengths(input: &[u8], output: &[u8]) -> Result<(), AesGcmError> {
+    if input.len() != output.len() || input.len() as u64 > MAX_PLAINTEXT_LEN {
+        return Err(AesGcmError::InvalidLength);
+    }
+
+    Ok(())
+}
+
+/// Builds `J₀ = nonce || 0x00000001` for a 96-bit nonce.
+fn initial_counter(nonce: &[u8; 12]) -> [u8; 16] {
+    let mut j0 = [0u8; 16];
+    j0[..12].copy_from_slice(nonce);
+    j0[15] = 1;
+    j0
+}
+
+/// Compares the expected tag with `tag` in constant time.
+fn verify_tag(
+    aes: &Aes,
+    j0: &[u8; 16],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+) -> Result<(), AesGcmError> {
+    let expected_tag = compute_tag(aes, j0, aad, ciphertext);
+
+    let mut diff = 0u8;
+    for i in 0..16 {
+        diff |= expected_tag[i] ^ tag[i];
+    }
+
+    if diff != 0 {
+        return Err(AesGcmError::AuthenticationFailed);
+    }
+
+    Ok(())
+}
+
+/// Computes the GCM tag over the AAD and the ciphertext.
+fn compute_tag(aes: &Aes, j0: &[u8; 16], aad: &[u8], ciphertext: &[u8]) -> [u8; 16] {
+    let mut h = [0u8; 16];
+    aes.encrypt_block(&mut h);
+
+    let mut ghash = Ghash::new(&h);
+    ghash.update(aad);
+    ghash.update(ciphertext);
+
+    let mut lengths = [0u8; 16];
+    lengths[..8].copy_from_slice(&(aad.len() as u64 * 8).to_be_bytes());
+    lengths[8..].copy_from_slice(&(ciphertext.len() as u64 * 8).to_be_bytes());
+    ghash.update(&lengths);
+
+    let mut tag = *j0;
+    aes.encrypt_block(&mut tag);
+
+    for (t, s) in tag.iter_mut().zip(ghash.finalize()) {
+        *t ^= s;
+    }
+
+    h.fill(0);
+    tag
+}
+
+/// XORs `buffer` in place with the AES-CTR keystream starting at
+/// `inc32(j0)`.
+///
+/// The counter occupies the last 32 bits of the block, big-endian, and
+/// wraps modulo 2³² as specified by GCM.
+fn ctr32(aes: &Aes, j0: &[u8; 16], buffer: &mut [u8]) {
+    let mut counter = u32::from_be_bytes(j0[12..].try_into().unwrap());
+    let mut keystream = [[0u8; 16]; 4];
+
+    for chunk in buffer.chunks_mut(64) {
+        let blocks = chunk.len().div_ceil(16);
+
+        for block in keystream[..blocks].iter_mut() {
+            counter = counter.wrapping_add(1);
+            block[..12].copy_from_slice(&j0[..12]);
+            block[12..].copy_from_slice(&counter.to_be_bytes());
+        }
+
+        aes.encrypt_blocks(&mut keystream[..blocks]);
+
+        for (i, b) in chunk.iter_mut().enumerate() {
+            *b ^= keystream[i / 16][i % 16];
+        }
+    }
+
+    keystream.iter_mut().for_each(|k| k.fill(0));
+}
@@ -0,0 +1,434 @@
+//! AES-GCM-SIV nonce-misuse-resistant authenticated encryption (RFC 8452).
+//!
+//! AES-GCM-SIV derives the initialization vector from the message itself
+//! (a *synthetic IV*): the tag is computed first, over the plaintext, and
+//! then used as the initial counter for encryption.
+//!
+//! It combines:
+//!
+//! - per-nonce key derivation from the key-generating key
+//! - POLYVAL over the AAD and the plaintext for authentication
+//! - AES in counter mode, seeded by the tag, for confidentiality
+//!
+//! ## Design goals
+//!
+//! - Dependency-free
+//! - Graceful degradation under nonce reuse
+//! - A deterministic, nonce-less mode for equality lookups
+//! - Same call shape as `aes_gcm` and `chacha20poly1305`
+//!
+//! ## Nonce reuse
+//!
+//! Unlike AES-GCM, repeating a nonce does **not** reveal the authentication
+//! key nor the XOR of plaintexts. The only leak is equality: two identical
+//! `(nonce, aad, plaintext)` triples produce identical ciphertexts.
+//!
+//! ## Deterministic mode
+//!
+//! `encrypt_deterministic` / `decrypt_deterministic` use a fixed all-zero
+//! nonce. Equal `(aad, plaintext)` pairs always encrypt to the same output,
+//! which makes them suitable for indexed, searchable fields. Everything
+//! else about the plaintext stays confidential.
+//!
+//! ## Notes
+//!
+//! - The key length selects the variant: 16 bytes for AES-128-GCM-SIV,
+//!   32 bytes for AES-256-GCM-SIV.
+//! - Plaintext and AAD are each limited to 2³⁶ bytes.
+
+use super::core::Aes;
+use super::ghash::Polyval;
+
+#[doc(hidden)]
+pub use super::force_software_backend;
+
+/// Maximum plaintext and AAD length accepted by AES-GCM-SIV, in bytes.
+const MAX_INPUT_LEN: u64 = 1 << 36;
+
+/// Nonce used by the deterministic mode.
+const DETERMINISTIC_NONCE: [u8; 12] = [0u8; 12];
+
+/// Errors that can occur during AES-GCM-SIV encryption or decryption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AesGcmSivError {
+    /// The key is neither 16 nor 32 bytes long.
+    InvalidKeyLength,
+    /// The input and output buffers have mismatched lengths, or an input
+    /// exceeds the AES-GCM-SIV length limit.
+    InvalidLength,
+    /// The authentication tag verification failed.
+    AuthenticationFailed,
+}
+
+/// Encrypts and authenticates a message using AES-GCM-SIV.
+///
+/// This is equivalent to [`encrypt_with_aad`] with an empty AAD.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit key-generating key
+/// - `nonce`: 96-bit nonce
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+///
+/// # Returns
+///
+/// - `Ok(())` on success
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+pub fn encrypt(
+    key: &[u8],
+    nonce: &[u8; 12],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+) -> Result<(), AesGcmSivError> {
+    encrypt_with_aad(key, nonce, &[], plaintext, ciphertext, tag)
+}
+
+/// Encrypts and authenticates a message and its associated data using
+/// AES-GCM-SIV.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit key-generating key
+/// - `nonce`: 96-bit nonce
+/// - `aad`: Additional authenticated data (authenticated, not encrypted)
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+///
+/// # Returns
+///
+/// - `Ok(())` on success
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch or an input is too long
+///
+/// # Algorithm
+///
+/// 1. Derive the per-nonce authentication and encryption keys
+/// 2. Compute `S = POLYVAL(AAD || pad || P || pad || len(AAD) || len(P))`
+/// 3. XOR the nonce into `S`, clear its top bit and encrypt it into the tag
+/// 4. Encrypt the plaintext in counter mode, starting from the tag with its
+///    top bit set
+///
+/// # Security Notes
+///
+/// - Reusing a nonce only reveals whether two messages are identical.
+pub fn encrypt_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+) -> Result<(), AesGcmSivError> {
+    check_lengths(aad, plaintext, ciphertext)?;
+
+    let (auth_key, aes) = derive_keys(key, nonce)?;
+
+    *tag = compute_tag(&aes, &auth_key, nonce, aad, plaintext);
+    ciphertext.copy_from_slice(plaintext);
+    ctr32(&aes, tag, ciphertext);
+
+    Ok(())
+}
+
+/// Encrypts a buffer in place and computes its tag.
+///
+/// Same construction as [`encrypt_with_aad`], without an intermediate
+/// copy of the plaintext.
+pub(crate) fn encrypt_in_place_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &mut [u8; 16],
+) -> Result<(), AesGcmSivError> {
+    check_lengths(aad, buffer, buffer)?;
+
+    let (auth_key, aes) = derive_keys(key, nonce)?;
+
+    *tag = compute_tag(&aes, &auth_key, nonce, aad, buffer);
+    ctr32(&aes, tag, buffer);
+
+    Ok(())
+}
+
+/// Decrypts and authenticates a message using AES-GCM-SIV.
+///
+/// This is equivalent to [`decrypt_with_aad`] with an empty AAD.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit key-generating key
+/// - `nonce`: 96-bit nonce
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// - `Ok(())` if authentication succeeds and decryption is successful
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+/// - `Err(AuthenticationFailed)` if tag verification fails
+pub fn decrypt(
+    key: &[u8],
+    nonce: &[u8; 12],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    plaintext: &mut [u8],
+) -> Result<(), AesGcmSivError> {
+    decrypt_with_aad(key, nonce, &[], ciphertext, tag, plaintext)
+}
+
+/// Decrypts and authenticates a message and its associated data using
+/// AES-GCM-SIV.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit key-generating key
+/// - `nonce`: 96-bit nonce
+/// - `aad`: Additional authenticated data supplied at encryption time
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// - `Ok(())` if authentication succeeds and decryption is successful
+/// - `Err(InvalidKeyLength)` if the key size is not supported
+/// - `Err(InvalidLength)` if buffer sizes mismatch or an input is too long
+/// - `Err(AuthenticationFailed)` if tag verification fails
+///
+/// # Security Notes
+///
+/// - The tag covers the plaintext, so decryption must happen before
+///   verification. On failure, `plaintext` is wiped before returning.
+/// - Tag comparison is constant-time
+pub fn decrypt_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    plaintext: &mut [u8],
+) -> Result<(), AesGcmSivError> {
+    check_lengths(aad, ciphertext, plaintext)?;
+
+    let (auth_key, aes) = derive_keys(key, nonce)?;
+
+    plaintext.copy_from_slice(ciphertext);
+    ctr32(&aes, tag, plaintext);
+
+    if verify_tag(&aes, &auth_key, nonce, aad, plaintext, tag).is_err() {
+        plaintext.fill(0);
+        return Err(AesGcmSivError::AuthenticationFailed);
+    }
+
+    Ok(())
+}
+
+/// Decrypts a buffer in place and verifies its tag.
+///
+/// Same construction as [`decrypt_with_aad`]. If authentication fails,
+/// the buffer is encrypted again, so it holds the original ciphertext.
+pub(crate) fn decrypt_in_place_with_aad(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &[u8; 16],
+) -> Result<(), AesGcmSivError> {
+    check_lengths(aad, buffer, buffer)?;
+
+    let (auth_key, aes) = derive_keys(key, nonce)?;
+
+    ctr32(&aes, tag, buffer);
+
+    if verify_tag(&aes, &auth_key, nonce, aad, buffer, tag).is_err() {
+        ctr32(&aes, tag, buffer);
+        return Err(AesGcmSivError::AuthenticationFailed);
+    }
+
+    Ok(())
+}
+
+/// Deterministically encrypts a message and its associated data.
+///
+/// This is [`encrypt_with_aad`] with a fixed all-zero nonce: identical
+/// `(key, aad, plaintext)` inputs always yield identical outputs.
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit key-generating key
+/// - `aad`: Additional authenticated data (authenticated, not encrypted)
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+///
+/// # Security Notes
+///
+/// - Equality of plaintexts is observable by design. Use a distinct key or
+///   AAD per field to avoid cross-field comparisons.
+pub fn encrypt_deterministic(
+    key: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+) -> Result<(), AesGcmSivError> {
+    encrypt_with_aad(key, &DETERMINISTIC_NONCE, aad, plaintext, ciphertext, tag)
+}
+
+/// Decrypts a message produced by [`encrypt_deterministic`].
+///
+/// # Parameters
+///
+/// - `key`: 128-bit or 256-bit key-generating key
+/// - `aad`: Additional authenticated data supplied at encryption time
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// Same as [`decrypt_with_aad`].
+pub fn decrypt_deterministic(
+    key: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    plaintext: &mut [u8],
+) -> Result<(), AesGcmSivError> {
+    decrypt_with_aad(key, &DETERMINISTIC_NONCE, aad, ciphertext, tag, plaintext)
+}
+
+/// Validates buffer lengths against each other and the RFC 8452 limits.
+fn check_lengths(aad: &[u8], input: &[u8], output: &[u8]) -> Result<(), AesGcmSivError> {
+    if input.len() != output.len()
+        || input.len() as u64 > MAX_INPUT_LEN
+        || aad.len() as u64 > MAX_INPUT_LEN
+    {
+        return Err(AesGcmSivError::InvalidLength);
+    }
+
+    Ok(())
+}
+
+/// Derives the per-nonce message keys (RFC 8452, Section 4).
+///
+/// Returns the POLYVAL authentication key and the expanded message
+/// encryption key.
+fn derive_keys(key: &[u8], nonce: &[u8; 12]) -> Result<([u8; 16], Aes), AesGcmSivError> {
+    let kgk = Aes::new(key).ok_or(AesGcmSivError::InvalidKeyLength)?;
+
+    // Two blocks for the authentication key, two or four for the
+    // encryption key; only the first 8 bytes of each output are kept.
+    let count = 2 + key.len() / 8;
+    let mut blocks = [[0u8; 16]; 6];
+
+    for (i, block) in blocks[..count].iter_mut().enumerate() {
+        block[..4].copy_from_slice(&(i as u32).to_le_bytes());
+        block[4..].copy_from_slice(nonce);
+    }
+
+    kgk.encrypt_blocks(&mut blocks[..count]);
+
+    let mut derived = [0u8; 48];
+    for (chunk, block) in derived.chunks_exact_mut(8).zip(&blocks[..count]) {
+        chunk.copy_from_slice(&block[..8]);
+    }
+
+    let mut auth_key = [0u8; 16];
+    auth_key.copy_from_slice(&derived[..16]);
+
+    let aes = Aes::new(&derived[16..16 + key.len()]).ok_or(AesGcmSivError::InvalidKeyLength)?;
+
+    blocks.iter_mut().for_each(|b| b.fill(0));
+    derived.fill(0);
+
+    Ok((auth_key, aes))
+}
+
+/// Compares the expected tag with `tag` in constant time.
+fn verify_tag(
+    aes: &Aes,
+    auth_key: &[u8; 16],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    plaintext: &[u8],
+    tag: &[u8; 16],
+) -> Result<(), AesGcmSivError> {
+    let expected_tag = compute_tag(aes, auth_key, nonce, aad, plaintext);
+
+    let mut diff = 0u8;
+    for i in 0..16 {
+        diff |= expected_tag[i] ^ tag[i];
+    }
+
+    if diff != 0 {
+        return Err(AesGcmSivError::AuthenticationFailed);
+    }
+
+    Ok(())
+}
+
+/// Computes the AES-GCM-SIV tag over the AAD and the plaintext.
+fn compute_tag(
+    aes: &Aes,
+    auth_key: &[u8; 16],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> [u8; 16] {
+    let mut polyval = Polyval::new(auth_key);
+    polyval.update(aad);
+    polyval.update(plaintext);
+
+    let mut lengths = [0u8; 16];
+    lengths[..8].copy_from_slice(&(aad.len() as u64 * 8).to_le_bytes());
+    lengths[8..].copy_from_slice(&(plaintext.len() as u64 * 8).to_le_bytes());
+    polyval.update_block(&lengths);
+
+    let mut tag = polyval.finalize();
+    for (t, n) in tag.iter_mut().zip(nonce) {
+        *t ^= n;
+    }
+    tag[15] &= 0x7f;
+
+    aes.encrypt_block(&mut tag);
+    tag
+}
+
+/// XORs `buffer` in place with the AES-CTR keystream derived from `tag`.
+///
+/// The initial counter block is the tag with its top bit set; the counter
+/// occupies the first 32 bits, little-endian, and wraps modulo 2³².
+fn ctr32(aes: &Aes, tag: &[u8; 16], buffer: &mut [u8]) {
+    let mut counter_block = *tag;
+    counter_block[15] |= 0x80;
+
+    let mut counter = u32::from_le_bytes(counter_block[..4].try_into().unwrap());
+    let mut keystream = [[0u8; 16]; 4];
+
+    for chunk in buffer.chunks_mut(64) {
+        let blocks = chunk.len().div_ceil(16);
+
+        for block in keystream[..blocks].iter_mut() {
+            *block = counter_block;
+            block[..4].copy_from_slice(&counter.to_le_bytes());
+            counter = counter.wrapping_add(1);
+        }
+
+        aes.encrypt_blocks(&mut keystream[..blocks]);
+
+        for (i, b) in chunk.iter_mut().enumerate() {
+            *b ^= keystream[i / 16][i % 16];
+        }
+    }
+
+    keystream.iter_mut().for_each(|k| k.fill(0));
+}
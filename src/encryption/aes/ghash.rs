@@ -0,0 +1,230 @@
+//! GHASH and POLYVAL universal hashes over GF(2¹²⁸).
+//!
+//! GHASH (NIST SP 800-38D) authenticates AES-GCM messages; POLYVAL
+//! (RFC 8452) is its little-endian counterpart. Both fields are defined by
+//! mutually reversed polynomials, so GHASH can be computed on top of a
+//! POLYVAL core (RFC 8452, Appendix A):
+//!
+//! ```text
+//! GHASH(H, X₁..Xₙ) = ByteReverse(POLYVAL(mulX(ByteReverse(H)), ByteReverse(X₁)..))
+//! ```
+//!
+//! ## Backends
+//!
+//! - Software: constant-time carry-less multiplication using integer
+//!   multiplications with "holes", adapted from BearSSL `ghash_ctmul64`.
+//! - x86_64: PCLMULQDQ, selected at runtime when available.
+//!
+//! Both backends are free of secret-dependent branches and table lookups.
+
+/// POLYVAL universal hash state.
+pub(crate) struct Polyval {
+    /// Hash key, as `[low, high]` little-endian halves.
+    h: [u64; 2],
+
+    /// Running accumulator.
+    s: [u64; 2],
+
+    /// Whether the PCLMULQDQ backend is used.
+    accelerated: bool,
+}
+
+impl Polyval {
+    /// Creates a new POLYVAL instance keyed with `h`.
+    pub(crate) fn new(h: &[u8; 16]) -> Self {
+        Self {
+            h: to_halves(h),
+            s: [0; 2],
+            accelerated: clmul_available(),
+        }
+    }
+
+    /// Absorbs data, zero-padding the final partial block.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        for chunk in data.chunks(16) {
+            let mut block = [0u8; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            self.update_block(&block);
+        }
+    }
+
+    /// Absorbs a single 16-byte block.
+    pub(crate) fn update_block(&mut self, block: &[u8; 16]) {
+        let x = to_halves(block);
+        let s = [self.s[0] ^ x[0], self.s[1] ^ x[1]];
+
+        self.s = self.mul(s);
+    }
+
+    /// Returns the current hash value and wipes the state.
+    pub(crate) fn finalize(mut self) -> [u8; 16] {
+        let mut out = [0u8; 16];
+        out[..8].copy_from_slice(&self.s[0].to_le_bytes());
+        out[8..].copy_from_slice(&self.s[1].to_le_bytes());
+
+        self.s = [0; 2];
+        out
+    }
+
+    /// Multiplies `x` by the hash key.
+    fn mul(&self, x: [u64; 2]) -> [u64; 2] {
+        #[cfg(target_arch = "x86_64")]
+        if self.accelerated {
+            // SAFETY: `accelerated` is only set when PCLMULQDQ is available.
+            return unsafe { super::ni::polyval_mul(x, self.h) };
+        }
+
+        soft_mul(x, self.h)
+    }
+}
+
+impl Drop for Polyval {
+    fn drop(&mut self) {
+        self.h = [0; 2];
+        self.s = [0; 2];
+    }
+}
+
+/// GHASH universal hash state, built on [`Polyval`].
+pub(crate) struct Ghash(Polyval);
+
+impl Ghash {
+    /// Creates a new GHASH instance keyed with `h = E(K, 0¹²⁸)`.
+    pub(crate) fn new(h: &[u8; 16]) -> Self {
+        let mut key = *h;
+        key.reverse();
+
+        let ghash = Self(Polyval::new(&mulx(&key)));
+        key.fill(0);
+
+        ghash
+    }
+
+    /// Absorbs data, zero-padding the final partial block.
+    ///
+    /// This matches the GCM padding rule, where the AAD and the ciphertext
+    /// are each padded to a multiple of 16 bytes.
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        for chunk in data.chunks(16) {
+            let mut block = [0u8; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            block.reverse();
+            self.0.update_block(&block);
+        }
+    }
+
+    /// Returns the GHASH value.
+    pub(crate) fn finalize(self) -> [u8; 16] {
+        let mut out = self.0.finalize();
+        out.reverse();
+        out
+    }
+}
+
+/// Multiplies a POLYVAL field element by `x` (RFC 8452, `mulX_POLYVAL`).
+pub(crate) fn mulx(block: &[u8; 16]) -> [u8; 16] {
+    let mut v = u128::from_le_bytes(*block);
+    let carry = v >> 127;
+
+    v <<= 1;
+    v ^= carry ^ (carry << 127) ^ (carry << 126) ^ (carry << 121);
+
+    v.to_le_bytes()
+}
+
+/// Splits a 16-byte block into little-endian `[low, high]` halves.
+fn to_halves(block: &[u8; 16]) -> [u64; 2] {
+    [
+        u64::from_le_bytes(block[..8].try_into().unwrap()),
+        u64::from_le_bytes(block[8..].try_into().unwrap()),
+    ]
+}
+
+/// Returns `true` if the PCLMULQDQ backend can be used on this CPU.
+#[cfg(target_arch = "x86_64")]
+fn clmul_available() -> bool {
+    !super::software_only() && super::ni::clmul_available()
+}
+
+/// Returns `true` if the PCLMULQDQ backend can be used on this CPU.
+#[cfg(not(target_arch = "x86_64"))]
+fn clmul_available() -> bool {
+    false
+}
+
+/// Constant-time POLYVAL multiplication.
+///
+/// Uses Karatsuba on 64-bit halves; each 64×64 carry-less product is
+/// computed twice (on normal and bit-reversed operands) to recover both
+/// its low and high halves.
+fn soft_mul(x: [u64; 2], h: [u64; 2]) -> [u64; 2] {
+    let [h0, h1] = h;
+    let h0r = rev64(h0);
+    let h1r = rev64(h1);
+    let h2 = h0 ^ h1;
+    let h2r = h0r ^ h1r;
+
+    let [y0, y1] = x;
+    let y0r = rev64(y0);
+    let y1r = rev64(y1);
+    let y2 = y0 ^ y1;
+    let y2r = y0r ^ y1r;
+
+    let z0 = bmul64(y0, h0);
+    let z1 = bmul64(y1, h1);
+    let mut z2 = bmul64(y2, h2);
+    let mut z0h = bmul64(y0r, h0r);
+    let mut z1h = bmul64(y1r, h1r);
+    let mut z2h = bmul64(y2r, h2r);
+
+    z2 ^= z0 ^ z1;
+    z2h ^= z0h ^ z1h;
+    z0h = rev64(z0h) >> 1;
+    z1h = rev64(z1h) >> 1;
+    z2h = rev64(z2h) >> 1;
+
+    let v0 = z0;
+    let mut v1 = z0h ^ z2;
+    let mut v2 = z1 ^ z2h;
+    let mut v3 = z1h;
+
+    // Reduction modulo x^128 + x^127 + x^126 + x^121 + 1.
+    v2 ^= v0 ^ (v0 >> 1) ^ (v0 >> 2) ^ (v0 >> 7);
+    v1 ^= (v0 << 63) ^ (v0 << 62) ^ (v0 << 57);
+    v3 ^= v1 ^ (v1 >> 1) ^ (v1 >> 2) ^ (v1 >> 7);
+    v2 ^= (v1 << 63) ^ (v1 << 62) ^ (v1 << 57);
+
+    [v2, v3]
+}
+
+/// Carry-less 64×64 multiplication, truncated to the low 64 bits.
+///
+/// Operands are split into four interleaved masks so that integer carries
+/// land in bit positions that are discarded afterwards.
+fn bmul64(x: u64, y: u64) -> u64 {
+    const M0: u64 = 0x1111_1111_1111_1111;
+    const M1: u64 = 0x2222_2222_2222_2222;
+    const M2: u64 = 0x4444_4444_4444_4444;
+    const M3: u64 = 0x8888_8888_8888_8888;
+
+    let (x0, x1, x2, x3) = (x & M0, x & M1, x & M2, x & M3);
+    let (y0, y1, y2, y3) = (y & M0, y & M1, y & M2, y & M3);
+
+    let z0 = x0.wrapping_mul(y0) ^ x1.wrapping_mul(y3) ^ x2.wrapping_mul(y2) ^ x3.wrapping_mul(y1);
+    let z1 = x0.wrapping_mul(y1) ^ x1.wrapping_mul(y0) ^ x2.wrapping_mul(y3) ^ x3.wrapping_mul(y2);
+    let z2 = x0.wrapping_mul(y2) ^ x1.wrapping_mul(y1) ^ x2.wrapping_mul(y0) ^ x3.wrapping_mul(y3);
+    let z3 = x0.wrapping_mul(y3) ^ x1.wrapping_mul(y2) ^ x2.wrapping_mul(y1) ^ x3.wrapping_mul(y0);
+
+    (z0 & M0) | (z1 & M1) | (z2 & M2) | (z3 & M3)
+}
+
+/// Reverses the bit order of a 64-bit word.
+fn rev64(mut x: u64) -> u64 {
+    x = ((x & 0x5555_5555_5555_5555) << 1) | ((x >> 1) & 0x5555_5555_5555_5555);
+    x = ((x & 0x3333_3333_3333_3333) << 2) | ((x >> 2) & 0x3333_3333_3333_3333);
+    x = ((x & 0x0f0f_0f0f_0f0f_0f0f) << 4) | ((x >> 4) & 0x0f0f_0f0f_0f0f_0f0f);
+    x = ((x & 0x00ff_00ff_00ff_00ff) << 8) | ((x >> 8) & 0x00ff_00ff_00ff_00ff);
+    x = ((x & 0x0000_ffff_0000_ffff) << 16) | ((x >> 16) & 0x0000_ffff_0000_ffff);
+
+    x.rotate_left(32)
+}
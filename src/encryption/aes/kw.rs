@@ -0,0 +1,159 @@
+//! AES key wrap (RFC 3394).
+//!
+//! This module wraps a key under a key-encryption key (KEK) with the
+//! AES-KW algorithm. Wrapping is deterministic: the same key and KEK
+//! always produce the same 8-byte-longer output, so no nonce or
+//! randomness is needed.
+//!
+//! ```text
+//! wrapped = A (8 bytes, integrity check) || R[1] || ... || R[n]
+//! ```
+//!
+//! ## Design goals
+//!
+//! - Interoperable with other RFC 3394 implementations (default IV
+//!   `A6A6A6A6A6A6A6A6`)
+//! - Integrity check in constant time
+//! - No unwrapped key material returned on failure
+//!
+//! ## Notes
+//!
+//! - The KEK selects the variant: 16 bytes for AES-128-KW, 32 bytes for
+//!   AES-256-KW.
+//! - Wrapped keys must be at least 16 bytes long and a multiple of 8.
+//! - AES-KW is meant for high-entropy keys only. Use an AEAD for anything
+//!   else, since equal inputs produce equal outputs.
+
+use super::core::Aes;
+
+/// Default initial value (RFC 3394 §2.2.3.1).
+const DEFAULT_IV: [u8; 8] = [0xa6; 8];
+
+/// Length of the integrity check value, in bytes.
+pub const OVERHEAD: usize = 8;
+
+/// Errors that can occur while wrapping or unwrapping a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AesKwError {
+    /// The KEK is neither 16 nor 32 bytes long.
+    InvalidKekLength,
+    /// The key to wrap is shorter than 16 bytes or not a multiple of 8.
+    InvalidKeyLength,
+    /// The wrapped input is shorter than 24 bytes or not a multiple of 8.
+    InvalidLength,
+    /// The wrapped key was modified, or the KEK is wrong.
+    IntegrityCheckFailed,
+}
+
+/// Wraps `key` under `kek`.
+///
+/// # Parameters
+///
+/// - `kek`: 128-bit or 256-bit key-encryption key
+/// - `key`: Key to wrap, at least 16 bytes and a multiple of 8
+///
+/// # Returns
+///
+/// - `Ok(wrapped)` with `key.len() + OVERHEAD` bytes
+/// - `Err(InvalidKekLength)` if the KEK size is not supported
+/// - `Err(InvalidKeyLength)` if `key` has an invalid length
+pub fn wrap(kek: &[u8], key: &[u8]) -> Result<Vec<u8>, AesKwError> {
+    let aes = Aes::new(kek).ok_or(AesKwError::InvalidKekLength)?;
+
+    if key.len() < 16 || !key.len().is_multiple_of(8) {
+        return Err(AesKwError::InvalidKeyLength);
+    }
+
+    let n = key.len() / 8;
+    let mut out = vec![0u8; OVERHEAD + key.len()];
+    out[OVERHEAD..].copy_from_slice(key);
+
+    let mut a = DEFAULT_IV;
+    let mut block = [0u8; 16];
+
+    for j in 0..6 {
+        for i in 1..=n {
+            let r = &mut out[8 * i..8 * i + 8];
+
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(r);
+            aes.encrypt_block(&mut block);
+
+            let t = (n * j + i) as u64;
+            a.copy_from_slice(&block[..8]);
+            xor_counter(&mut a, t);
+            r.copy_from_slice(&block[8..]);
+        }
+    }
+
+    out[..OVERHEAD].copy_from_slice(&a);
+    block.fill(0);
+
+    Ok(out)
+}
+
+/// Unwraps a key produced by [`wrap`] and verifies its integrity.
+///
+/// # Parameters
+///
+/// - `kek`: Key-encryption key used for wrapping
+/// - `wrapped`: Wrapped key, `OVERHEAD` bytes longer than the key
+///
+/// # Returns
+///
+/// - `Ok(key)` on success
+/// - `Err(InvalidKekLength)` if the KEK size is not supported
+/// - `Err(InvalidLength)` if `wrapped` has an invalid length
+/// - `Err(IntegrityCheckFailed)` if the integrity check fails
+///
+/// # Security Notes
+///
+/// - The check value is compared in constant time.
+/// - On failure, the partially unwrapped key is wiped and not returned.
+pub fn unwrap(kek: &[u8], wrapped: &[u8]) -> Result<Vec<u8>, AesKwError> {
+    let aes = Aes::new(kek).ok_or(AesKwError::InvalidKekLength)?;
+
+    if wrapped.len() < 24 || !wrapped.len().is_multiple_of(8) {
+        return Err(AesKwError::InvalidLength);
+    }
+
+    let n = wrapped.len() / 8 - 1;
+    let mut a: [u8; 8] = wrapped[..OVERHEAD].try_into().unwrap();
+    let mut key = wrapped[OVERHEAD..].to_vec();
+    let mut block = [0u8; 16];
+
+    for j in (0..6).rev() {
+        for i in (1..=n).rev() {
+            let r = &mut key[8 * (i - 1)..8 * i];
+
+            let t = (n * j + i) as u64;
+            xor_counter(&mut a, t);
+            block[..8].copy_from_slice(&a);
+            block[8..].copy_from_slice(r);
+            aes.decrypt_block(&mut block);
+
+            a.copy_from_slice(&block[..8]);
+            r.copy_from_slice(&block[8..]);
+        }
+    }
+
+    block.fill(0);
+
+    let diff = a
+        .iter()
+        .zip(&DEFAULT_IV)
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y));
+
+    if diff != 0 {
+        key.fill(0);
+        return Err(AesKwError::IntegrityCheckFailed);
+    }
+
+    Ok(key)
+}
+
+/// XORs the big-endian step counter `t` into the check register.
+#[inline(always)]
+fn xor_counter(a: &mut [u8; 8], t: u64) {
+    a.iter_mut().zip(t.to_be_bytes()).for_each(|(x, y)| *x ^= y);
+}
@@ -0,0 +1,86 @@
+//! AES block cipher and AES-based AEAD constructions.
+//!
+//! This module provides an internal, constant-time implementation of the
+//! AES block cipher (FIPS 197), designed to be used as a building block for
+//! authenticated encryption schemes such as AES-GCM.
+//!
+//! The implementation is split into several layers:
+//!
+//! - `core`: bitsliced AES key schedule, block encryption and decryption
+//! - `ghash`: GHASH / POLYVAL universal hashes over GF(2¹²⁸)
+//! - `ni`: optional x86_64 AES-NI / PCLMULQDQ backend
+//! - `gcm`: high-level AES-GCM AEAD interface
+//! - `gcm_siv`: high-level AES-GCM-SIV AEAD interface
+//! - `kw`: AES key wrap (RFC 3394)
+//!
+//! Only the AEAD and key wrap interfaces are meant to be used by end
+//! users; the block cipher and universal hashes remain internal.
+
+/// Bitsliced AES block cipher.
+///
+/// This module contains the key schedule, the forward cipher (operating
+/// on two blocks at a time) and the inverse cipher, all without
+/// secret-dependent memory accesses.
+///
+/// This module is cryptographically sensitive and must remain internal.
+pub(crate) mod core;
+
+/// AES-GCM authenticated encryption (NIST SP 800-38D).
+///
+/// This module exposes the high-level AEAD interface:
+/// - key size selection (AES-128 / AES-256)
+/// - counter-mode encryption
+/// - GHASH authentication with associated data
+pub mod gcm;
+
+/// AES-GCM-SIV nonce-misuse-resistant authenticated encryption (RFC 8452).
+///
+/// This module exposes the high-level AEAD interface:
+/// - per-nonce key derivation
+/// - POLYVAL authentication over AAD and plaintext
+/// - synthetic-IV counter-mode encryption
+/// - a deterministic, nonce-less mode
+pub mod gcm_siv;
+
+/// AES key wrap (RFC 3394).
+///
+/// This module exposes deterministic key wrapping:
+/// - the default integrity check value
+/// - `wrap` / `unwrap` with typed errors on tampering
+pub mod kw;
+
+/// GHASH and POLYVAL universal hashes.
+///
+/// Both share a single constant-time GF(2¹²⁸) multiplier, with an optional
+/// PCLMULQDQ backend.
+pub(crate) mod ghash;
+
+/// x86_64 hardware backend.
+///
+/// Thin `unsafe` wrappers around AES-NI and PCLMULQDQ, only called after
+/// runtime feature detection.
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod ni;
+
+use std::cell::Cell;
+
+thread_local! {
+    /// Set by [`force_software_backend`] to bypass the hardware backend.
+    static SOFTWARE_ONLY: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Forces the bitsliced AES and software GHASH / POLYVAL backends on the
+/// current thread.
+///
+/// Only keys set up afterwards are affected. This exists so that tests
+/// exercise the constant-time fallback on CPUs with AES-NI and PCLMULQDQ;
+/// it is not part of the public API.
+#[doc(hidden)]
+pub fn force_software_backend(enabled: bool) {
+    SOFTWARE_ONLY.set(enabled);
+}
+
+/// Returns `true` if [`force_software_backend`] is enabled on this thread.
+pub(crate) fn software_only() -> bool {
+    SOFTWARE_ONLY.get()
+}
@@ -0,0 +1,160 @@
+//! x86_64 hardware backend (AES-NI and PCLMULQDQ).
+//!
+//! This module contains the only `unsafe` code of the AES implementation.
+//! Every function here requires a CPU feature that must have been detected
+//! at runtime by the caller before the function is invoked.
+//!
+//! The hardware instructions run in constant time, so this backend keeps
+//! the same side-channel guarantees as the bitsliced software fallback.
+
+use core::arch::x86_64::{
+    __m128i, _mm_aesdec_si128, _mm_aesdeclast_si128, _mm_aesenc_si128, _mm_aesenclast_si128,
+    _mm_aesimc_si128, _mm_clmulepi64_si128, _mm_loadu_si128, _mm_set_epi64x, _mm_shuffle_epi32,
+    _mm_slli_epi64, _mm_srli_epi64, _mm_storeu_si128, _mm_unpackhi_epi64, _mm_unpacklo_epi64,
+    _mm_xor_si128,
+};
+
+/// Returns `true` if carry-less multiplication is available.
+pub(crate) fn clmul_available() -> bool {
+    std::is_x86_feature_detected!("pclmulqdq") && std::is_x86_feature_detected!("sse2")
+}
+
+/// Encrypts one block with AES-NI.
+///
+/// `round_keys` holds the standard (FIPS 197 byte order) round keys,
+/// including the initial whitening key.
+///
+/// # Safety
+///
+/// The CPU must support the `aes` and `sse2` features.
+#[target_feature(enable = "aes,sse2")]
+pub(crate) unsafe fn encrypt_block(round_keys: &[[u8; 16]], block: &mut [u8; 16]) {
+    let (last, middle) = round_keys[1..].split_last().unwrap();
+
+    // SAFETY: all loads and stores target 16-byte arrays; unaligned access
+    // is explicitly allowed by `loadu` / `storeu`.
+    unsafe {
+        let mut state = _mm_loadu_si128(block.as_ptr().cast());
+        state = _mm_xor_si128(state, _mm_loadu_si128(round_keys[0].as_ptr().cast()));
+
+        for key in middle {
+            state = _mm_aesenc_si128(state, _mm_loadu_si128(key.as_ptr().cast()));
+        }
+
+        state = _mm_aesenclast_si128(state, _mm_loadu_si128(last.as_ptr().cast()));
+        _mm_storeu_si128(block.as_mut_ptr().cast(), state);
+    }
+}
+
+/// Decrypts one block with AES-NI.
+///
+/// `round_keys` holds the same encryption round keys as for
+/// [`encrypt_block`]; the equivalent inverse cipher keys are derived on
+/// the fly with `aesimc`.
+///
+/// # Safety
+///
+/// The CPU must support the `aes` and `sse2` features.
+#[target_feature(enable = "aes,sse2")]
+pub(crate) unsafe fn decrypt_block(round_keys: &[[u8; 16]], block: &mut [u8; 16]) {
+    let (last, rest) = round_keys.split_last().unwrap();
+    let (first, middle) = rest.split_first().unwrap();
+
+    // SAFETY: all loads and stores target 16-byte arrays; unaligned access
+    // is explicitly allowed by `loadu` / `storeu`.
+    unsafe {
+        let mut state = _mm_loadu_si128(block.as_ptr().cast());
+        state = _mm_xor_si128(state, _mm_loadu_si128(last.as_ptr().cast()));
+
+        for key in middle.iter().rev() {
+            let key = _mm_aesimc_si128(_mm_loadu_si128(key.as_ptr().cast()));
+            state = _mm_aesdec_si128(state, key);
+        }
+
+        state = _mm_aesdeclast_si128(state, _mm_loadu_si128(first.as_ptr().cast()));
+        _mm_storeu_si128(block.as_mut_ptr().cast(), state);
+    }
+}
+
+/// Multiplies two POLYVAL field elements with PCLMULQDQ.
+///
+/// Elements are given as `[low, high]` 64-bit halves of their
+/// little-endian encoding.
+///
+/// # Safety
+///
+/// The CPU must support the `pclmulqdq` and `sse2` features.
+#[target_feature(enable = "pclmulqdq,sse2")]
+pub(crate) unsafe fn polyval_mul(x: [u64; 2], h: [u64; 2]) -> [u64; 2] {
+    let x = _mm_set_epi64x(x[1] as i64, x[0] as i64);
+    let h = _mm_set_epi64x(h[1] as i64, h[0] as i64);
+
+    // Karatsuba multiplication into a 256-bit product (v0, v1, v2, v3).
+    let h1 = _mm_shuffle_epi32(h, 0x0e);
+    let h2 = _mm_xor_si128(h, h1);
+    let x1 = _mm_shuffle_epi32(x, 0x0e);
+    let x2 = _mm_xor_si128(x, x1);
+
+    let t0 = _mm_clmulepi64_si128(x, h, 0x00);
+    let t1 = _mm_clmulepi64_si128(x, h, 0x11);
+    let t2 = _mm_clmulepi64_si128(x2, h2, 0x00);
+    let t2 = _mm_xor_si128(t2, _mm_xor_si128(t0, t1));
+
+    let v0 = t0;
+    let v1 = _mm_xor_si128(_mm_shuffle_epi32(t0, 0x0e), t2);
+    let v2 = _mm_xor_si128(t1, _mm_shuffle_epi32(t2, 0x0e));
+    let v3 = _mm_shuffle_epi32(t1, 0x0e);
+
+    // Reduction modulo x^128 + x^127 + x^126 + x^121 + 1.
+    let v2 = xor(
+        xor(v2, v0),
+        xor(
+            _mm_srli_epi64(v0, 1),
+            xor(_mm_srli_epi64(v0, 2), _mm_srli_epi64(v0, 7)),
+        ),
+    );
+    let v1 = xor(
+        v1,
+        xor(
+            _mm_slli_epi64(v0, 63),
+            xor(_mm_slli_epi64(v0, 62), _mm_slli_epi64(v0, 57)),
+        ),
+    );
+    let v3 = xor(
+        xor(v3, v1),
+        xor(
+            _mm_srli_epi64(v1, 1),
+            xor(_mm_srli_epi64(v1, 2), _mm_srli_epi64(v1, 7)),
+        ),
+    );
+    let v2 = xor(
+        v2,
+        xor(
+            _mm_slli_epi64(v1, 63),
+            xor(_mm_slli_epi64(v1, 62), _mm_slli_epi64(v1, 57)),
+        ),
+    );
+
+    let low = _mm_unpacklo_epi64(v2, v3);
+    let high = _mm_unpackhi_epi64(low, low);
+
+    [to_u64(low), to_u64(high)]
+}
+
+/// XOR of two 128-bit registers.
+#[inline(always)]
+fn xor(a: __m128i, b: __m128i) -> __m128i {
+    // SAFETY: SSE2 is part of the x86_64 baseline.
+    unsafe { _mm_xor_si128(a, b) }
+}
+
+/// Extracts the low 64 bits of a register.
+#[inline(always)]
+fn to_u64(x: __m128i) -> u64 {
+    let mut out = [0u8; 16];
+
+    // SAFETY: `out` is a 16-byte buffer; unaligned stores are allowed.
+    unsafe { _mm_storeu_si128(out.as_mut_ptr().cast(), x) };
+
+    u64::from_le_bytes(out[..8].try_into().unwrap())
+}
@@ -0,0 +1,98 @@
+//! age ASCII armor.
+//!
+//! An armored file is the binary age file encoded as padded Base64,
+//! wrapped at 64 columns, between fixed begin and end lines:
+//!
+//! ```text
+//! -----BEGIN AGE ENCRYPTED FILE-----
+//! YWdlLWVuY3J5cHRpb24ub3JnL3YxCi0+IFgyNTUxOSBURWlGMHlwcXIrYnB2Y3FY
+//! ...
+//! -----END AGE ENCRYPTED FILE-----
+//! ```
+//!
+//! Decoding is strict: whitespace is only allowed around the armor, lines
+//! may end with LF or CRLF, every line but the last must be exactly 64
+//! columns, and the Base64 must be canonical.
+
+use super::AgeError;
+use super::encoding::{base64_decode, base64_encode};
+
+/// First line of an armored file.
+const BEGIN: &[u8] = b"-----BEGIN AGE ENCRYPTED FILE-----";
+
+/// Last line of an armored file.
+const END: &[u8] = b"-----END AGE ENCRYPTED FILE-----";
+
+/// Number of Base64 characters per armored line.
+const COLUMNS: usize = 64;
+
+/// Encodes a binary age file with ASCII armor.
+///
+/// # Parameters
+///
+/// - `file`: Binary age file, as produced by [`encrypt`](super::encrypt)
+///
+/// # Returns
+///
+/// The armored text, ending with a newline.
+pub fn armor(file: &[u8]) -> String {
+    let encoded = base64_encode(file, true);
+
+    let mut out = String::with_capacity(encoded.len() + encoded.len() / COLUMNS + 72);
+    out.push_str(core::str::from_utf8(BEGIN).unwrap());
+    out.push('\n');
+
+    for line in encoded.as_bytes().chunks(COLUMNS) {
+        out.push_str(core::str::from_utf8(line).unwrap());
+        out.push('\n');
+    }
+
+    out.push_str(core::str::from_utf8(END).unwrap());
+    out.push('\n');
+
+    out
+}
+
+/// Removes the ASCII armor from an age file.
+///
+/// # Parameters
+///
+/// - `text`: Armored file contents
+///
+/// # Returns
+///
+/// - `Ok(file)` with the binary age file
+/// - `Err(InvalidArmor)` if the armor is malformed or not canonical
+pub fn dearmor(text: &[u8]) -> Result<Vec<u8>, AgeError> {
+    let text = text.trim_ascii();
+
+    let lines: Vec<&[u8]> = text
+        .split(|&c| c == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .collect();
+
+    if lines.len() < 3 || lines[0] != BEGIN || lines[lines.len() - 1] != END {
+        return Err(AgeError::InvalidArmor);
+    }
+
+    let body = &lines[1..lines.len() - 1];
+    let mut encoded = Vec::with_capacity(body.len() * COLUMNS);
+
+    for (i, line) in body.iter().enumerate() {
+        let last = i + 1 == body.len();
+
+        let valid_len = if last {
+            !line.is_empty() && line.len() <= COLUMNS && line.len().is_multiple_of(4)
+        } else {
+            line.len() == COLUMNS && !line.contains(&b'=')
+        };
+
+        if !valid_len {
+            return Err(AgeError::InvalidArmor);
+        }
+
+        encoded.extend_from_slice(line);
+    }
+
+    base64_decode(&encoded, true).ok_or(AgeError::InvalidArmor)
+}
@@ -0,0 +1,478 @@
+//! age recipients, identities and file encryption.
+//!
+//! A random 16-byte *file key* encrypts the payload. It is wrapped once
+//! per recipient into a header stanza:
+//!
+//! - `X25519`: an ephemeral Diffie–Hellman with the recipient's public
+//!   key, the shared secret expanded with HKDF-SHA256
+//! - `scrypt`: a key derived from a passphrase with scrypt
+//!   (`r = 8`, `p = 1`, `N = 2^work_factor`)
+//!
+//! In both cases the file key is encrypted with ChaCha20-Poly1305 under a
+//! zero nonce, which is safe because each wrapping key is used once.
+
+use super::encoding::{base64_decode, base64_encode, bech32_decode, bech32_encode};
+use super::header::{self, Stanza};
+use super::stream::{self, NONCE_LEN};
+use crate::derivation::{ScryptParams, hkdf, scrypt};
+use crate::encryption::chacha20poly1305::{decrypt as aead_open, encrypt as aead_seal};
+use crate::keys::x25519::{x25519, x25519_base};
+use crate::rng::Csprng;
+
+/// Human-readable part of `age1…` recipients.
+const RECIPIENT_HRP: &str = "age";
+
+/// Human-readable part of `AGE-SECRET-KEY-1…` identities.
+const IDENTITY_HRP: &str = "age-secret-key-";
+
+/// HKDF info string for X25519 stanzas.
+const X25519_LABEL: &[u8] = b"age-encryption.org/v1/X25519";
+
+/// scrypt salt prefix for passphrase stanzas.
+const SCRYPT_LABEL: &[u8] = b"age-encryption.org/v1/scrypt";
+
+/// Default scrypt work factor used by the reference implementation.
+pub const DEFAULT_WORK_FACTOR: u8 = 18;
+
+/// Highest scrypt work factor accepted by default when decrypting.
+pub const DEFAULT_MAX_WORK_FACTOR: u8 = 22;
+
+/// Errors that can occur while encrypting or decrypting age files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgeError {
+    /// The ASCII armor is malformed.
+    InvalidArmor,
+    /// The header is malformed, or a stanza is invalid for its type.
+    InvalidHeader,
+    /// The file uses an age format version other than v1.
+    UnsupportedVersion,
+    /// A scrypt stanza exceeds the identity's maximum work factor.
+    WorkFactorTooLarge,
+    /// No identity could unwrap the file key.
+    NoMatchingIdentity,
+    /// The header MAC does not match.
+    HeaderMacMismatch,
+    /// The payload is truncated, extended or fails authentication.
+    InvalidPayload,
+    /// A recipient or identity string is not valid Bech32 for its type.
+    InvalidKey,
+    /// Encryption was requested without recipients, or with a scrypt
+    /// recipient alongside other recipients.
+    InvalidRecipients,
+}
+
+/// A recipient to encrypt a file to.
+#[derive(Debug, Clone, Copy)]
+pub enum Recipient<'a> {
+    /// An X25519 public key (`age1…`).
+    X25519([u8; 32]),
+
+    /// A passphrase. Must be the only recipient of the file.
+    Scrypt {
+        /// Passphrase bytes.
+        passphrase: &'a [u8],
+        /// Base-2 logarithm of the scrypt cost `N`.
+        work_factor: u8,
+    },
+}
+
+/// An identity to decrypt a file with.
+#[derive(Debug, Clone, Copy)]
+pub enum Identity<'a> {
+    /// An X25519 secret key (`AGE-SECRET-KEY-1…`).
+    X25519([u8; 32]),
+
+    /// A passphrase.
+    Scrypt {
+        /// Passphrase bytes.
+        passphrase: &'a [u8],
+        /// Highest work factor accepted from the file.
+        max_work_factor: u8,
+    },
+}
+
+/// Generates a random X25519 key pair.
+///
+/// # Returns
+///
+/// `(public, secret)`, both 32 bytes.
+pub fn generate_key_pair() -> ([u8; 32], [u8; 32]) {
+    let mut secret = [0u8; 32];
+    Csprng::new().fill_bytes(&mut secret);
+
+    (x25519_base(&secret), secret)
+}
+
+/// Encodes an X25519 public key as an `age1…` recipient string.
+pub fn encode_recipient(public: &[u8; 32]) -> String {
+    bech32_encode(RECIPIENT_HRP, public)
+}
+
+/// Decodes an `age1…` recipient string.
+///
+/// # Returns
+///
+/// - `Ok(public)` on success
+/// - `Err(InvalidKey)` if the string is not a valid recipient
+pub fn decode_recipient(text: &str) -> Result<[u8; 32], AgeError> {
+    decode_key(text, RECIPIENT_HRP)
+}
+
+/// Encodes an X25519 secret key as an `AGE-SECRET-KEY-1…` string.
+pub fn encode_identity(secret: &[u8; 32]) -> String {
+    bech32_encode(IDENTITY_HRP, secret).to_ascii_uppercase()
+}
+
+/// Decodes an `AGE-SECRET-KEY-1…` identity string.
+///
+/// # Returns
+///
+/// - `Ok(secret)` on success
+/// - `Err(InvalidKey)` if the string is not a valid identity
+pub fn decode_identity(text: &str) -> Result<[u8; 32], AgeError> {
+    decode_key(text, IDENTITY_HRP)
+}
+
+/// Encrypts `plaintext` to one or more recipients.
+///
+/// # Parameters
+///
+/// - `recipients`: X25519 keys, or a single passphrase
+/// - `plaintext`: Data to encrypt
+///
+/// # Returns
+///
+/// - `Ok(file)` with the binary age file
+/// - `Err(InvalidRecipients)` if `recipients` is empty, or mixes a
+///   passphrase with other recipients
+/// - `Err(WorkFactorTooLarge)` if a scrypt work factor is out of range
+pub fn encrypt(recipients: &[Recipient<'_>], plaintext: &[u8]) -> Result<Vec<u8>, AgeError> {
+    let has_scrypt = recipients
+        .iter()
+        .any(|r| matches!(r, Recipient::Scrypt { .. }));
+
+    if recipients.is_empty() || (has_scrypt && recipients.len() > 1) {
+        return Err(AgeError::InvalidRecipients);
+    }
+
+    let mut rng = Csprng::new();
+
+    let mut file_key = [0u8; 16];
+    rng.fill_bytes(&mut file_key);
+
+    let stanzas = recipients
+        .iter()
+        .map(|r| wrap(r, &file_key, &mut rng))
+        .collect::<Result<Vec<_>, _>>();
+
+    let stanzas = match stanzas {
+        Ok(s) => s,
+        Err(e) => {
+            file_key.fill(0);
+            return Err(e);
+        }
+    };
+
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill_bytes(&mut nonce);
+
+    let mut out = header::write(&stanzas, &file_key);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&stream::seal(&file_key, &nonce, plaintext));
+
+    file_key.fill(0);
+    Ok(out)
+}
+
+/// Decrypts a binary age file.
+///
+/// Armored files must first be passed through
+/// [`dearmor`](super::dearmor).
+///
+/// # Parameters
+///
+/// - `identities`: Keys and passphrases to try, in order
+/// - `file`: Binary age file
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(InvalidHeader | UnsupportedVersion)` for malformed headers
+/// - `Err(WorkFactorTooLarge)` if a scrypt stanza is too expensive
+/// - `Err(NoMatchingIdentity)` if no identity unwraps the file key
+/// - `Err(HeaderMacMismatch)` if the header was modified
+/// - `Err(InvalidPayload)` if the payload fails authentication
+///
+/// # Security Notes
+///
+/// - Nothing is returned unless the whole payload authenticates.
+pub fn decrypt(identities: &[Identity<'_>], file: &[u8]) -> Result<Vec<u8>, AgeError> {
+    let header = header::parse(file)?;
+
+    let scrypt_stanzas = header
+        .stanzas
+        .iter()
+        .filter(|s| s.args[0] == "scrypt")
+        .count();
+
+    if scrypt_stanzas > 0 && header.stanzas.len() > 1 {
+        return Err(AgeError::InvalidHeader);
+    }
+
+    let mut file_key = None;
+
+    'search: for identity in identities {
+        for stanza in &header.stanzas {
+            if let Some(key) = unwrap(identity, stanza)? {
+                file_key = Some(key);
+                break 'search;
+            }
+        }
+    }
+
+    let mut file_key = file_key.ok_or(AgeError::NoMatchingIdentity)?;
+    let result = open_payload(&file_key, file, &header);
+    file_key.fill(0);
+
+    result
+}
+
+/// Checks the header MAC and decrypts the payload.
+fn open_payload(
+    file_key: &[u8; 16],
+    file: &[u8],
+    header: &header::Header,
+) -> Result<Vec<u8>, AgeError> {
+    let expected = header::mac(file_key, &file[..header.mac_len]);
+
+    let mut diff = 0u8;
+    for (a, b) in expected.iter().zip(&header.mac) {
+        diff |= a ^ b;
+    }
+
+    if diff != 0 {
+        return Err(AgeError::HeaderMacMismatch);
+    }
+
+    let payload = &file[header.payload_start..];
+    if payload.len() < NONCE_LEN {
+        return Err(AgeError::InvalidHeader);
+    }
+
+    let (nonce, payload) = payload.split_at(NONCE_LEN);
+    stream::open(file_key, nonce.try_into().unwrap(), payload)
+}
+
+/// Wraps the file key for one recipient.
+fn wrap(
+    recipient: &Recipient<'_>,
+    file_key: &[u8; 16],
+    rng: &mut Csprng,
+) -> Result<Stanza, AgeError> {
+    match *recipient {
+        Recipient::X25519(public) => {
+            let mut ephemeral = [0u8; 32];
+            rng.fill_bytes(&mut ephemeral);
+
+            let share = x25519_base(&ephemeral);
+            let mut shared = x25519(&ephemeral, &public);
+            ephemeral.fill(0);
+
+            if shared == [0u8; 32] {
+                return Err(AgeError::InvalidKey);
+            }
+
+            let mut key = x25519_wrap_key(&shared, &share, &public);
+            shared.fill(0);
+
+            let body = seal_file_key(&key, file_key);
+            key.fill(0);
+
+            Ok(Stanza {
+                args: vec!["X25519".into(), base64_encode(&share, false)],
+                body,
+            })
+        }
+
+        Recipient::Scrypt {
+            passphrase,
+            work_factor,
+        } => {
+            if !(1..64).contains(&work_factor) {
+                return Err(AgeError::WorkFactorTooLarge);
+            }
+
+            let mut salt = [0u8; 16];
+            rng.fill_bytes(&mut salt);
+
+            let mut key = scrypt_wrap_key(passphrase, &salt, work_factor)?;
+            let body = seal_file_key(&key, file_key);
+            key.fill(0);
+
+            Ok(Stanza {
+                args: vec![
+                    "scrypt".into(),
+                    base64_encode(&salt, false),
+                    work_factor.to_string(),
+                ],
+                body,
+            })
+        }
+    }
+}
+
+/// Tries to unwrap the file key from one stanza with one identity.
+///
+/// Returns `Ok(None)` if the stanza is not addressed to this identity,
+/// and an error if the stanza is malformed for its type.
+fn unwrap(identity: &Identity<'_>, stanza: &Stanza) -> Result<Option<[u8; 16]>, AgeError> {
+    match (*identity, stanza.args[0].as_str()) {
+        (Identity::X25519(secret), "X25519") => {
+            if stanza.args.len() != 2 || stanza.body.len() != 32 {
+                return Err(AgeError::InvalidHeader);
+            }
+
+            let share: [u8; 32] = base64_decode(stanza.args[1].as_bytes(), false)
+                .and_then(|s| s.try_into().ok())
+                .ok_or(AgeError::InvalidHeader)?;
+
+            let mut shared = x25519(&secret, &share);
+            if shared == [0u8; 32] {
+                return Err(AgeError::InvalidHeader);
+            }
+
+            let mut key = x25519_wrap_key(&shared, &share, &x25519_base(&secret));
+            shared.fill(0);
+
+            let file_key = open_file_key(&key, &stanza.body);
+            key.fill(0);
+
+            Ok(file_key)
+        }
+
+        (
+            Identity::Scrypt {
+                passphrase,
+                max_work_factor,
+            },
+            "scrypt",
+        ) => {
+            if stanza.args.len() != 3 || stanza.body.len() != 32 {
+                return Err(AgeError::InvalidHeader);
+            }
+
+            let salt: [u8; 16] = base64_decode(stanza.args[1].as_bytes(), false)
+                .and_then(|s| s.try_into().ok())
+                .ok_or(AgeError::InvalidHeader)?;
+
+            let work_factor = parse_work_factor(&stanza.args[2])?;
+            if work_factor > max_work_factor {
+                return Err(AgeError::WorkFactorTooLarge);
+            }
+
+            let mut key = scrypt_wrap_key(passphrase, &salt, work_factor)?;
+            let file_key = open_file_key(&key, &stanza.body);
+            key.fill(0);
+
+            Ok(file_key)
+        }
+
+        _ => Ok(None),
+    }
+}
+
+/// Parses a canonical decimal work factor in `1..64`.
+fn parse_work_factor(text: &str) -> Result<u8, AgeError> {
+    let canonical = !text.is_empty()
+        && text.len() <= 2
+        && text.bytes().all(|c| c.is_ascii_digit())
+        && !text.starts_with('0');
+
+    if !canonical {
+        return Err(AgeError::InvalidHeader);
+    }
+
+    let value: u8 = text.parse().map_err(|_| AgeError::InvalidHeader)?;
+    if value >= 64 {
+        return Err(AgeError::InvalidHeader);
+    }
+
+    Ok(value)
+}
+
+/// Derives the X25519 stanza wrapping key.
+fn x25519_wrap_key(shared: &[u8; 32], share: &[u8; 32], public: &[u8; 32]) -> [u8; 32] {
+    let mut salt = [0u8; 64];
+    salt[..32].copy_from_slice(share);
+    salt[32..].copy_from_slice(public);
+
+    let mut okm = hkdf(&salt, shared, X25519_LABEL, 32).unwrap();
+    let key = okm[..].try_into().unwrap();
+    okm.fill(0);
+
+    key
+}
+
+/// Derives the scrypt stanza wrapping key.
+fn scrypt_wrap_key(
+    passphrase: &[u8],
+    salt: &[u8; 16],
+    work_factor: u8,
+) -> Result<[u8; 32], AgeError> {
+    let mut full_salt = Vec::with_capacity(SCRYPT_LABEL.len() + 16);
+    full_salt.extend_from_slice(SCRYPT_LABEL);
+    full_salt.extend_from_slice(salt);
+
+    let params = ScryptParams {
+        log_n: work_factor,
+        r: 8,
+        p: 1,
+        len: 32,
+    };
+
+    let mut okm = scrypt(passphrase, &full_salt, &params).map_err(|_| AgeError::InvalidHeader)?;
+    let key = okm[..].try_into().unwrap();
+    okm.fill(0);
+
+    Ok(key)
+}
+
+/// Encrypts the file key under a single-use wrapping key.
+fn seal_file_key(key: &[u8; 32], file_key: &[u8; 16]) -> Vec<u8> {
+    let mut body = vec![0u8; 32];
+    let (ciphertext, tag) = body.split_at_mut(16);
+
+    let mut tag_bytes = [0u8; 16];
+    aead_seal(key, &[0u8; 12], file_key, ciphertext, &mut tag_bytes);
+    tag.copy_from_slice(&tag_bytes);
+
+    body
+}
+
+/// Decrypts a 32-byte stanza body, returning `None` on failure.
+fn open_file_key(key: &[u8; 32], body: &[u8]) -> Option<[u8; 16]> {
+    let (ciphertext, tag) = body.split_at(16);
+
+    let mut file_key = [0u8; 16];
+    aead_open(
+        key,
+        &[0u8; 12],
+        ciphertext,
+        tag.try_into().unwrap(),
+        &mut file_key,
+    )
+    .ok()?;
+
+    Some(file_key)
+}
+
+/// Decodes a 32-byte Bech32 key with the expected human-readable part.
+fn decode_key(text: &str, hrp: &str) -> Result<[u8; 32], AgeError> {
+    let (found, data) = bech32_decode(text).ok_or(AgeError::InvalidKey)?;
+
+    if found != hrp {
+        return Err(AgeError::InvalidKey);
+    }
+
+    data.try_into().map_err(|_| AgeError::InvalidKey)
+}
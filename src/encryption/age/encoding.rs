@@ -0,0 +1,233 @@
+//! Text encodings used by the age format.
+//!
+//! - Base64 (RFC 4648, standard alphabet), unpadded in the header and
+//!   padded in the ASCII armor. Decoding is strict: only canonical
+//!   encodings are accepted, so every byte string has exactly one valid
+//!   text form.
+//! - Bech32 (BIP 173), used for `age1…` recipients and
+//!   `AGE-SECRET-KEY-1…` identities.
+
+/// Standard Base64 alphabet.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Bech32 data alphabet.
+const BECH32: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Bech32 checksum generator coefficients.
+const BECH32_GEN: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+
+/// Encodes `data` as Base64, with or without `=` padding.
+pub(crate) fn base64_encode(data: &[u8], pad: bool) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let mut buf = [0u8; 3];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let n = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]);
+
+        let chars = chunk.len() + 1;
+        for i in 0..4 {
+            if i < chars {
+                out.push(BASE64[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+            } else if pad {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+/// Decodes canonical Base64.
+///
+/// With `pad`, the input length must be a multiple of four and end with
+/// the exact padding; without it, no `=` may appear. In both cases the
+/// unused low bits of the final character must be zero.
+pub(crate) fn base64_decode(text: &[u8], pad: bool) -> Option<Vec<u8>> {
+    let body = if pad {
+        if !text.len().is_multiple_of(4) {
+            return None;
+        }
+
+        let padding = text
+            .iter()
+            .rev()
+            .take(2)
+            .take_while(|&&c| c == b'=')
+            .count();
+        let body = &text[..text.len() - padding];
+
+        // Exactly the padding needed to complete the last group.
+        if (4 - body.len() % 4) % 4 != padding {
+            return None;
+        }
+
+        body
+    } else {
+        text
+    };
+
+    if body.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(body.len() * 3 / 4);
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+
+    for &c in body {
+        acc = (acc << 6) | base64_value(c)? as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+
+    // Leftover bits must be zero for the encoding to be canonical.
+    if acc & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+
+    Some(out)
+}
+
+/// Returns the 6-bit value of a Base64 character.
+fn base64_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `c` belongs to the Base64 alphabet (without `=`).
+pub(crate) fn is_base64_char(c: u8) -> bool {
+    base64_value(c).is_some()
+}
+
+/// Encodes `data` as lowercase Bech32 with the given human-readable part.
+pub(crate) fn bech32_encode(hrp: &str, data: &[u8]) -> String {
+    let mut values = Vec::with_capacity(data.len() * 8 / 5 + 1);
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+
+    for &b in data {
+        acc = (acc << 8) | b as u32;
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            values.push(((acc >> bits) & 0x1f) as u8);
+        }
+    }
+
+    if bits > 0 {
+        values.push(((acc << (5 - bits)) & 0x1f) as u8);
+    }
+
+    let checksum = bech32_checksum(hrp.as_bytes(), &values);
+
+    let mut out = String::with_capacity(hrp.len() + 1 + values.len() + 6);
+    out.push_str(hrp);
+    out.push('1');
+    for v in values.iter().chain(&checksum) {
+        out.push(BECH32[*v as usize] as char);
+    }
+
+    out
+}
+
+/// Decodes a Bech32 string into its lowercase human-readable part and
+/// data bytes.
+///
+/// Mixed-case strings, invalid checksums and non-zero padding bits are
+/// rejected.
+pub(crate) fn bech32_decode(text: &str) -> Option<(String, Vec<u8>)> {
+    let has_lower = text.bytes().any(|c| c.is_ascii_lowercase());
+    let has_upper = text.bytes().any(|c| c.is_ascii_uppercase());
+
+    if has_lower && has_upper {
+        return None;
+    }
+
+    let text = text.to_ascii_lowercase();
+    let sep = text.rfind('1')?;
+    let (hrp, data) = (&text[..sep], &text[sep + 1..]);
+
+    if hrp.is_empty() || data.len() < 6 || hrp.bytes().any(|c| !(33..=126).contains(&c)) {
+        return None;
+    }
+
+    let values = data
+        .bytes()
+        .map(|c| BECH32.iter().position(|&a| a == c).map(|v| v as u8))
+        .collect::<Option<Vec<u8>>>()?;
+
+    if bech32_polymod(hrp.as_bytes(), &values) != 1 {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(values.len() * 5 / 8);
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+
+    for &v in &values[..values.len() - 6] {
+        acc = (acc << 5) | v as u32;
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+
+    if bits >= 5 || acc & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+
+    Some((hrp.to_string(), out))
+}
+
+/// Computes the six checksum characters for `hrp` and `values`.
+fn bech32_checksum(hrp: &[u8], values: &[u8]) -> [u8; 6] {
+    let mut padded = values.to_vec();
+    padded.extend_from_slice(&[0; 6]);
+
+    let modulus = bech32_polymod(hrp, &padded) ^ 1;
+
+    core::array::from_fn(|i| ((modulus >> (5 * (5 - i))) & 0x1f) as u8)
+}
+
+/// BCH checksum over the expanded human-readable part and the data.
+fn bech32_polymod(hrp: &[u8], values: &[u8]) -> u32 {
+    let expanded = hrp
+        .iter()
+        .map(|c| c >> 5)
+        .chain(core::iter::once(0))
+        .chain(hrp.iter().map(|c| c & 0x1f));
+
+    let mut chk = 1u32;
+    for v in expanded.chain(values.iter().copied()) {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ v as u32;
+
+        for (i, g) in BECH32_GEN.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+
+    chk
+}
@@ -0,0 +1,193 @@
+//! age header parsing and serialization.
+//!
+//! ```text
+//! age-encryption.org/v1
+//! -> X25519 <share>
+//! <wrapped file key, Base64, 64 columns>
+//! -> scrypt <salt> <work factor>
+//! <wrapped file key>
+//! --- <MAC>
+//! ```
+//!
+//! Each stanza starts with `-> ` followed by space-separated arguments,
+//! and its body is unpadded Base64 split into 64-column lines. The body
+//! always ends with a line shorter than 64 columns, which may be empty.
+//!
+//! The MAC is HMAC-SHA256 over the header up to and including `---`,
+//! keyed with `HKDF-SHA256(ikm = file key, info = "header")`.
+
+use super::AgeError;
+use super::encoding::{base64_decode, base64_encode, is_base64_char};
+use crate::derivation::hkdf;
+use crate::hash::hmac_sha256;
+
+/// Version line of the only supported format.
+const VERSION_LINE: &[u8] = b"age-encryption.org/v1";
+
+/// Common prefix of all version lines.
+const VERSION_PREFIX: &[u8] = b"age-encryption.org/";
+
+/// Number of Base64 characters per stanza body line.
+const COLUMNS: usize = 64;
+
+/// A recipient stanza.
+pub(crate) struct Stanza {
+    /// Arguments, the first one being the stanza type.
+    pub(crate) args: Vec<String>,
+
+    /// Decoded body.
+    pub(crate) body: Vec<u8>,
+}
+
+/// A parsed header.
+pub(crate) struct Header {
+    /// Recipient stanzas, in file order.
+    pub(crate) stanzas: Vec<Stanza>,
+
+    /// Header MAC.
+    pub(crate) mac: [u8; 32],
+
+    /// Length of the header prefix covered by the MAC.
+    pub(crate) mac_len: usize,
+
+    /// Offset of the payload (the byte after the MAC line).
+    pub(crate) payload_start: usize,
+}
+
+impl Stanza {
+    /// Serializes the stanza, including the final short body line.
+    pub(crate) fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(b"->");
+        for arg in &self.args {
+            out.push(b' ');
+            out.extend_from_slice(arg.as_bytes());
+        }
+        out.push(b'\n');
+
+        let body = base64_encode(&self.body, false);
+        for line in body.as_bytes().chunks(COLUMNS) {
+            out.extend_from_slice(line);
+            out.push(b'\n');
+        }
+
+        if body.len().is_multiple_of(COLUMNS) {
+            out.push(b'\n');
+        }
+    }
+}
+
+/// Serializes a header and its MAC.
+pub(crate) fn write(stanzas: &[Stanza], file_key: &[u8; 16]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(VERSION_LINE);
+    out.push(b'\n');
+
+    for stanza in stanzas {
+        stanza.write(&mut out);
+    }
+
+    out.extend_from_slice(b"---");
+    let tag = mac(file_key, &out);
+
+    out.push(b' ');
+    out.extend_from_slice(base64_encode(&tag, false).as_bytes());
+    out.push(b'\n');
+
+    out
+}
+
+/// Parses the header at the start of `file`.
+pub(crate) fn parse(file: &[u8]) -> Result<Header, AgeError> {
+    let mut pos = 0;
+
+    let version = next_line(file, &mut pos)?;
+    if version != VERSION_LINE {
+        return Err(if version.starts_with(VERSION_PREFIX) {
+            AgeError::UnsupportedVersion
+        } else {
+            AgeError::InvalidHeader
+        });
+    }
+
+    let mut stanzas = Vec::new();
+
+    loop {
+        let start = pos;
+        let line = next_line(file, &mut pos)?;
+
+        if let Some(rest) = line.strip_prefix(b"-> ") {
+            stanzas.push(parse_stanza(rest, file, &mut pos)?);
+            continue;
+        }
+
+        let encoded = line.strip_prefix(b"--- ").ok_or(AgeError::InvalidHeader)?;
+
+        if stanzas.is_empty() || encoded.len() != 43 {
+            return Err(AgeError::InvalidHeader);
+        }
+
+        let mac = base64_decode(encoded, false).ok_or(AgeError::InvalidHeader)?;
+
+        return Ok(Header {
+            stanzas,
+            mac: mac.try_into().unwrap(),
+            mac_len: start + 3,
+            payload_start: pos,
+        });
+    }
+}
+
+/// Computes the header MAC over `header`.
+pub(crate) fn mac(file_key: &[u8; 16], header: &[u8]) -> [u8; 32] {
+    let mut key = hkdf(&[], file_key, b"header", 32).unwrap();
+    let tag = hmac_sha256(&key, header);
+    key.fill(0);
+
+    tag
+}
+
+/// Parses the arguments after `-> ` and the following body lines.
+fn parse_stanza(args: &[u8], file: &[u8], pos: &mut usize) -> Result<Stanza, AgeError> {
+    let args = args
+        .split(|&c| c == b' ')
+        .map(|arg| {
+            if arg.is_empty() || arg.iter().any(|c| !(0x21..=0x7e).contains(c)) {
+                return Err(AgeError::InvalidHeader);
+            }
+
+            Ok(String::from_utf8(arg.to_vec()).unwrap())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut encoded = Vec::new();
+
+    loop {
+        let line = next_line(file, pos)?;
+
+        if line.len() > COLUMNS || !line.iter().all(|&c| is_base64_char(c)) {
+            return Err(AgeError::InvalidHeader);
+        }
+
+        encoded.extend_from_slice(line);
+
+        if line.len() < COLUMNS {
+            break;
+        }
+    }
+
+    let body = base64_decode(&encoded, false).ok_or(AgeError::InvalidHeader)?;
+
+    Ok(Stanza { args, body })
+}
+
+/// Returns the next LF-terminated line, without the terminator.
+fn next_line<'a>(file: &'a [u8], pos: &mut usize) -> Result<&'a [u8], AgeError> {
+    let rest = &file[*pos..];
+    let end = rest
+        .iter()
+        .position(|&c| c == b'\n')
+        .ok_or(AgeError::InvalidHeader)?;
+
+    *pos += end + 1;
+    Ok(&rest[..end])
+}
@@ -0,0 +1,51 @@
+//! age v1 file encryption (<https://age-encryption.org/v1>).
+//!
+//! This module reads and writes files in the age format, so that data
+//! can be exchanged with the `age` and `rage` tools:
+//!
+//! - X25519 recipients (`age1…`) and identities (`AGE-SECRET-KEY-1…`)
+//! - scrypt passphrase recipients
+//! - multiple recipients per file
+//! - the STREAM payload (64 KiB ChaCha20-Poly1305 chunks)
+//! - ASCII armor
+//!
+//! ## Structure
+//!
+//! - `core`: recipients, identities, key encoding, `encrypt` / `decrypt`
+//! - `header`: header grammar and MAC
+//! - `stream`: chunked payload encryption
+//! - `armor`: PEM-like ASCII armor
+//! - `encoding`: strict Base64 and Bech32
+//!
+//! ## Notes
+//!
+//! - Passphrase recipients use scrypt, as the format requires. The
+//!   crate's Argon2id does not follow RFC 9106 and would not
+//!   interoperate, so no Argon2 stanza is offered.
+//! - A scrypt stanza must be the only stanza in a file, both when
+//!   encrypting and when decrypting.
+//! - Parsing is strict: non-canonical Base64, malformed stanzas and
+//!   unknown versions are rejected. Unknown stanza types are skipped.
+//! - Files are processed in memory; nothing is returned before the whole
+//!   payload has been authenticated.
+
+/// ASCII armor.
+pub(crate) mod armor;
+
+/// Recipients, identities and file encryption.
+pub(crate) mod core;
+
+/// Base64 and Bech32.
+pub(crate) mod encoding;
+
+/// Header grammar and MAC.
+pub(crate) mod header;
+
+/// STREAM payload encryption.
+pub(crate) mod stream;
+
+pub use armor::{armor, dearmor};
+pub use core::{
+    AgeError, DEFAULT_MAX_WORK_FACTOR, DEFAULT_WORK_FACTOR, Identity, Recipient, decode_identity,
+    decode_recipient, decrypt, encode_identity, encode_recipient, encrypt, generate_key_pair,
+};
@@ -0,0 +1,142 @@
+//! age STREAM payload encryption.
+//!
+//! The payload starts with a 16-byte random nonce, from which the payload
+//! key is derived:
+//!
+//! ```text
+//! payload_key = HKDF-SHA256(ikm = file key, salt = nonce, info = "payload")
+//! ```
+//!
+//! The plaintext is split into 64 KiB chunks, each encrypted with
+//! ChaCha20-Poly1305 under the nonce `counter (11 bytes, big-endian) ||
+//! last`, where `last` is `0x01` for the final chunk and `0x00` otherwise.
+//!
+//! ## Notes
+//!
+//! - The final chunk may only be empty if the whole plaintext is empty.
+//! - The final-chunk flag prevents truncation and extension attacks.
+
+use super::AgeError;
+use crate::derivation::hkdf;
+use crate::encryption::chacha20poly1305::{decrypt, encrypt};
+
+/// Plaintext chunk size.
+const CHUNK_LEN: usize = 64 * 1024;
+
+/// Poly1305 tag length.
+const TAG_LEN: usize = 16;
+
+/// Payload nonce length.
+pub(crate) const NONCE_LEN: usize = 16;
+
+/// Encrypts `plaintext` into a STREAM payload (without the leading nonce).
+pub(crate) fn seal(file_key: &[u8; 16], nonce: &[u8; NONCE_LEN], plaintext: &[u8]) -> Vec<u8> {
+    let mut key = payload_key(file_key, nonce);
+
+    let chunks = plaintext.len().div_ceil(CHUNK_LEN).max(1);
+    let mut out = Vec::with_capacity(plaintext.len() + chunks * TAG_LEN);
+
+    for index in 0..chunks {
+        let start = index * CHUNK_LEN;
+        let chunk = &plaintext[start..(start + CHUNK_LEN).min(plaintext.len())];
+
+        let mut ciphertext = vec![0u8; chunk.len()];
+        let mut tag = [0u8; TAG_LEN];
+        encrypt(
+            &key,
+            &chunk_nonce(index as u64, index + 1 == chunks),
+            chunk,
+            &mut ciphertext,
+            &mut tag,
+        );
+
+        out.extend_from_slice(&ciphertext);
+        out.extend_from_slice(&tag);
+    }
+
+    key.fill(0);
+    out
+}
+
+/// Decrypts a STREAM payload (without the leading nonce).
+pub(crate) fn open(
+    file_key: &[u8; 16],
+    nonce: &[u8; NONCE_LEN],
+    payload: &[u8],
+) -> Result<Vec<u8>, AgeError> {
+    if payload.is_empty() {
+        return Err(AgeError::InvalidPayload);
+    }
+
+    let mut key = payload_key(file_key, nonce);
+    let mut out = Vec::with_capacity(payload.len());
+    let mut rest = payload;
+    let mut index = 0u64;
+
+    let result = loop {
+        let take = rest.len().min(CHUNK_LEN + TAG_LEN);
+        let (chunk, tail) = rest.split_at(take);
+        rest = tail;
+
+        if chunk.len() < TAG_LEN {
+            break Err(AgeError::InvalidPayload);
+        }
+
+        let last = rest.is_empty();
+        let (ciphertext, tag) = chunk.split_at(chunk.len() - TAG_LEN);
+
+        let mut plaintext = vec![0u8; ciphertext.len()];
+        if decrypt(
+            &key,
+            &chunk_nonce(index, last),
+            ciphertext,
+            tag.try_into().unwrap(),
+            &mut plaintext,
+        )
+        .is_err()
+        {
+            break Err(AgeError::InvalidPayload);
+        }
+
+        out.extend_from_slice(&plaintext);
+
+        if last {
+            if plaintext.is_empty() && index > 0 {
+                break Err(AgeError::InvalidPayload);
+            }
+
+            break Ok(());
+        }
+
+        index += 1;
+    };
+
+    key.fill(0);
+
+    match result {
+        Ok(()) => Ok(out),
+        Err(e) => {
+            out.fill(0);
+            Err(e)
+        }
+    }
+}
+
+/// Derives the payload key from the file key and the payload nonce.
+fn payload_key(file_key: &[u8; 16], nonce: &[u8; NONCE_LEN]) -> [u8; 32] {
+    let mut okm = hkdf(nonce, file_key, b"payload", 32).unwrap();
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&okm);
+    okm.fill(0);
+
+    key
+}
+
+/// Builds the ChaCha20-Poly1305 nonce for chunk `index`.
+fn chunk_nonce(index: u64, last: bool) -> [u8; 12] {
+    let mut nonce = [0u8; 12];
+    nonce[3..11].copy_from_slice(&index.to_be_bytes());
+    nonce[11] = last as u8;
+    nonce
+}
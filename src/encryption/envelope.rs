@@ -0,0 +1,324 @@
+//! Versioned ciphertext envelope.
+//!
+//! An envelope packs everything needed to decrypt a message, except the
+//! key itself, into a single self-describing byte string:
+//!
+//! ```text
+//! +-------+---------+-----------+--------+-------+------------+-----+
+//! | magic | version | algorithm | key id | nonce | ciphertext | tag |
+//! |   4   |    1    |     1     |   4    |  12   |     n      | 16  |
+//! +-------+---------+-----------+--------+-------+------------+-----+
+//! \________________ header (22 bytes) _________/
+//! ```
+//!
+//! - `magic`: the ASCII bytes `CRPT`
+//! - `version`: format version, currently `1`
+//! - `algorithm`: AEAD identifier (see [`Algorithm`])
+//! - `key id`: big-endian 32-bit identifier chosen by the caller, used to
+//!   select the right key during rotation
+//! - `nonce`: 96-bit nonce, generated with `Csprng` on sealing
+//!
+//! The whole header is authenticated as associated data, followed by the
+//! caller's own AAD. Changing the algorithm, the key id or the version
+//! therefore causes authentication to fail.
+//!
+//! ## Design goals
+//!
+//! - Algorithm agility and key rotation without out-of-band metadata
+//! - Strict parsing: unknown versions and algorithms are rejected before
+//!   any decryption is attempted
+//! - Fixed-size header, no variable-length encodings
+//!
+//! ## Notes
+//!
+//! - All algorithms use 256-bit keys, 96-bit nonces and 128-bit tags.
+//! - Random 96-bit nonces are safe for about 2³² messages per key; rotate
+//!   keys (and key ids) well before that.
+
+use super::aead::{Aead, AeadError, Aes256Gcm, Aes256GcmSiv, ChaCha20Poly1305};
+use crate::rng::Csprng;
+
+/// Magic bytes identifying an envelope.
+pub const MAGIC: [u8; 4] = *b"CRPT";
+
+/// Current (and only supported) envelope version.
+pub const VERSION: u8 = 1;
+
+/// Length of the authenticated header, in bytes.
+pub const HEADER_LEN: usize = 4 + 1 + 1 + 4 + 12;
+
+/// Length of the authentication tag, in bytes.
+pub const TAG_LEN: usize = 16;
+
+/// Errors that can occur while parsing, sealing or opening an envelope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvelopeError {
+    /// The input is shorter than a header and a tag.
+    Truncated,
+    /// The magic bytes do not match.
+    InvalidMagic,
+    /// The version is not supported by this implementation.
+    UnsupportedVersion,
+    /// The algorithm identifier is unknown.
+    UnknownAlgorithm,
+    /// The message exceeds the algorithm's length limit.
+    InvalidLength,
+    /// The header or the ciphertext failed authentication.
+    AuthenticationFailed,
+}
+
+/// AEAD algorithm used to protect the envelope payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    /// ChaCha20-Poly1305 (RFC 8439), identifier `0x01`.
+    ChaCha20Poly1305,
+    /// AES-256-GCM (NIST SP 800-38D), identifier `0x02`.
+    Aes256Gcm,
+    /// AES-256-GCM-SIV (RFC 8452), identifier `0x03`.
+    Aes256GcmSiv,
+}
+
+impl Algorithm {
+    /// Returns the wire identifier of the algorithm.
+    pub fn id(self) -> u8 {
+        match self {
+            Algorithm::ChaCha20Poly1305 => 0x01,
+            Algorithm::Aes256Gcm => 0x02,
+            Algorithm::Aes256GcmSiv => 0x03,
+        }
+    }
+
+    /// Returns the algorithm matching a wire identifier, if any.
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0x01 => Some(Algorithm::ChaCha20Poly1305),
+            0x02 => Some(Algorithm::Aes256Gcm),
+            0x03 => Some(Algorithm::Aes256GcmSiv),
+            _ => None,
+        }
+    }
+}
+
+/// Parsed envelope header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    /// AEAD algorithm protecting the payload.
+    pub algorithm: Algorithm,
+
+    /// Identifier of the key the payload was sealed with.
+    pub key_id: u32,
+
+    /// Nonce used for this envelope.
+    pub nonce: [u8; 12],
+}
+
+impl Header {
+    /// Serializes the header, including magic and version.
+    pub fn to_bytes(&self) -> [u8; HEADER_LEN] {
+        let mut out = [0u8; HEADER_LEN];
+        out[..4].copy_from_slice(&MAGIC);
+        out[4] = VERSION;
+        out[5] = self.algorithm.id();
+        out[6..10].copy_from_slice(&self.key_id.to_be_bytes());
+        out[10..].copy_from_slice(&self.nonce);
+        out
+    }
+}
+
+/// Borrowed view of a parsed, not yet authenticated envelope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Envelope<'a> {
+    /// Parsed header fields.
+    pub header: Header,
+
+    /// Raw header bytes, authenticated as associated data.
+    header_bytes: &'a [u8],
+
+    /// Encrypted payload.
+    ciphertext: &'a [u8],
+
+    /// Authentication tag.
+    tag: &'a [u8; TAG_LEN],
+}
+
+impl<'a> Envelope<'a> {
+    /// Parses an envelope without decrypting it.
+    ///
+    /// This lets the caller read [`Header::key_id`] to select a key
+    /// before calling [`open`](Self::open). The header is **not** trusted
+    /// until `open` succeeds.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(envelope)` if the framing is well-formed
+    /// - `Err(Truncated)` if the input is too short
+    /// - `Err(InvalidMagic)` if the magic bytes do not match
+    /// - `Err(UnsupportedVersion)` if the version is not [`VERSION`]
+    /// - `Err(UnknownAlgorithm)` if the algorithm identifier is unknown
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, EnvelopeError> {
+        if bytes.len() < HEADER_LEN + TAG_LEN {
+            return Err(EnvelopeError::Truncated);
+        }
+
+        if bytes[..4] != MAGIC {
+            return Err(EnvelopeError::InvalidMagic);
+        }
+
+        if bytes[4] != VERSION {
+            return Err(EnvelopeError::UnsupportedVersion);
+        }
+
+        let algorithm = Algorithm::from_id(bytes[5]).ok_or(EnvelopeError::UnknownAlgorithm)?;
+        let key_id = u32::from_be_bytes(bytes[6..10].try_into().unwrap());
+        let nonce = bytes[10..HEADER_LEN].try_into().unwrap();
+
+        let (header_bytes, rest) = bytes.split_at(HEADER_LEN);
+        let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+
+        Ok(Self {
+            header: Header {
+                algorithm,
+                key_id,
+                nonce,
+            },
+            header_bytes,
+            ciphertext,
+            tag: tag.try_into().unwrap(),
+        })
+    }
+
+    /// Authenticates and decrypts the envelope.
+    ///
+    /// # Parameters
+    ///
+    /// - `key`: 256-bit key matching `header.key_id`
+    /// - `aad`: Caller associated data supplied at sealing time
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(plaintext)` on success
+    /// - `Err(AuthenticationFailed)` if the key, the AAD, the header or
+    ///   the ciphertext does not match
+    pub fn open(&self, key: &[u8; 32], aad: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+        let full_aad = [self.header_bytes, aad].concat();
+        let nonce = &self.header.nonce;
+
+        let mut plaintext = self.ciphertext.to_vec();
+
+        let ok = match self.header.algorithm {
+            Algorithm::ChaCha20Poly1305 => {
+                open_with::<ChaCha20Poly1305>(key, nonce, &full_aad, &mut plaintext, self.tag)
+            }
+            Algorithm::Aes256Gcm => {
+                open_with::<Aes256Gcm>(key, nonce, &full_aad, &mut plaintext, self.tag)
+            }
+            Algorithm::Aes256GcmSiv => {
+                open_with::<Aes256GcmSiv>(key, nonce, &full_aad, &mut plaintext, self.tag)
+            }
+        }
+        .is_ok();
+
+        if !ok {
+            plaintext.fill(0);
+            return Err(EnvelopeError::AuthenticationFailed);
+        }
+
+        Ok(plaintext)
+    }
+}
+
+/// Encrypts a message into a new envelope.
+///
+/// A fresh nonce is drawn from [`Csprng`] for every call.
+///
+/// # Parameters
+///
+/// - `algorithm`: AEAD used for the payload
+/// - `key_id`: Identifier stored in the header to select the key later
+/// - `key`: 256-bit secret key
+/// - `aad`: Caller associated data (authenticated, not stored)
+/// - `plaintext`: Message to encrypt
+///
+/// # Returns
+///
+/// - `Ok(envelope)` with `HEADER_LEN + plaintext.len() + TAG_LEN` bytes
+/// - `Err(InvalidLength)` if the message exceeds the algorithm's limit
+pub fn seal(
+    algorithm: Algorithm,
+    key_id: u32,
+    key: &[u8; 32],
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, EnvelopeError> {
+    let mut nonce = [0u8; 12];
+    Csprng::new().fill_bytes(&mut nonce);
+
+    let header = Header {
+        algorithm,
+        key_id,
+        nonce,
+    }
+    .to_bytes();
+
+    let full_aad = [&header[..], aad].concat();
+
+    let mut out = vec![0u8; HEADER_LEN + plaintext.len() + TAG_LEN];
+    out[..HEADER_LEN].copy_from_slice(&header);
+
+    let (ciphertext, tag) = out[HEADER_LEN..].split_at_mut(plaintext.len());
+    ciphertext.copy_from_slice(plaintext);
+
+    match algorithm {
+        Algorithm::ChaCha20Poly1305 => {
+            seal_with::<ChaCha20Poly1305>(key, &nonce, &full_aad, ciphertext, tag)
+        }
+        Algorithm::Aes256Gcm => seal_with::<Aes256Gcm>(key, &nonce, &full_aad, ciphertext, tag),
+        Algorithm::Aes256GcmSiv => {
+            seal_with::<Aes256GcmSiv>(key, &nonce, &full_aad, ciphertext, tag)
+        }
+    }
+    .map_err(|_| EnvelopeError::InvalidLength)?;
+
+    Ok(out)
+}
+
+/// Encrypts `buffer` in place with cipher `A`.
+fn seal_with<A: Aead>(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &mut [u8],
+) -> Result<(), AeadError> {
+    A::new(key)?.seal_in_place_detached(nonce, aad, buffer, tag)
+}
+
+/// Verifies and decrypts `buffer` in place with cipher `A`.
+fn open_with<A: Aead>(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &[u8; TAG_LEN],
+) -> Result<(), AeadError> {
+    A::new(key)?.open_in_place_detached(nonce, aad, buffer, tag)
+}
+
+/// Parses and decrypts an envelope in one step.
+///
+/// Use [`Envelope::parse`] instead when the key must be selected from the
+/// header's key id.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `aad`: Caller associated data supplied at sealing time
+/// - `envelope`: Serialized envelope
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(_)` with the parsing or authentication error otherwise
+pub fn open(key: &[u8; 32], aad: &[u8], envelope: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+    Envelope::parse(envelope)?.open(key, aad)
+}
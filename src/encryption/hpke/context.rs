@@ -0,0 +1,231 @@
+//! HPKE key schedule and encryption contexts (RFC 9180, Sections 5.1–5.3).
+//!
+//! The key schedule turns the KEM shared secret, the application `info`
+//! and the optional PSK into:
+//!
+//! - an AEAD key,
+//! - a base nonce, XORed with the sequence number for each message,
+//! - an exporter secret for deriving additional keys.
+
+use super::core::{HpkeError, SUITE_ID, labeled_expand, labeled_extract};
+use crate::derivation::HKDF_MAX_OUTPUT;
+use crate::encryption::chacha20poly1305::{decrypt_with_aad, encrypt_with_aad};
+
+/// Secrets produced by the key schedule, shared by both context types.
+struct Secrets {
+    /// ChaCha20-Poly1305 key.
+    key: [u8; 32],
+
+    /// Base nonce, combined with the sequence number.
+    base_nonce: [u8; 12],
+
+    /// Secret used by the export interface.
+    exporter_secret: [u8; 32],
+
+    /// Number of messages processed so far.
+    seq: u64,
+}
+
+impl Secrets {
+    /// Runs the RFC 9180 key schedule.
+    fn new(mode: u8, shared_secret: &[u8; 32], info: &[u8], psk: &[u8], psk_id: &[u8]) -> Self {
+        let psk_id_hash = labeled_extract(&SUITE_ID, &[], b"psk_id_hash", psk_id);
+        let info_hash = labeled_extract(&SUITE_ID, &[], b"info_hash", info);
+
+        let mut context = [0u8; 65];
+        context[0] = mode;
+        context[1..33].copy_from_slice(&psk_id_hash);
+        context[33..].copy_from_slice(&info_hash);
+
+        let mut secret = labeled_extract(&SUITE_ID, shared_secret, b"secret", psk);
+
+        let mut out = Self {
+            key: [0u8; 32],
+            base_nonce: [0u8; 12],
+            exporter_secret: [0u8; 32],
+            seq: 0,
+        };
+
+        let expand = |label: &[u8], dst: &mut [u8]| {
+            let mut okm = labeled_expand(&SUITE_ID, &secret, label, &context, dst.len()).unwrap();
+            dst.copy_from_slice(&okm);
+            okm.fill(0);
+        };
+
+        expand(b"key", &mut out.key);
+        expand(b"base_nonce", &mut out.base_nonce);
+        expand(b"exp", &mut out.exporter_secret);
+
+        secret.fill(0);
+        out
+    }
+
+    /// Computes the nonce for the current sequence number and advances it.
+    fn next_nonce(&mut self) -> Result<[u8; 12], HpkeError> {
+        if self.seq == u64::MAX {
+            return Err(HpkeError::MessageLimitReached);
+        }
+
+        let mut nonce = self.base_nonce;
+        for (n, s) in nonce[4..].iter_mut().zip(self.seq.to_be_bytes()) {
+            *n ^= s;
+        }
+
+        Ok(nonce)
+    }
+
+    /// Derives an exported secret (`Context.Export`).
+    fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, HpkeError> {
+        if len > HKDF_MAX_OUTPUT {
+            return Err(HpkeError::ExportTooLong);
+        }
+
+        labeled_expand(
+            &SUITE_ID,
+            &self.exporter_secret,
+            b"sec",
+            exporter_context,
+            len,
+        )
+        .map_err(|_| HpkeError::ExportTooLong)
+    }
+}
+
+impl Drop for Secrets {
+    fn drop(&mut self) {
+        self.key.fill(0);
+        self.base_nonce.fill(0);
+        self.exporter_secret.fill(0);
+    }
+}
+
+/// Sender-side HPKE encryption context.
+///
+/// Created by [`setup_sender`](super::setup_sender). Each call to
+/// [`seal`](Self::seal) uses the next sequence number.
+pub struct SenderContext(Secrets);
+
+impl SenderContext {
+    /// Builds a context from the KEM shared secret.
+    pub(crate) fn new(
+        mode: u8,
+        shared_secret: &[u8; 32],
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+    ) -> Self {
+        Self(Secrets::new(mode, shared_secret, info, psk, psk_id))
+    }
+
+    /// Encrypts and authenticates the next message.
+    ///
+    /// # Parameters
+    ///
+    /// - `aad`: Additional authenticated data
+    /// - `plaintext`: Message to encrypt
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ciphertext)`: the encrypted message followed by the 16-byte tag
+    /// - `Err(MessageLimitReached)` if the sequence number is exhausted
+    pub fn seal(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, HpkeError> {
+        let nonce = self.0.next_nonce()?;
+
+        let mut out = vec![0u8; plaintext.len() + 16];
+        let (ciphertext, tag) = out.split_at_mut(plaintext.len());
+
+        let mut tag_bytes = [0u8; 16];
+        encrypt_with_aad(
+            &self.0.key,
+            &nonce,
+            aad,
+            plaintext,
+            ciphertext,
+            &mut tag_bytes,
+        );
+        tag.copy_from_slice(&tag_bytes);
+
+        self.0.seq += 1;
+        Ok(out)
+    }
+
+    /// Derives a secret from the context (`Context.Export`).
+    ///
+    /// # Parameters
+    ///
+    /// - `exporter_context`: Application-specific context string
+    /// - `len`: Number of bytes to export (at most 8160)
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, HpkeError> {
+        self.0.export(exporter_context, len)
+    }
+
+    /// Returns the sequence number of the next message.
+    pub fn sequence_number(&self) -> u64 {
+        self.0.seq
+    }
+}
+
+/// Receiver-side HPKE decryption context.
+///
+/// Created by [`setup_receiver`](super::setup_receiver). Messages must be
+/// opened in the order they were sealed.
+pub struct ReceiverContext(Secrets);
+
+impl ReceiverContext {
+    /// Builds a context from the KEM shared secret.
+    pub(crate) fn new(
+        mode: u8,
+        shared_secret: &[u8; 32],
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+    ) -> Self {
+        Self(Secrets::new(mode, shared_secret, info, psk, psk_id))
+    }
+
+    /// Decrypts and authenticates the next message.
+    ///
+    /// # Parameters
+    ///
+    /// - `aad`: Additional authenticated data supplied by the sender
+    /// - `ciphertext`: Encrypted message followed by the 16-byte tag
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(plaintext)` on success
+    /// - `Err(OpenFailed)` if authentication fails; the sequence number is
+    ///   not advanced
+    /// - `Err(MessageLimitReached)` if the sequence number is exhausted
+    pub fn open(&mut self, aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, HpkeError> {
+        let nonce = self.0.next_nonce()?;
+
+        if ciphertext.len() < 16 {
+            return Err(HpkeError::OpenFailed);
+        }
+
+        let (body, tag) = ciphertext.split_at(ciphertext.len() - 16);
+        let tag: &[u8; 16] = tag.try_into().unwrap();
+
+        let mut plaintext = vec![0u8; body.len()];
+        decrypt_with_aad(&self.0.key, &nonce, aad, body, tag, &mut plaintext)
+            .map_err(|_| HpkeError::OpenFailed)?;
+
+        self.0.seq += 1;
+        Ok(plaintext)
+    }
+
+    /// Derives a secret from the context (`Context.Export`).
+    ///
+    /// # Parameters
+    ///
+    /// - `exporter_context`: Application-specific context string
+    /// - `len`: Number of bytes to export (at most 8160)
+    pub fn export(&self, exporter_context: &[u8], len: usize) -> Result<Vec<u8>, HpkeError> {
+        self.0.export(exporter_context, len)
+    }
+
+    /// Returns the sequence number of the next message.
+    pub fn sequence_number(&self) -> u64 {
+        self.0.seq
+    }
+}
@@ -0,0 +1,274 @@
+//! HPKE setup functions and single-shot API (RFC 9180, Sections 5 and 6).
+//!
+//! The setup functions run the KEM, select the mode from the optional
+//! inputs, and return a sender or receiver context. The single-shot
+//! `seal` / `open` functions wrap a setup and a single message.
+
+use super::context::{ReceiverContext, SenderContext};
+use super::kem::{decap, encap};
+use crate::derivation::{HkdfError, hkdf_expand, hkdf_extract};
+use crate::rng::Csprng;
+
+/// KEM identifier for DHKEM(X25519, HKDF-SHA256).
+pub const KEM_ID: u16 = 0x0020;
+
+/// KDF identifier for HKDF-SHA256.
+pub const KDF_ID: u16 = 0x0001;
+
+/// AEAD identifier for ChaCha20-Poly1305.
+pub const AEAD_ID: u16 = 0x0003;
+
+/// HPKE suite identifier:
+/// `"HPKE" || I2OSP(kem_id, 2) || I2OSP(kdf_id, 2) || I2OSP(aead_id, 2)`.
+pub(crate) const SUITE_ID: [u8; 10] = [
+    b'H',
+    b'P',
+    b'K',
+    b'E',
+    (KEM_ID >> 8) as u8,
+    KEM_ID as u8,
+    (KDF_ID >> 8) as u8,
+    KDF_ID as u8,
+    (AEAD_ID >> 8) as u8,
+    AEAD_ID as u8,
+];
+
+/// Version label prepended to every labeled KDF input.
+const VERSION_LABEL: &[u8] = b"HPKE-v1";
+
+/// Mode identifiers (RFC 9180, Table 1).
+const MODE_BASE: u8 = 0x00;
+const MODE_PSK: u8 = 0x01;
+const MODE_AUTH: u8 = 0x02;
+const MODE_AUTH_PSK: u8 = 0x03;
+
+/// Errors that can occur during HPKE operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HpkeError {
+    /// The pre-shared key or its identifier is empty.
+    InvalidPsk,
+
+    /// A public key produced an all-zero Diffie–Hellman output.
+    InvalidPublicKey,
+
+    /// Ciphertext authentication failed.
+    OpenFailed,
+
+    /// The context's sequence number is exhausted.
+    MessageLimitReached,
+
+    /// The requested export length exceeds 255 × 32 bytes.
+    ExportTooLong,
+}
+
+/// Pre-shared key input for the PSK and AuthPSK modes.
+#[derive(Debug, Clone, Copy)]
+pub struct Psk<'a> {
+    /// Pre-shared key (should hold at least 32 bytes of entropy).
+    pub psk: &'a [u8],
+
+    /// Identifier the recipient uses to select the key.
+    pub psk_id: &'a [u8],
+}
+
+/// `LabeledExtract(salt, label, ikm)`.
+pub(crate) fn labeled_extract(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> [u8; 32] {
+    let mut input =
+        Vec::with_capacity(VERSION_LABEL.len() + suite_id.len() + label.len() + ikm.len());
+    input.extend_from_slice(VERSION_LABEL);
+    input.extend_from_slice(suite_id);
+    input.extend_from_slice(label);
+    input.extend_from_slice(ikm);
+
+    let prk = hkdf_extract(salt, &input);
+    input.fill(0);
+
+    prk
+}
+
+/// `LabeledExpand(prk, label, info, len)`.
+pub(crate) fn labeled_expand(
+    suite_id: &[u8],
+    prk: &[u8],
+    label: &[u8],
+    info: &[u8],
+    len: usize,
+) -> Result<Vec<u8>, HkdfError> {
+    let len_bytes = u16::try_from(len).map_err(|_| HkdfError::OutputTooLong)?;
+
+    let mut labeled_info =
+        Vec::with_capacity(2 + VERSION_LABEL.len() + suite_id.len() + label.len() + info.len());
+    labeled_info.extend_from_slice(&len_bytes.to_be_bytes());
+    labeled_info.extend_from_slice(VERSION_LABEL);
+    labeled_info.extend_from_slice(suite_id);
+    labeled_info.extend_from_slice(label);
+    labeled_info.extend_from_slice(info);
+
+    hkdf_expand(prk, &labeled_info, len)
+}
+
+/// Selects the mode and unpacks the PSK, rejecting half-empty inputs.
+fn mode_and_psk<'a>(
+    psk: Option<Psk<'a>>,
+    authenticated: bool,
+) -> Result<(u8, &'a [u8], &'a [u8]), HpkeError> {
+    match (psk, authenticated) {
+        (None, false) => Ok((MODE_BASE, &[], &[])),
+        (None, true) => Ok((MODE_AUTH, &[], &[])),
+        (Some(p), auth) => {
+            if p.psk.is_empty() || p.psk_id.is_empty() {
+                return Err(HpkeError::InvalidPsk);
+            }
+
+            let mode = if auth { MODE_AUTH_PSK } else { MODE_PSK };
+            Ok((mode, p.psk, p.psk_id))
+        }
+    }
+}
+
+/// Sets up a sender context for the recipient public key `pk_r`.
+///
+/// # Parameters
+///
+/// - `pk_r`: Recipient X25519 public key
+/// - `info`: Application-supplied context, bound to the derived keys
+/// - `psk`: Optional pre-shared key (PSK / AuthPSK modes)
+/// - `sk_s`: Optional sender static secret key (Auth / AuthPSK modes)
+///
+/// # Returns
+///
+/// - `Ok((enc, context))`: the encapsulated key to send to the recipient
+///   and the sender context
+/// - `Err(InvalidPsk)` if the PSK or its identifier is empty
+/// - `Err(InvalidPublicKey)` if `pk_r` is a low-order point
+pub fn setup_sender(
+    pk_r: &[u8; 32],
+    info: &[u8],
+    psk: Option<Psk<'_>>,
+    sk_s: Option<&[u8; 32]>,
+) -> Result<([u8; 32], SenderContext), HpkeError> {
+    let mut ikm_e = [0u8; 32];
+    Csprng::new().fill_bytes(&mut ikm_e);
+
+    let result = setup_sender_with_ikm(&ikm_e, pk_r, info, psk, sk_s);
+    ikm_e.fill(0);
+
+    result
+}
+
+/// Sets up a sender context with a caller-supplied ephemeral seed.
+///
+/// The ephemeral key pair is derived from `ikm_e` with
+/// [`derive_key_pair`](super::derive_key_pair). This exists for
+/// deterministic testing against the RFC 9180 vectors.
+///
+/// # Security Notes
+///
+/// - Reusing `ikm_e` reuses the ephemeral key and therefore the AEAD key
+///   and nonces. Use [`setup_sender`] unless reproducibility is required.
+pub fn setup_sender_with_ikm(
+    ikm_e: &[u8],
+    pk_r: &[u8; 32],
+    info: &[u8],
+    psk: Option<Psk<'_>>,
+    sk_s: Option<&[u8; 32]>,
+) -> Result<([u8; 32], SenderContext), HpkeError> {
+    let (mode, psk, psk_id) = mode_and_psk(psk, sk_s.is_some())?;
+
+    let (mut shared_secret, enc) = encap(ikm_e, pk_r, sk_s)?;
+    let context = SenderContext::new(mode, &shared_secret, info, psk, psk_id);
+    shared_secret.fill(0);
+
+    Ok((enc, context))
+}
+
+/// Sets up a receiver context from an encapsulated key.
+///
+/// # Parameters
+///
+/// - `enc`: Encapsulated key produced by the sender
+/// - `sk_r`: Recipient X25519 secret key
+/// - `info`: Application-supplied context (must match the sender's)
+/// - `psk`: Optional pre-shared key (must match the sender's mode)
+/// - `pk_s`: Optional sender static public key (Auth / AuthPSK modes)
+///
+/// # Returns
+///
+/// - `Ok(context)` on success
+/// - `Err(InvalidPsk)` if the PSK or its identifier is empty
+/// - `Err(InvalidPublicKey)` if `enc` or `pk_s` is a low-order point
+///
+/// A mismatch in any input is only detected when opening the first
+/// message.
+pub fn setup_receiver(
+    enc: &[u8; 32],
+    sk_r: &[u8; 32],
+    info: &[u8],
+    psk: Option<Psk<'_>>,
+    pk_s: Option<&[u8; 32]>,
+) -> Result<ReceiverContext, HpkeError> {
+    let (mode, psk, psk_id) = mode_and_psk(psk, pk_s.is_some())?;
+
+    let mut shared_secret = decap(enc, sk_r, pk_s)?;
+    let context = ReceiverContext::new(mode, &shared_secret, info, psk, psk_id);
+    shared_secret.fill(0);
+
+    Ok(context)
+}
+
+/// Encrypts a single message to `pk_r`.
+///
+/// # Parameters
+///
+/// - `pk_r`: Recipient X25519 public key
+/// - `info`: Application-supplied context
+/// - `aad`: Additional authenticated data
+/// - `plaintext`: Message to encrypt
+/// - `psk`: Optional pre-shared key
+/// - `sk_s`: Optional sender static secret key
+///
+/// # Returns
+///
+/// `Ok((enc, ciphertext))`, where `ciphertext` carries a 16-byte tag.
+pub fn seal(
+    pk_r: &[u8; 32],
+    info: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+    psk: Option<Psk<'_>>,
+    sk_s: Option<&[u8; 32]>,
+) -> Result<([u8; 32], Vec<u8>), HpkeError> {
+    let (enc, mut context) = setup_sender(pk_r, info, psk, sk_s)?;
+    let ciphertext = context.seal(aad, plaintext)?;
+
+    Ok((enc, ciphertext))
+}
+
+/// Decrypts a single message produced by [`seal`].
+///
+/// # Parameters
+///
+/// - `enc`: Encapsulated key
+/// - `sk_r`: Recipient X25519 secret key
+/// - `info`: Application-supplied context
+/// - `aad`: Additional authenticated data
+/// - `ciphertext`: Encrypted message with its tag
+/// - `psk`: Optional pre-shared key
+/// - `pk_s`: Optional sender static public key
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(OpenFailed)` if any input does not match the sender's
+pub fn open(
+    enc: &[u8; 32],
+    sk_r: &[u8; 32],
+    info: &[u8],
+    aad: &[u8],
+    ciphertext: &[u8],
+    psk: Option<Psk<'_>>,
+    pk_s: Option<&[u8; 32]>,
+) -> Result<Vec<u8>, HpkeError> {
+    let mut context = setup_receiver(enc, sk_r, info, psk, pk_s)?;
+    context.open(aad, ciphertext)
+}
@@ -0,0 +1,142 @@
+//! DHKEM(X25519, HKDF-SHA256) (RFC 9180, Section 4.1).
+//!
+//! The KEM turns X25519 Diffie–Hellman into key encapsulation: the sender
+//! generates an ephemeral key pair, and the shared secret is derived from
+//! the DH output(s) and the public keys involved (`kem_context`).
+
+use super::core::{HpkeError, KEM_ID, labeled_expand, labeled_extract};
+use crate::keys::x25519::{x25519, x25519_base};
+use crate::rng::Csprng;
+
+/// KEM suite identifier: `"KEM" || I2OSP(kem_id, 2)`.
+const SUITE_ID: [u8; 5] = [b'K', b'E', b'M', (KEM_ID >> 8) as u8, KEM_ID as u8];
+
+/// Derives an X25519 key pair from input keying material.
+///
+/// This is `DeriveKeyPair` from RFC 9180: the same `ikm` always yields the
+/// same key pair.
+///
+/// # Parameters
+///
+/// - `ikm`: Input keying material (at least 32 bytes of entropy)
+///
+/// # Returns
+///
+/// `(public, secret)`, both 32 bytes.
+pub fn derive_key_pair(ikm: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut prk = labeled_extract(&SUITE_ID, &[], b"dkp_prk", ikm);
+    let mut okm = labeled_expand(&SUITE_ID, &prk, b"sk", &[], 32).unwrap();
+
+    let mut secret = [0u8; 32];
+    secret.copy_from_slice(&okm);
+
+    prk.fill(0);
+    okm.fill(0);
+
+    (x25519_base(&secret), secret)
+}
+
+/// Generates a random X25519 key pair.
+///
+/// # Returns
+///
+/// `(public, secret)`, both 32 bytes.
+pub fn generate_key_pair() -> ([u8; 32], [u8; 32]) {
+    let mut ikm = [0u8; 32];
+    Csprng::new().fill_bytes(&mut ikm);
+
+    let pair = derive_key_pair(&ikm);
+    ikm.fill(0);
+
+    pair
+}
+
+/// Encapsulates a shared secret to `pk_r`.
+///
+/// When `sk_s` is provided, this is `AuthEncap`: the sender's static key
+/// is mixed into the shared secret.
+///
+/// Returns `(shared_secret, enc)`.
+pub(crate) fn encap(
+    ikm_e: &[u8],
+    pk_r: &[u8; 32],
+    sk_s: Option<&[u8; 32]>,
+) -> Result<([u8; 32], [u8; 32]), HpkeError> {
+    let (pk_e, mut sk_e) = derive_key_pair(ikm_e);
+
+    let mut dh = Vec::with_capacity(64);
+    let mut kem_context = Vec::with_capacity(96);
+
+    dh.extend_from_slice(&diffie_hellman(&sk_e, pk_r)?);
+    kem_context.extend_from_slice(&pk_e);
+    kem_context.extend_from_slice(pk_r);
+
+    if let Some(sk_s) = sk_s {
+        dh.extend_from_slice(&diffie_hellman(sk_s, pk_r)?);
+        kem_context.extend_from_slice(&x25519_base(sk_s));
+    }
+
+    let shared_secret = extract_and_expand(&dh, &kem_context);
+
+    sk_e.fill(0);
+    dh.fill(0);
+
+    Ok((shared_secret, pk_e))
+}
+
+/// Decapsulates the shared secret from `enc` with the recipient key.
+///
+/// When `pk_s` is provided, this is `AuthDecap`, which only succeeds if
+/// the sender used the matching static secret key.
+pub(crate) fn decap(
+    enc: &[u8; 32],
+    sk_r: &[u8; 32],
+    pk_s: Option<&[u8; 32]>,
+) -> Result<[u8; 32], HpkeError> {
+    let mut dh = Vec::with_capacity(64);
+    let mut kem_context = Vec::with_capacity(96);
+
+    dh.extend_from_slice(&diffie_hellman(sk_r, enc)?);
+    kem_context.extend_from_slice(enc);
+    kem_context.extend_from_slice(&x25519_base(sk_r));
+
+    if let Some(pk_s) = pk_s {
+        dh.extend_from_slice(&diffie_hellman(sk_r, pk_s)?);
+        kem_context.extend_from_slice(pk_s);
+    }
+
+    let shared_secret = extract_and_expand(&dh, &kem_context);
+    dh.fill(0);
+
+    Ok(shared_secret)
+}
+
+/// X25519 with the all-zero output check required by RFC 9180.
+fn diffie_hellman(secret: &[u8; 32], public: &[u8; 32]) -> Result<[u8; 32], HpkeError> {
+    let shared = x25519(secret, public);
+
+    let mut acc = 0u8;
+    for b in shared {
+        acc |= b;
+    }
+
+    if acc == 0 {
+        return Err(HpkeError::InvalidPublicKey);
+    }
+
+    Ok(shared)
+}
+
+/// Derives the KEM shared secret from the DH output and the KEM context.
+fn extract_and_expand(dh: &[u8], kem_context: &[u8]) -> [u8; 32] {
+    let mut prk = labeled_extract(&SUITE_ID, &[], b"eae_prk", dh);
+    let mut okm = labeled_expand(&SUITE_ID, &prk, b"shared_secret", kem_context, 32).unwrap();
+
+    let mut shared_secret = [0u8; 32];
+    shared_secret.copy_from_slice(&okm);
+
+    prk.fill(0);
+    okm.fill(0);
+
+    shared_secret
+}
@@ -0,0 +1,53 @@
+//! Hybrid Public Key Encryption (RFC 9180).
+//!
+//! This module implements HPKE with a single, fixed cipher suite:
+//!
+//! - KEM: DHKEM(X25519, HKDF-SHA256) (`0x0020`)
+//! - KDF: HKDF-SHA256 (`0x0001`)
+//! - AEAD: ChaCha20-Poly1305 (`0x0003`)
+//!
+//! HPKE lets a sender encrypt to a recipient's X25519 public key. The
+//! sender produces an *encapsulated key* (`enc`, 32 bytes) that the
+//! recipient combines with its secret key to derive the same AEAD context.
+//!
+//! ## Modes
+//!
+//! The four RFC 9180 modes are selected by the optional inputs passed to
+//! the setup functions:
+//!
+//! | Mode      | `psk`  | sender key |
+//! |-----------|--------|------------|
+//! | Base      | `None` | `None`     |
+//! | PSK       | `Some` | `None`     |
+//! | Auth      | `None` | `Some`     |
+//! | AuthPSK   | `Some` | `Some`     |
+//!
+//! ## Structure
+//!
+//! - `kem`: DHKEM key generation, encapsulation and decapsulation
+//! - `context`: key schedule and stateful sender / receiver contexts
+//! - `core`: setup functions, single-shot `seal` / `open`, errors
+//!
+//! ## Notes
+//!
+//! - Keys are raw 32-byte X25519 secrets and public u-coordinates.
+//! - Contexts track sequence numbers internally; each message gets a
+//!   fresh nonce and messages must be opened in order.
+//! - Diffie–Hellman outputs equal to zero (low-order public keys) are
+//!   rejected.
+
+/// Key schedule and encryption contexts.
+pub(crate) mod context;
+
+/// Setup functions, single-shot API and errors.
+pub(crate) mod core;
+
+/// DHKEM(X25519, HKDF-SHA256).
+pub(crate) mod kem;
+
+pub use context::{ReceiverContext, SenderContext};
+pub use core::{
+    AEAD_ID, HpkeError, KDF_ID, KEM_ID, Psk, open, seal, setup_receiver, setup_sender,
+    setup_sender_with_ikm,
+};
+pub use kem::{derive_key_pair, generate_key_pair};
@@ -1,13 +1,77 @@
-//! ChaCha20-Poly1305 authenticated encryption (RFC 8439).
+//! Authenticated encryption.
 //!
-//! This module exposes the ChaCha20-Poly1305 AEAD construction by re-exporting
-//! the internal Poly1305-based implementation under a clear, unambiguous name.
+//! This module exposes the ChaCha20-Poly1305 (RFC 8439), AES-GCM
+//! (NIST SP 800-38D) and AES-GCM-SIV (RFC 8452) AEAD constructions, and
+//! the AES key wrap algorithm (RFC 3394), by re-exporting the internal
+//! implementations under clear, unambiguous names.
+//!
+//! It also provides Hybrid Public Key Encryption (RFC 9180) on top of
+//! X25519 and ChaCha20-Poly1305, and a versioned envelope format that
+//! tags ciphertexts with their algorithm and key id. Passphrase-protected
+//! files combine Argon2id with ChaCha20-Poly1305, and `age` reads and
+//! writes files in the age v1 format. `nacl` provides libsodium-compatible
+//! `secretbox` and `box` for legacy interoperability, `nonce` manages
+//! nonces so that they are never reused, and `padding` hides message
+//! lengths.
+//!
+//! The [`aead::Aead`] trait exposes the AEADs behind one generic interface,
+//! so that higher-level formats can be parameterized by cipher.
 //!
 //! The underlying implementation is split internally for clarity and safety,
 //! but users of this module interact only with the high-level AEAD API.
 
+mod aes;
 mod poly1305;
 
+/// Generic AEAD trait.
+///
+/// A single seal/open interface (attached, detached and in-place) over
+/// ChaCha20-Poly1305, AES-GCM and AES-GCM-SIV.
+pub mod aead;
+
+/// age v1 file encryption.
+///
+/// X25519 and scrypt recipients, the STREAM payload, Bech32 keys and
+/// ASCII armor, compatible with the `age` tools.
+pub mod age;
+
+/// NaCl / libsodium compatibility.
+///
+/// XSalsa20-Poly1305 `secretbox`, X25519 `box` with precomputation, and
+/// anonymous sealed boxes, byte-compatible with libsodium.
+pub mod nacl;
+
+/// Nonce sequences and sealing keys.
+///
+/// Counter nonces with a random per-key prefix, a hard message limit and
+/// crash-safe persistence, owned together with the key.
+pub mod nonce;
+
+/// Length-hiding padding.
+///
+/// Padmé, power-of-two and bucket padding with ISO/IEC 7816-4 markers and
+/// a constant-time unpad, applied before encryption.
+pub mod padding;
+
+/// Versioned ciphertext envelope.
+///
+/// Self-describing `magic || version || algorithm || key id || nonce`
+/// header, authenticated together with the ciphertext, for algorithm
+/// agility and key rotation.
+pub mod envelope;
+
+/// Passphrase-based file encryption.
+///
+/// Argon2id key derivation and ChaCha20-Poly1305 behind a single file
+/// format, with the KDF parameters stored in an authenticated header.
+pub mod passphrase;
+
+/// Hybrid Public Key Encryption (RFC 9180).
+///
+/// DHKEM(X25519, HKDF-SHA256), HKDF-SHA256 and ChaCha20-Poly1305, with
+/// the base, PSK, auth and auth-PSK modes.
+pub mod hpke;
+
 /// ChaCha20-Poly1305 AEAD construction.
 ///
 /// This is a re-export of the internal Poly1305-based implementation,
@@ -25,3 +89,61 @@ mod poly1305;
 /// This re-export intentionally hides the internal Poly1305 structure
 /// and exposes only the AEAD interface.
 pub use poly1305::core as chacha20poly1305;
+
+/// Key-committing ChaCha20-Poly1305 AEAD construction.
+///
+/// This is a re-export of the internal committing wrapper around
+/// ChaCha20-Poly1305. Each ciphertext carries a 32-byte commitment to the
+/// key and nonce, so it decrypts under at most one key.
+///
+/// # Notes
+///
+/// - Use this variant whenever the key is derived from a password (e.g.
+///   an Argon2id-unlocked vault), where partitioning-oracle attacks apply.
+/// - Ciphertexts are not interchangeable with plain `chacha20poly1305`.
+pub use poly1305::committing as chacha20poly1305_committing;
+
+/// AES-GCM AEAD construction.
+///
+/// This is a re-export of the internal AES implementation, providing
+/// authenticated encryption with associated data (AEAD) as specified in
+/// NIST SP 800-38D, with 128-bit or 256-bit keys.
+///
+/// # Notes
+///
+/// - This module combines:
+///   - AES in counter mode for encryption
+///   - GHASH for authentication
+/// - The block cipher is bitsliced and runs in constant time; AES-NI and
+///   PCLMULQDQ are used instead when the CPU supports them.
+/// - Nonce reuse with the same key is catastrophic and must be avoided.
+pub use aes::gcm as aes_gcm;
+
+/// AES-GCM-SIV nonce-misuse-resistant AEAD construction.
+///
+/// This is a re-export of the internal AES implementation, providing
+/// authenticated encryption with associated data as specified in RFC 8452.
+///
+/// # Notes
+///
+/// - Repeating a nonce only reveals whether two messages are equal; it
+///   does not compromise the key or other messages.
+/// - A deterministic mode without nonce is provided for fields that need
+///   equality lookups.
+/// - Prefer `aes_gcm` or `chacha20poly1305` when unique nonces can be
+///   guaranteed and equality must stay hidden.
+pub use aes::gcm_siv as aes_gcm_siv;
+
+/// AES key wrap.
+///
+/// This is a re-export of the internal AES implementation, providing the
+/// RFC 3394 key wrap algorithm with 128-bit or 256-bit key-encryption
+/// keys.
+///
+/// # Notes
+///
+/// - Wrapping is deterministic and needs no nonce.
+/// - Unwrapping verifies an integrity check value and fails on any
+///   modification of the wrapped key or a wrong KEK.
+/// - Only wrap high-entropy keys; use an AEAD for other data.
+pub use aes::kw as aes_kw;
@@ -0,0 +1,209 @@
+//! X25519-XSalsa20-Poly1305 public-key authenticated encryption.
+//!
+//! Compatible with libsodium's `crypto_box_*` and `crypto_box_seal`:
+//!
+//! ```text
+//! k      = HSalsa20(X25519(sk, pk), 0^16)        (beforenm)
+//! output = secretbox(k, nonce, m)                (afternm)
+//! ```
+//!
+//! Anonymous sealed boxes encrypt to a public key with a fresh ephemeral
+//! key pair and derive the nonce from both public keys:
+//!
+//! ```text
+//! nonce  = BLAKE2b-192(epk || pk)
+//! output = epk || crypto_box(pk, esk, nonce, m)
+//! ```
+//!
+//! ## Notes
+//!
+//! - A box authenticates the sender: both parties can compute the shared
+//!   key, so either could have produced a given box.
+//! - A sealed box is anonymous; the recipient learns nothing about the
+//!   sender.
+
+use super::NaclError;
+use super::secretbox::{self, MAC_LEN, NONCE_LEN};
+use crate::hash::blake2b;
+use crate::keys::x25519::{x25519, x25519_base};
+use crate::rng::Csprng;
+use crate::rng::chacha20::hsalsa20;
+
+/// Public key length, in bytes.
+pub const PUBLIC_KEY_LEN: usize = 32;
+
+/// Secret key length, in bytes.
+pub const SECRET_KEY_LEN: usize = 32;
+
+/// Overhead of a sealed box: ephemeral public key and MAC.
+pub const SEAL_OVERHEAD: usize = PUBLIC_KEY_LEN + MAC_LEN;
+
+/// Generates a random key pair.
+///
+/// # Returns
+///
+/// `(public, secret)`.
+pub fn keypair() -> ([u8; PUBLIC_KEY_LEN], [u8; SECRET_KEY_LEN]) {
+    let mut secret = [0u8; SECRET_KEY_LEN];
+    Csprng::new().fill_bytes(&mut secret);
+
+    (x25519_base(&secret), secret)
+}
+
+/// Precomputes the shared key for a pair of parties.
+///
+/// # Parameters
+///
+/// - `public`: The other party's public key
+/// - `secret`: Our secret key
+///
+/// # Returns
+///
+/// - `Ok(key)`, usable with [`seal_afternm`] and [`open_afternm`]
+/// - `Err(InvalidPublicKey)` if the shared secret is all zero
+pub fn beforenm(
+    public: &[u8; PUBLIC_KEY_LEN],
+    secret: &[u8; SECRET_KEY_LEN],
+) -> Result<[u8; 32], NaclError> {
+    let mut shared = x25519(secret, public);
+
+    if shared == [0u8; 32] {
+        return Err(NaclError::InvalidPublicKey);
+    }
+
+    let key = hsalsa20(&shared, &[0u8; 16]);
+    shared.fill(0);
+
+    Ok(key)
+}
+
+/// Encrypts a message with a precomputed shared key.
+///
+/// Identical to [`secretbox::seal`].
+pub fn seal_afternm(key: &[u8; 32], nonce: &[u8; NONCE_LEN], plaintext: &[u8]) -> Vec<u8> {
+    secretbox::seal(key, nonce, plaintext)
+}
+
+/// Decrypts a message with a precomputed shared key.
+///
+/// Identical to [`secretbox::open`].
+pub fn open_afternm(
+    key: &[u8; 32],
+    nonce: &[u8; NONCE_LEN],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, NaclError> {
+    secretbox::open(key, nonce, ciphertext)
+}
+
+/// Encrypts and authenticates a message from `secret` to `public`.
+///
+/// # Parameters
+///
+/// - `public`: Recipient public key
+/// - `secret`: Sender secret key
+/// - `nonce`: 192-bit nonce, unique per message for this key pair
+/// - `plaintext`: Data to encrypt
+///
+/// # Returns
+///
+/// - `Ok(MAC || ciphertext)` on success
+/// - `Err(InvalidPublicKey)` if the shared secret is all zero
+pub fn seal(
+    public: &[u8; PUBLIC_KEY_LEN],
+    secret: &[u8; SECRET_KEY_LEN],
+    nonce: &[u8; NONCE_LEN],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, NaclError> {
+    let mut key = beforenm(public, secret)?;
+    let out = secretbox::seal(&key, nonce, plaintext);
+    key.fill(0);
+
+    Ok(out)
+}
+
+/// Verifies and decrypts a message from `public` to `secret`.
+///
+/// # Parameters
+///
+/// - `public`: Sender public key
+/// - `secret`: Recipient secret key
+/// - `nonce`: Nonce used for encryption
+/// - `ciphertext`: `MAC || ciphertext`
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(InvalidPublicKey)` if the shared secret is all zero
+/// - `Err(InvalidLength | AuthenticationFailed)` otherwise
+pub fn open(
+    public: &[u8; PUBLIC_KEY_LEN],
+    secret: &[u8; SECRET_KEY_LEN],
+    nonce: &[u8; NONCE_LEN],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, NaclError> {
+    let mut key = beforenm(public, secret)?;
+    let result = secretbox::open(&key, nonce, ciphertext);
+    key.fill(0);
+
+    result
+}
+
+/// Encrypts a message anonymously to `public` (`crypto_box_seal`).
+///
+/// # Returns
+///
+/// - `Ok(epk || MAC || ciphertext)`, `SEAL_OVERHEAD + plaintext.len()`
+///   bytes
+/// - `Err(InvalidPublicKey)` if the shared secret is all zero
+pub fn box_seal(public: &[u8; PUBLIC_KEY_LEN], plaintext: &[u8]) -> Result<Vec<u8>, NaclError> {
+    let (ephemeral_public, mut ephemeral_secret) = keypair();
+    let nonce = seal_nonce(&ephemeral_public, public);
+
+    let sealed = seal(public, &ephemeral_secret, &nonce, plaintext);
+    ephemeral_secret.fill(0);
+
+    let mut out = Vec::with_capacity(SEAL_OVERHEAD + plaintext.len());
+    out.extend_from_slice(&ephemeral_public);
+    out.extend_from_slice(&sealed?);
+
+    Ok(out)
+}
+
+/// Decrypts a message produced by [`box_seal`] (`crypto_box_seal_open`).
+///
+/// # Parameters
+///
+/// - `public`: Recipient public key
+/// - `secret`: Recipient secret key
+/// - `sealed`: `epk || MAC || ciphertext`
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(InvalidLength)` if `sealed` is shorter than `SEAL_OVERHEAD`
+/// - `Err(InvalidPublicKey | AuthenticationFailed)` otherwise
+pub fn box_seal_open(
+    public: &[u8; PUBLIC_KEY_LEN],
+    secret: &[u8; SECRET_KEY_LEN],
+    sealed: &[u8],
+) -> Result<Vec<u8>, NaclError> {
+    if sealed.len() < SEAL_OVERHEAD {
+        return Err(NaclError::InvalidLength);
+    }
+
+    let (ephemeral_public, ciphertext) = sealed.split_at(PUBLIC_KEY_LEN);
+    let ephemeral_public: &[u8; PUBLIC_KEY_LEN] = ephemeral_public.try_into().unwrap();
+
+    let nonce = seal_nonce(ephemeral_public, public);
+    open(ephemeral_public, secret, &nonce, ciphertext)
+}
+
+/// Derives the sealed box nonce, `BLAKE2b-192(epk || pk)`.
+fn seal_nonce(ephemeral_public: &[u8; 32], public: &[u8; 32]) -> [u8; NONCE_LEN] {
+    let mut input = [0u8; 64];
+    input[..32].copy_from_slice(ephemeral_public);
+    input[32..].copy_from_slice(public);
+
+    let digest = blake2b(NONCE_LEN, &input);
+    digest[..NONCE_LEN].try_into().unwrap()
+}
@@ -0,0 +1,35 @@
+//! NaCl / libsodium compatible authenticated encryption.
+//!
+//! This module provides byte-compatible implementations of:
+//!
+//! - `secretbox`: `crypto_secretbox_easy` (XSalsa20-Poly1305)
+//! - `crypto_box`: `crypto_box_easy`, `crypto_box_beforenm` and
+//!   `crypto_box_seal` (X25519, HSalsa20 and XSalsa20-Poly1305)
+//!
+//! ## Design goals
+//!
+//! - Interoperability with legacy clients and tools built on libsodium
+//! - The same combined `MAC || ciphertext` layout as the `_easy` APIs
+//!
+//! ## Notes
+//!
+//! - New designs should prefer ChaCha20-Poly1305 or HPKE. These
+//!   constructions exist for compatibility.
+//! - 24-byte nonces are large enough to be chosen at random.
+
+/// Secret-key authenticated encryption (XSalsa20-Poly1305).
+pub mod secretbox;
+
+/// Public-key authenticated encryption and anonymous sealed boxes.
+pub mod crypto_box;
+
+/// Errors that can occur when opening a box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NaclError {
+    /// The input is shorter than the fixed overhead.
+    InvalidLength,
+    /// The key is wrong or the ciphertext was modified.
+    AuthenticationFailed,
+    /// The public key yields an all-zero shared secret.
+    InvalidPublicKey,
+}
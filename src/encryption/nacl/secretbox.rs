@@ -0,0 +1,120 @@
+//! XSalsa20-Poly1305 secret-key authenticated encryption.
+//!
+//! Compatible with libsodium's `crypto_secretbox_easy`:
+//!
+//! ```text
+//! keystream = XSalsa20(key, nonce)
+//! otk       = keystream[0..32]
+//! c         = m XOR keystream[32..]
+//! output    = Poly1305(otk, c) || c
+//! ```
+
+use super::NaclError;
+use crate::encryption::poly1305::core::auth;
+use crate::rng::chacha20::xsalsa20_xor;
+
+/// Key length, in bytes.
+pub const KEY_LEN: usize = 32;
+
+/// Nonce length, in bytes.
+pub const NONCE_LEN: usize = 24;
+
+/// Authentication tag length, in bytes.
+pub const MAC_LEN: usize = 16;
+
+/// Encrypts and authenticates a message.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: 192-bit nonce, unique per message
+/// - `plaintext`: Data to encrypt
+///
+/// # Returns
+///
+/// `MAC || ciphertext`, `MAC_LEN + plaintext.len()` bytes.
+///
+/// # Security Notes
+///
+/// - Reusing a nonce with the same key reveals the XOR of the plaintexts
+///   and allows forgeries.
+pub fn seal(key: &[u8; KEY_LEN], nonce: &[u8; NONCE_LEN], plaintext: &[u8]) -> Vec<u8> {
+    // The first 32 keystream bytes become the Poly1305 key.
+    let mut input = vec![0u8; 32 + plaintext.len()];
+    input[32..].copy_from_slice(plaintext);
+
+    let mut stream = vec![0u8; input.len()];
+    xsalsa20_xor(key, nonce, 0, &input, &mut stream);
+
+    let mut otk = [0u8; 32];
+    otk.copy_from_slice(&stream[..32]);
+
+    let mut tag = [0u8; MAC_LEN];
+    auth(&mut tag, &otk, &stream[32..]);
+    otk.fill(0);
+
+    let mut out = Vec::with_capacity(MAC_LEN + plaintext.len());
+    out.extend_from_slice(&tag);
+    out.extend_from_slice(&stream[32..]);
+
+    input.fill(0);
+    stream.fill(0);
+
+    out
+}
+
+/// Verifies and decrypts a message produced by [`seal`].
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: Nonce used for encryption
+/// - `ciphertext`: `MAC || ciphertext`
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(InvalidLength)` if `ciphertext` is shorter than `MAC_LEN`
+/// - `Err(AuthenticationFailed)` if the tag does not verify
+///
+/// # Security Notes
+///
+/// - The tag is checked in constant time before anything is decrypted.
+pub fn open(
+    key: &[u8; KEY_LEN],
+    nonce: &[u8; NONCE_LEN],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, NaclError> {
+    if ciphertext.len() < MAC_LEN {
+        return Err(NaclError::InvalidLength);
+    }
+
+    let (tag, ciphertext) = ciphertext.split_at(MAC_LEN);
+
+    let mut otk = [0u8; 32];
+    xsalsa20_xor(key, nonce, 0, &[0u8; 32], &mut otk);
+
+    let mut expected = [0u8; MAC_LEN];
+    auth(&mut expected, &otk, ciphertext);
+    otk.fill(0);
+
+    let mut diff = 0u8;
+    for (a, b) in expected.iter().zip(tag) {
+        diff |= a ^ b;
+    }
+
+    if diff != 0 {
+        return Err(NaclError::AuthenticationFailed);
+    }
+
+    let mut input = vec![0u8; 32 + ciphertext.len()];
+    input[32..].copy_from_slice(ciphertext);
+
+    let mut stream = vec![0u8; input.len()];
+    xsalsa20_xor(key, nonce, 0, &input, &mut stream);
+
+    let plaintext = stream[32..].to_vec();
+    stream.fill(0);
+
+    Ok(plaintext)
+}
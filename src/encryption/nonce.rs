@@ -0,0 +1,320 @@
+//! Nonce sequences and sealing keys.
+//!
+//! ChaCha20-Poly1305 requires a unique nonce for every message under a
+//! key; a single repetition leaks the XOR of two plaintexts and the
+//! Poly1305 key. This module makes reuse structurally impossible:
+//!
+//! ```text
+//! nonce = prefix (4 bytes, random per key) || counter (8 bytes, big-endian)
+//! ```
+//!
+//! - [`NonceSequence`] hands out each counter value at most once and stops
+//!   at a hard message limit, before the counter can wrap around.
+//! - [`SealingKey`] owns a key together with its sequence, so the nonce
+//!   cannot be chosen (or forgotten) by the caller.
+//!
+//! ## Persistence
+//!
+//! A sequence restored from storage must never repeat a counter that was
+//! used before a crash. Persisted state therefore records a *reservation*
+//! rather than the current counter:
+//!
+//! 1. [`NonceSequence::reserve`] returns the state to store, covering the
+//!    next `count` nonces
+//! 2. the caller writes it durably
+//! 3. only then are those nonces handed out
+//!
+//! After a restart, the sequence resumes past the whole reservation. At
+//! most `count` nonces are skipped, and none is ever reused.
+//!
+//! ## Notes
+//!
+//! - The random prefix keeps sequences distinct if the same key is
+//!   (mistakenly) used from two places, or restored from an old backup.
+//! - Sequences created with [`NonceSequence::new`] live in memory only and
+//!   need no reservation.
+
+use super::poly1305::core::{decrypt_with_aad, encrypt_with_aad};
+use crate::rng::Csprng;
+
+/// Nonce length, in bytes.
+pub const NONCE_LEN: usize = 12;
+
+/// Random prefix length, in bytes.
+pub const PREFIX_LEN: usize = 4;
+
+/// Length of a serialized [`NonceSequence`] state, in bytes.
+pub const STATE_LEN: usize = 1 + PREFIX_LEN + 8 + 8;
+
+/// Default message limit per key.
+///
+/// 2^48 messages, far below the counter range and well within the
+/// ChaCha20-Poly1305 usage bounds.
+pub const DEFAULT_LIMIT: u64 = 1 << 48;
+
+/// State format version.
+const STATE_VERSION: u8 = 1;
+
+/// Errors that can occur while using a nonce sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonceError {
+    /// The message limit for this key has been reached. Rotate the key.
+    Exhausted,
+    /// The persisted reservation is used up. Call `reserve` and store the
+    /// returned state before continuing.
+    NotReserved,
+    /// The serialized state is malformed.
+    InvalidState,
+    /// The sealed message is too short to contain a nonce and a tag.
+    Truncated,
+    /// The key is wrong or the message was modified.
+    AuthenticationFailed,
+}
+
+/// A counter-based nonce generator with a random per-key prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonceSequence {
+    /// Random prefix, fixed for the lifetime of the key.
+    prefix: [u8; PREFIX_LEN],
+
+    /// Counter value of the next nonce.
+    next: u64,
+
+    /// Counter value at which the sequence is exhausted.
+    limit: u64,
+
+    /// Counter value up to which nonces may be handed out without a new
+    /// reservation.
+    reserved: u64,
+}
+
+impl NonceSequence {
+    /// Creates an in-memory sequence with a random prefix and
+    /// [`DEFAULT_LIMIT`].
+    pub fn new() -> Self {
+        Self::with_limit(DEFAULT_LIMIT)
+    }
+
+    /// Creates an in-memory sequence with a random prefix and a custom
+    /// message limit.
+    ///
+    /// # Parameters
+    ///
+    /// - `limit`: Maximum number of nonces, at most `u64::MAX`
+    pub fn with_limit(limit: u64) -> Self {
+        let mut prefix = [0u8; PREFIX_LEN];
+        Csprng::new().fill_bytes(&mut prefix);
+
+        Self {
+            prefix,
+            next: 0,
+            limit,
+            reserved: limit,
+        }
+    }
+
+    /// Returns the next nonce.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(nonce)`, never returned before by this sequence
+    /// - `Err(Exhausted)` once `limit` nonces have been produced
+    /// - `Err(NotReserved)` if a restored sequence needs a new reservation
+    pub fn advance(&mut self) -> Result<[u8; NONCE_LEN], NonceError> {
+        if self.next >= self.limit {
+            return Err(NonceError::Exhausted);
+        }
+
+        if self.next >= self.reserved {
+            return Err(NonceError::NotReserved);
+        }
+
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[..PREFIX_LEN].copy_from_slice(&self.prefix);
+        nonce[PREFIX_LEN..].copy_from_slice(&self.next.to_be_bytes());
+
+        self.next += 1;
+        Ok(nonce)
+    }
+
+    /// Returns the number of nonces left before the limit.
+    pub fn remaining(&self) -> u64 {
+        self.limit - self.next
+    }
+
+    /// Reserves the next `count` nonces for persistent use.
+    ///
+    /// The returned state must be stored durably **before** any of the
+    /// reserved nonces are used. Restoring it with
+    /// [`from_bytes`](Self::from_bytes) resumes after the reservation.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: Number of nonces to reserve (capped at the limit)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(state)` to persist
+    /// - `Err(Exhausted)` if no nonces are left
+    pub fn reserve(&mut self, count: u64) -> Result<[u8; STATE_LEN], NonceError> {
+        if self.next >= self.limit {
+            return Err(NonceError::Exhausted);
+        }
+
+        self.reserved = self.next.saturating_add(count).min(self.limit);
+
+        let mut out = [0u8; STATE_LEN];
+        out[0] = STATE_VERSION;
+        out[1..1 + PREFIX_LEN].copy_from_slice(&self.prefix);
+        out[1 + PREFIX_LEN..13].copy_from_slice(&self.reserved.to_be_bytes());
+        out[13..].copy_from_slice(&self.limit.to_be_bytes());
+
+        Ok(out)
+    }
+
+    /// Restores a sequence from state returned by [`reserve`](Self::reserve).
+    ///
+    /// The restored sequence starts after the stored reservation and
+    /// requires a new reservation before producing nonces.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(sequence)` on success
+    /// - `Err(InvalidState)` if the state is malformed
+    pub fn from_bytes(state: &[u8]) -> Result<Self, NonceError> {
+        if state.len() != STATE_LEN || state[0] != STATE_VERSION {
+            return Err(NonceError::InvalidState);
+        }
+
+        let next = u64::from_be_bytes(state[1 + PREFIX_LEN..13].try_into().unwrap());
+        let limit = u64::from_be_bytes(state[13..].try_into().unwrap());
+
+        if next > limit {
+            return Err(NonceError::InvalidState);
+        }
+
+        Ok(Self {
+            prefix: state[1..1 + PREFIX_LEN].try_into().unwrap(),
+            next,
+            limit,
+            reserved: next,
+        })
+    }
+}
+
+impl Default for NonceSequence {
+    /// Creates an in-memory sequence with [`DEFAULT_LIMIT`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A ChaCha20-Poly1305 key bound to its nonce sequence.
+///
+/// Sealed messages carry their nonce:
+///
+/// ```text
+/// nonce (12) || ciphertext || tag (16)
+/// ```
+///
+/// They can be opened with [`open`], which needs only the key.
+pub struct SealingKey {
+    key: [u8; 32],
+    nonces: NonceSequence,
+}
+
+impl SealingKey {
+    /// Creates a sealing key with a fresh in-memory [`NonceSequence`].
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self::with_sequence(key, NonceSequence::new())
+    }
+
+    /// Creates a sealing key with an existing sequence, typically one
+    /// restored with [`NonceSequence::from_bytes`].
+    ///
+    /// # Security Notes
+    ///
+    /// - The sequence must belong to this key. Pairing a key with a
+    ///   sequence used by another copy of the same key reuses nonces.
+    pub fn with_sequence(key: &[u8; 32], nonces: NonceSequence) -> Self {
+        Self { key: *key, nonces }
+    }
+
+    /// Encrypts and authenticates a message with the next nonce.
+    ///
+    /// # Parameters
+    ///
+    /// - `aad`: Additional authenticated data
+    /// - `plaintext`: Message to encrypt
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(nonce || ciphertext || tag)` on success
+    /// - `Err(Exhausted | NotReserved)` if no nonce is available
+    pub fn seal(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, NonceError> {
+        let nonce = self.nonces.advance()?;
+
+        let mut out = vec![0u8; NONCE_LEN + plaintext.len() + 16];
+        out[..NONCE_LEN].copy_from_slice(&nonce);
+
+        let (ciphertext, tag) = out[NONCE_LEN..].split_at_mut(plaintext.len());
+        let mut tag_bytes = [0u8; 16];
+        encrypt_with_aad(
+            &self.key,
+            &nonce,
+            aad,
+            plaintext,
+            ciphertext,
+            &mut tag_bytes,
+        );
+        tag.copy_from_slice(&tag_bytes);
+
+        Ok(out)
+    }
+
+    /// Returns the nonce sequence, e.g. to reserve and persist nonces.
+    pub fn sequence(&mut self) -> &mut NonceSequence {
+        &mut self.nonces
+    }
+}
+
+impl Drop for SealingKey {
+    fn drop(&mut self) {
+        self.key.fill(0);
+    }
+}
+
+/// Opens a message produced by [`SealingKey::seal`].
+///
+/// # Parameters
+///
+/// - `key`: ChaCha20-Poly1305 key
+/// - `aad`: Additional authenticated data supplied by the sender
+/// - `sealed`: `nonce || ciphertext || tag`
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(Truncated)` if `sealed` is too short
+/// - `Err(AuthenticationFailed)` if the tag does not verify
+pub fn open(key: &[u8; 32], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>, NonceError> {
+    if sealed.len() < NONCE_LEN + 16 {
+        return Err(NonceError::Truncated);
+    }
+
+    let (nonce, rest) = sealed.split_at(NONCE_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - 16);
+
+    let mut plaintext = vec![0u8; ciphertext.len()];
+    decrypt_with_aad(
+        key,
+        nonce.try_into().unwrap(),
+        aad,
+        ciphertext,
+        tag.try_into().unwrap(),
+        &mut plaintext,
+    )
+    .map_err(|_| NonceError::AuthenticationFailed)?;
+
+    Ok(plaintext)
+}
@@ -0,0 +1,186 @@
+//! Length-hiding padding.
+//!
+//! AEADs hide the content of a message but not its length, which can be
+//! enough to tell messages apart. This module pads plaintexts before
+//! encryption so that many different lengths map to the same size:
+//!
+//! - [`Padding::Padme`]: Padmé (PURBs, Nikitin et al. 2019), which leaks
+//!   `O(log log n)` bits of the length with at most 12% overhead
+//! - [`Padding::PowerOfTwo`]: the next power of two, at most 100% overhead
+//! - [`Padding::Buckets`]: the smallest of a list of configured sizes
+//!
+//! The padding itself is ISO/IEC 7816-4 style, which is unambiguous for
+//! any content:
+//!
+//! ```text
+//! data || 0x80 || 0x00 ... 0x00
+//! ```
+//!
+//! ## Notes
+//!
+//! - Pad **before** encrypting, and unpad only after the AEAD tag has
+//!   been verified.
+//! - [`unpad`] scans the whole buffer in constant time, so the position
+//!   of the marker (the original length) does not leak through timing.
+//!   Copying the result out naturally depends on its length.
+
+/// ISO/IEC 7816-4 padding marker.
+const MARKER: u8 = 0x80;
+
+/// Errors that can occur while padding or unpadding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingError {
+    /// The padded length would overflow, or exceeds the largest bucket.
+    TooLong,
+    /// The bucket list is empty or not strictly increasing.
+    InvalidBuckets,
+    /// The input does not end with a valid padding.
+    InvalidPadding,
+}
+
+/// Padding scheme, selecting the padded size for a given length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding<'a> {
+    /// Padmé: rounds the length so that only the top `O(log log n)` bits
+    /// of it vary.
+    Padme,
+
+    /// Rounds the length up to the next power of two.
+    PowerOfTwo,
+
+    /// Rounds the length up to the smallest listed size. The sizes must be
+    /// strictly increasing.
+    Buckets(&'a [usize]),
+}
+
+/// Returns the padded size for `len` bytes of data.
+///
+/// The size includes the mandatory marker byte, so it is always at least
+/// `len + 1`.
+///
+/// # Returns
+///
+/// - `Ok(size)` on success
+/// - `Err(TooLong)` if the size overflows or exceeds the last bucket
+/// - `Err(InvalidBuckets)` if the bucket list is invalid
+pub fn padded_len(len: usize, scheme: Padding<'_>) -> Result<usize, PaddingError> {
+    let min = len.checked_add(1).ok_or(PaddingError::TooLong)?;
+
+    match scheme {
+        Padding::Padme => padme(min),
+
+        Padding::PowerOfTwo => min.checked_next_power_of_two().ok_or(PaddingError::TooLong),
+
+        Padding::Buckets(sizes) => {
+            if sizes.is_empty() || sizes.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(PaddingError::InvalidBuckets);
+            }
+
+            sizes
+                .iter()
+                .copied()
+                .find(|&size| size >= min)
+                .ok_or(PaddingError::TooLong)
+        }
+    }
+}
+
+/// Pads `data` according to `scheme`.
+///
+/// # Parameters
+///
+/// - `data`: Plaintext to pad
+/// - `scheme`: Padding scheme
+///
+/// # Returns
+///
+/// - `Ok(padded)` with `padded_len(data.len(), scheme)` bytes
+/// - `Err(_)` as for [`padded_len`]
+pub fn pad(data: &[u8], scheme: Padding<'_>) -> Result<Vec<u8>, PaddingError> {
+    let size = padded_len(data.len(), scheme)?;
+
+    let mut out = Vec::with_capacity(size);
+    out.extend_from_slice(data);
+    out.push(MARKER);
+    out.resize(size, 0);
+
+    Ok(out)
+}
+
+/// Removes padding added by [`pad`].
+///
+/// # Parameters
+///
+/// - `padded`: Padded data
+///
+/// # Returns
+///
+/// - `Ok(data)` on success
+/// - `Err(InvalidPadding)` if no marker is found, or a non-zero byte
+///   follows it
+///
+/// # Security Notes
+///
+/// - Every byte is inspected regardless of where the marker is, and no
+///   branch depends on the data, so timing does not reveal the length.
+pub fn unpad(padded: &[u8]) -> Result<Vec<u8>, PaddingError> {
+    let len = unpadded_len(padded)?;
+    Ok(padded[..len].to_vec())
+}
+
+/// Returns the length of the data before the padding, in constant time.
+///
+/// # Returns
+///
+/// - `Ok(len)` on success
+/// - `Err(InvalidPadding)` if the padding is malformed
+pub fn unpadded_len(padded: &[u8]) -> Result<usize, PaddingError> {
+    // All flags are 0 or 1; masks are all-zero or all-one words.
+    let mut found = 0usize;
+    let mut invalid = 0usize;
+    let mut position = 0usize;
+
+    for (i, &byte) in padded.iter().enumerate().rev() {
+        let is_zero = ct_is_zero(byte);
+        let is_marker = ct_is_zero(byte ^ MARKER);
+        let searching = found ^ 1;
+
+        // First marker seen from the end.
+        let take = searching & is_marker;
+        let mask = take.wrapping_neg();
+        position = (i & mask) | (position & !mask);
+
+        // Before the marker, only zero bytes are allowed.
+        invalid |= searching & (is_zero ^ 1) & (is_marker ^ 1);
+        found |= take;
+    }
+
+    if (found & (invalid ^ 1)) == 0 {
+        return Err(PaddingError::InvalidPadding);
+    }
+
+    Ok(position)
+}
+
+/// Returns `1` if `byte` is zero, `0` otherwise, without branching.
+#[inline(always)]
+fn ct_is_zero(byte: u8) -> usize {
+    let x = byte as u32;
+    (((x | x.wrapping_neg()) >> 31) ^ 1) as usize
+}
+
+/// Padmé: keeps the top `floor(log2(E)) + 1` bits of a length whose
+/// exponent is `E`, rounding the rest up.
+fn padme(len: usize) -> Result<usize, PaddingError> {
+    if len < 2 {
+        return Ok(len);
+    }
+
+    let e = len.ilog2();
+    let s = e.ilog2() + 1;
+    let mask = (1usize << (e - s)) - 1;
+
+    len.checked_add(mask)
+        .map(|v| v & !mask)
+        .ok_or(PaddingError::TooLong)
+}
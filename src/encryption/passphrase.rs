@@ -0,0 +1,319 @@
+//! Passphrase-based file encryption.
+//!
+//! This module combines Argon2id and ChaCha20-Poly1305 into a single,
+//! self-contained file format, so that a passphrase is all that is needed
+//! to decrypt an exported vault:
+//!
+//! ```text
+//! +-------+---------+---------+------+-------+------+-------+------------+-----+
+//! | magic | version | mem_kib | time | lanes | salt | nonce | ciphertext | tag |
+//! |   4   |    1    |    4    |  4   |   4   |  16  |  12   |     n      | 16  |
+//! +-------+---------+---------+------+-------+------+-------+------------+-----+
+//! \___________________________ header (45 bytes) ___________/
+//! ```
+//!
+//! The key is `argon2id(passphrase, salt, params)` with a 32-byte output.
+//! The whole header is authenticated as associated data, so tampering
+//! with the parameters, salt or nonce causes decryption to fail.
+//!
+//! ## Design goals
+//!
+//! - One well-defined format instead of ad-hoc "KDF then AEAD" code
+//! - Parameters stored with the data, so they can be raised over time
+//! - Safe against hostile files: parameters are bounded **before** any
+//!   key derivation is attempted
+//!
+//! ## Notes
+//!
+//! - Integers are big-endian.
+//! - Salt and nonce are drawn from `Csprng` on every encryption.
+//! - Parameters read from a file are checked against
+//!   [`PassphraseLimits`]. A file written with parameters above the
+//!   reader's limits is rejected, so choose limits that cover the
+//!   parameters used for encryption.
+
+use super::poly1305::core::{decrypt_with_aad, encrypt_with_aad};
+use crate::derivation::{Argon2Params, argon2id};
+use crate::rng::Csprng;
+
+/// Magic bytes identifying a passphrase-encrypted file.
+pub const MAGIC: [u8; 4] = *b"CRPW";
+
+/// Current (and only supported) file format version.
+pub const VERSION: u8 = 1;
+
+/// Salt length, in bytes.
+pub const SALT_LEN: usize = 16;
+
+/// Length of the authenticated header, in bytes.
+pub const HEADER_LEN: usize = 4 + 1 + 4 + 4 + 4 + SALT_LEN + 12;
+
+/// Length of the authentication tag, in bytes.
+pub const TAG_LEN: usize = 16;
+
+/// Errors that can occur during passphrase encryption or decryption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassphraseError {
+    /// The input is shorter than a header and a tag.
+    Truncated,
+    /// The magic bytes do not match.
+    InvalidMagic,
+    /// The version is not supported by this implementation.
+    UnsupportedVersion,
+    /// The Argon2id parameters are invalid.
+    InvalidParams,
+    /// The Argon2id parameters exceed the configured limits.
+    ParamsTooExpensive,
+    /// The passphrase is wrong or the file was modified.
+    AuthenticationFailed,
+}
+
+/// Upper bounds on Argon2id parameters accepted from a file.
+///
+/// Without these bounds, a crafted file could request gigabytes of memory
+/// or hours of computation before the passphrase is even checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassphraseLimits {
+    /// Maximum memory cost, in KiB.
+    pub max_mem_kib: u32,
+
+    /// Maximum number of passes.
+    pub max_time: u32,
+
+    /// Maximum degree of parallelism.
+    pub max_lanes: u32,
+}
+
+impl Default for PassphraseLimits {
+    /// 1 GiB of memory, 10 passes and 16 lanes.
+    fn default() -> Self {
+        Self {
+            max_mem_kib: 1024 * 1024,
+            max_time: 10,
+            max_lanes: 16,
+        }
+    }
+}
+
+/// Encrypts data under a passphrase.
+///
+/// # Parameters
+///
+/// - `passphrase`: User passphrase
+/// - `plaintext`: Data to encrypt
+/// - `params`: Argon2id cost parameters (`tag_len` is ignored; the key is
+///   always 32 bytes)
+///
+/// # Returns
+///
+/// - `Ok(file)` with `HEADER_LEN + plaintext.len() + TAG_LEN` bytes
+/// - `Err(InvalidParams)` if the parameters are rejected by Argon2id
+pub fn encrypt(
+    passphrase: &[u8],
+    plaintext: &[u8],
+    params: &Argon2Params,
+) -> Result<Vec<u8>, PassphraseError> {
+    let mut rng = Csprng::new();
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; 12];
+    rng.fill_bytes(&mut salt);
+    rng.fill_bytes(&mut nonce);
+
+    let params = key_params(params);
+    let header = write_header(&params, &salt, &nonce);
+    let mut key = derive_key(passphrase, &salt, &params)?;
+
+    let mut out = vec![0u8; HEADER_LEN + plaintext.len() + TAG_LEN];
+    out[..HEADER_LEN].copy_from_slice(&header);
+
+    let (ciphertext, tag_out) = out[HEADER_LEN..].split_at_mut(plaintext.len());
+    let mut tag = [0u8; TAG_LEN];
+    encrypt_with_aad(&key, &nonce, &header, plaintext, ciphertext, &mut tag);
+    tag_out.copy_from_slice(&tag);
+
+    key.fill(0);
+    Ok(out)
+}
+
+/// Decrypts a file using the default [`PassphraseLimits`].
+///
+/// See [`decrypt_with_limits`].
+pub fn decrypt(passphrase: &[u8], file: &[u8]) -> Result<Vec<u8>, PassphraseError> {
+    decrypt_with_limits(passphrase, file, &PassphraseLimits::default())
+}
+
+/// Decrypts a file, rejecting parameters above `limits`.
+///
+/// # Parameters
+///
+/// - `passphrase`: User passphrase
+/// - `file`: Encrypted file contents
+/// - `limits`: Maximum Argon2id cost accepted from the file
+///
+/// # Returns
+///
+/// - `Ok(plaintext)` on success
+/// - `Err(Truncated | InvalidMagic | UnsupportedVersion)` for malformed files
+/// - `Err(InvalidParams)` if the stored parameters are invalid
+/// - `Err(ParamsTooExpensive)` if they exceed `limits`
+/// - `Err(AuthenticationFailed)` if the passphrase is wrong or the file
+///   was modified
+///
+/// # Security Notes
+///
+/// - The parameter bounds are checked on the raw header values, before
+///   any other use of the parameters.
+pub fn decrypt_with_limits(
+    passphrase: &[u8],
+    file: &[u8],
+    limits: &PassphraseLimits,
+) -> Result<Vec<u8>, PassphraseError> {
+    let (params, salt, nonce) = read_header(file, Some(limits))?;
+
+    let (header, rest) = file.split_at(HEADER_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+    let tag: &[u8; TAG_LEN] = tag.try_into().unwrap();
+
+    let mut key = derive_key(passphrase, &salt, &params)?;
+    let mut plaintext = vec![0u8; ciphertext.len()];
+    let result = decrypt_with_aad(&key, &nonce, header, ciphertext, tag, &mut plaintext);
+    key.fill(0);
+
+    if result.is_err() {
+        plaintext.fill(0);
+        return Err(PassphraseError::AuthenticationFailed);
+    }
+
+    Ok(plaintext)
+}
+
+/// Decrypts a file and encrypts it again with new parameters.
+///
+/// This is used to raise the Argon2id cost of existing files or to change
+/// the passphrase. A fresh salt and nonce are always generated.
+///
+/// # Parameters
+///
+/// - `passphrase`: Current passphrase
+/// - `new_passphrase`: Passphrase for the new file (may equal `passphrase`)
+/// - `file`: Encrypted file contents
+/// - `params`: Argon2id parameters for the new file
+/// - `limits`: Maximum Argon2id cost accepted from `file`
+///
+/// # Returns
+///
+/// - `Ok(file)` with the re-encrypted contents
+/// - `Err(_)` with the decryption or encryption error otherwise
+pub fn reencrypt(
+    passphrase: &[u8],
+    new_passphrase: &[u8],
+    file: &[u8],
+    params: &Argon2Params,
+    limits: &PassphraseLimits,
+) -> Result<Vec<u8>, PassphraseError> {
+    let mut plaintext = decrypt_with_limits(passphrase, file, limits)?;
+    let result = encrypt(new_passphrase, &plaintext, params);
+    plaintext.fill(0);
+
+    result
+}
+
+/// Returns the Argon2id parameters stored in a file.
+///
+/// The returned values are **not** authenticated until the file has been
+/// decrypted successfully. This is intended for deciding whether a file
+/// should be re-encrypted with stronger parameters.
+pub fn read_params(file: &[u8]) -> Result<Argon2Params, PassphraseError> {
+    read_header(file, None).map(|(params, _, _)| params)
+}
+
+/// Normalizes caller parameters for key derivation.
+fn key_params(params: &Argon2Params) -> Argon2Params {
+    Argon2Params {
+        mem_kib: params.mem_kib,
+        time: params.time,
+        lanes: params.lanes,
+        tag_len: 32,
+    }
+}
+
+/// Runs Argon2id and returns a 32-byte key.
+fn derive_key(
+    passphrase: &[u8],
+    salt: &[u8; SALT_LEN],
+    params: &Argon2Params,
+) -> Result<[u8; 32], PassphraseError> {
+    let mut tag = argon2id(passphrase, salt, params).map_err(|_| PassphraseError::InvalidParams)?;
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&tag);
+    tag.fill(0);
+
+    Ok(key)
+}
+
+/// Serializes the header.
+fn write_header(
+    params: &Argon2Params,
+    salt: &[u8; SALT_LEN],
+    nonce: &[u8; 12],
+) -> [u8; HEADER_LEN] {
+    let mut out = [0u8; HEADER_LEN];
+    out[..4].copy_from_slice(&MAGIC);
+    out[4] = VERSION;
+    out[5..9].copy_from_slice(&params.mem_kib.to_be_bytes());
+    out[9..13].copy_from_slice(&params.time.to_be_bytes());
+    out[13..17].copy_from_slice(&params.lanes.to_be_bytes());
+    out[17..17 + SALT_LEN].copy_from_slice(salt);
+    out[17 + SALT_LEN..].copy_from_slice(nonce);
+    out
+}
+
+/// Parses and validates the header.
+///
+/// When `limits` is given, the parameters are checked against it before
+/// being validated.
+fn read_header(
+    file: &[u8],
+    limits: Option<&PassphraseLimits>,
+) -> Result<(Argon2Params, [u8; SALT_LEN], [u8; 12]), PassphraseError> {
+    if file.len() < HEADER_LEN + TAG_LEN {
+        return Err(PassphraseError::Truncated);
+    }
+
+    if file[..4] != MAGIC {
+        return Err(PassphraseError::InvalidMagic);
+    }
+
+    if file[4] != VERSION {
+        return Err(PassphraseError::UnsupportedVersion);
+    }
+
+    let word = |i: usize| u32::from_be_bytes(file[i..i + 4].try_into().unwrap());
+
+    let params = Argon2Params {
+        mem_kib: word(5),
+        time: word(9),
+        lanes: word(13),
+        tag_len: 32,
+    };
+
+    let too_expensive = limits.is_some_and(|limits| {
+        params.mem_kib > limits.max_mem_kib
+            || params.time > limits.max_time
+            || params.lanes > limits.max_lanes
+    });
+    if too_expensive {
+        return Err(PassphraseError::ParamsTooExpensive);
+    }
+
+    params
+        .validate()
+        .map_err(|_| PassphraseError::InvalidParams)?;
+
+    let salt = file[17..17 + SALT_LEN].try_into().unwrap();
+    let nonce = file[17 + SALT_LEN..HEADER_LEN].try_into().unwrap();
+
+    Ok((params, salt, nonce))
+}
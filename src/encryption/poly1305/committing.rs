@@ -0,0 +1,207 @@
+//! Key-committing ChaCha20-Poly1305.
+//!
+//! Poly1305-based AEADs are not key-committing: an attacker can craft a
+//! single ciphertext that authenticates under many different keys. When the
+//! key is derived from a password, this enables *partitioning-oracle*
+//! attacks, where each decryption attempt tests thousands of candidate
+//! passwords at once.
+//!
+//! This module implements the *CommitKey* transform on top of
+//! ChaCha20-Poly1305. From the user key `K` and the nonce `N`, two values
+//! are derived with keyed BLAKE2b-256:
+//!
+//! ```text
+//! commitment = BLAKE2b-256(key = K, LABEL || 0x00 || N)
+//! subkey     = BLAKE2b-256(key = K, LABEL || 0x01 || N)
+//! ```
+//!
+//! The message is encrypted with ChaCha20-Poly1305 under `subkey`, and the
+//! 32-byte commitment is transmitted alongside the ciphertext. Decryption
+//! recomputes the commitment and rejects the message before touching the
+//! ciphertext if it does not match.
+//!
+//! ## Design goals
+//!
+//! - Dependency-free
+//! - Same call shape as `chacha20poly1305`, plus a commitment output
+//! - Constant-time commitment and tag checks
+//!
+//! ## Notes
+//!
+//! - Finding two keys that accept the same ciphertext requires a BLAKE2b
+//!   collision, so each ciphertext decrypts under at most one key.
+//! - The commitment also binds the nonce.
+//! - The caller must still ensure `(key, nonce)` uniqueness.
+
+use super::core::{Chacha20Poly1305Error, decrypt_with_aad, encrypt_with_aad};
+use crate::hash::blake2b::core::Blake2b;
+
+/// Domain separation label for the commitment and subkey derivation.
+const LABEL: &[u8] = b"cryptal-chacha20poly1305-commit-v1";
+
+/// Encrypts and authenticates a message using key-committing
+/// ChaCha20-Poly1305.
+///
+/// This is equivalent to [`encrypt_committing_with_aad`] with an empty AAD.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: 96-bit nonce (IETF variant)
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+/// - `commitment`: Output key commitment (32 bytes)
+///
+/// # Panics
+///
+/// Panics if `plaintext.len() != ciphertext.len()`.
+pub fn encrypt_committing(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+    commitment: &mut [u8; 32],
+) {
+    encrypt_committing_with_aad(key, nonce, &[], plaintext, ciphertext, tag, commitment);
+}
+
+/// Encrypts and authenticates a message and its associated data using
+/// key-committing ChaCha20-Poly1305.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: 96-bit nonce (IETF variant)
+/// - `aad`: Additional authenticated data (authenticated, not encrypted)
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+/// - `commitment`: Output key commitment (32 bytes)
+///
+/// # Panics
+///
+/// Panics if `plaintext.len() != ciphertext.len()`.
+///
+/// # Security Notes
+///
+/// - The commitment must be stored or transmitted with the ciphertext.
+/// - `(key, nonce)` MUST be unique per encryption.
+pub fn encrypt_committing_with_aad(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+    commitment: &mut [u8; 32],
+) {
+    let mut subkey = derive(key, nonce, 0x01);
+
+    encrypt_with_aad(&subkey, nonce, aad, plaintext, ciphertext, tag);
+    *commitment = derive(key, nonce, 0x00);
+
+    subkey.fill(0);
+}
+
+/// Decrypts and authenticates a message using key-committing
+/// ChaCha20-Poly1305.
+///
+/// This is equivalent to [`decrypt_committing_with_aad`] with an empty AAD.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: 96-bit nonce (IETF variant)
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `commitment`: Key commitment to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// - `Ok(())` if the commitment and the tag are valid
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+/// - `Err(AuthenticationFailed)` if the commitment or the tag is invalid
+pub fn decrypt_committing(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    commitment: &[u8; 32],
+    plaintext: &mut [u8],
+) -> Result<(), Chacha20Poly1305Error> {
+    decrypt_committing_with_aad(key, nonce, &[], ciphertext, tag, commitment, plaintext)
+}
+
+/// Decrypts and authenticates a message and its associated data using
+/// key-committing ChaCha20-Poly1305.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: 96-bit nonce (IETF variant)
+/// - `aad`: Additional authenticated data supplied at encryption time
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `commitment`: Key commitment to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// - `Ok(())` if the commitment and the tag are valid
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+/// - `Err(AuthenticationFailed)` if the commitment or the tag is invalid
+///
+/// # Security Notes
+///
+/// - The commitment is checked first, in constant time, so a ciphertext
+///   crafted for another key is rejected without running the AEAD.
+/// - Both failure causes are reported identically.
+pub fn decrypt_committing_with_aad(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    commitment: &[u8; 32],
+    plaintext: &mut [u8],
+) -> Result<(), Chacha20Poly1305Error> {
+    if plaintext.len() != ciphertext.len() {
+        return Err(Chacha20Poly1305Error::InvalidLength);
+    }
+
+    let expected = derive(key, nonce, 0x00);
+
+    let mut diff = 0u8;
+    for i in 0..32 {
+        diff |= expected[i] ^ commitment[i];
+    }
+
+    if diff != 0 {
+        return Err(Chacha20Poly1305Error::AuthenticationFailed);
+    }
+
+    let mut subkey = derive(key, nonce, 0x01);
+    let result = decrypt_with_aad(&subkey, nonce, aad, ciphertext, tag, plaintext);
+    subkey.fill(0);
+
+    result
+}
+
+/// Derives the commitment (`domain = 0x00`) or the subkey (`domain = 0x01`).
+fn derive(key: &[u8; 32], nonce: &[u8; 12], domain: u8) -> [u8; 32] {
+    let mut state = Blake2b::new(32, key);
+    state.update(LABEL);
+    state.update(&[domain]);
+    state.update(nonce);
+
+    let mut digest = state.finalize();
+
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&digest[..32]);
+    digest.fill(0);
+
+    out
+}
@@ -15,20 +15,14 @@
 //!
 //! ## Notes
 //!
-//! - This implementation currently uses an empty AAD (`AAD = []`).
+//! - `encrypt` / `decrypt` authenticate an empty AAD.
+//! - `encrypt_with_aad` / `decrypt_with_aad` accept caller-provided
+//!   associated data, which is authenticated but not encrypted.
 //! - The caller must ensure `(key, nonce)` uniqueness.
 //! - Reusing a `(key, nonce)` pair breaks security.
 
 use super::mac::Poly1305;
-use crate::rng::chacha20::{block, xor};
-
-/// Additional Authenticated Data (AAD).
-///
-/// This data is authenticated but not encrypted.
-/// It is currently empty, but the construction fully supports AAD.
-///
-/// In a production API, this should be provided by the caller.
-const AAD: &[u8] = &[];
+use crate::rng::chacha20::{block, xor, xor_in_place};
 
 /// Errors that can occur during ChaCha20-Poly1305 decryption.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,10 +35,41 @@ pub enum Chacha20Poly1305Error {
 
 /// Encrypts and authenticates a message using ChaCha20-Poly1305.
 ///
+/// This is equivalent to [`encrypt_with_aad`] with an empty AAD.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: 96-bit nonce (IETF variant)
+/// - `plaintext`: Input message to encrypt
+/// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
+/// - `tag`: Output authentication tag (16 bytes)
+///
+/// # Panics
+///
+/// Panics if `plaintext.len() != ciphertext.len()`.
+///
+/// # Security Notes
+///
+/// - `(key, nonce)` MUST be unique per encryption.
+pub fn encrypt(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    plaintext: &[u8],
+    ciphertext: &mut [u8],
+    tag: &mut [u8; 16],
+) {
+    encrypt_with_aad(key, nonce, &[], plaintext, ciphertext, tag);
+}
+
+/// Encrypts and authenticates a message and its associated data using
+/// ChaCha20-Poly1305.
+///
 /// # Parameters
 ///
 /// - `key`: 256-bit secret key
 /// - `nonce`: 96-bit nonce (IETF variant)
+/// - `aad`: Additional authenticated data (authenticated, not encrypted)
 /// - `plaintext`: Input message to encrypt
 /// - `ciphertext`: Output buffer for encrypted data (same length as `plaintext`)
 /// - `tag`: Output authentication tag (16 bytes)
@@ -68,9 +93,10 @@ pub enum Chacha20Poly1305Error {
 /// - This function does not allocate secret material on the heap except
 ///   for the MAC buffer.
 /// - `(key, nonce)` MUST be unique per encryption.
-pub fn encrypt(
+pub fn encrypt_with_aad(
     key: &[u8; 32],
     nonce: &[u8; 12],
+    aad: &[u8],
     plaintext: &[u8],
     ciphertext: &mut [u8],
     tag: &mut [u8; 16],
@@ -85,24 +111,16 @@ pub fn encrypt(
     // Encrypt plaintext (ChaCha20 is symmetric)
     xor(key, nonce, 1, plaintext, ciphertext);
 
-    // Build MAC input according to RFC 8439
-    let mut mac_data = Vec::new();
+    // Compute authentication tag according to RFC 8439
+    auth(tag, &otk, &mac_data(aad, ciphertext));
 
-    mac_data.extend_from_slice(AAD);
-    pad16(&mut mac_data);
-
-    mac_data.extend_from_slice(ciphertext);
-    pad16(&mut mac_data);
-
-    mac_data.extend_from_slice(&(AAD.len() as u64).to_le_bytes());
-    mac_data.extend_from_slice(&(ciphertext.len() as u64).to_le_bytes());
-
-    // Compute authentication tag
-    auth(tag, &otk, &mac_data);
+    otk.fill(0);
 }
 
 /// Decrypts and authenticates a message using ChaCha20-Poly1305.
 ///
+/// This is equivalent to [`decrypt_with_aad`] with an empty AAD.
+///
 /// # Parameters
 ///
 /// - `key`: 256-bit secret key
@@ -116,11 +134,38 @@ pub fn encrypt(
 /// - `Ok(())` if authentication succeeds and decryption is successful
 /// - `Err(InvalidLength)` if buffer sizes mismatch
 /// - `Err(AuthenticationFailed)` if tag verification fails
+pub fn decrypt(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    ciphertext: &[u8],
+    tag: &[u8; 16],
+    plaintext: &mut [u8],
+) -> Result<(), Chacha20Poly1305Error> {
+    decrypt_with_aad(key, nonce, &[], ciphertext, tag, plaintext)
+}
+
+/// Decrypts and authenticates a message and its associated data using
+/// ChaCha20-Poly1305.
+///
+/// # Parameters
+///
+/// - `key`: 256-bit secret key
+/// - `nonce`: 96-bit nonce (IETF variant)
+/// - `aad`: Additional authenticated data supplied at encryption time
+/// - `ciphertext`: Encrypted input data
+/// - `tag`: Authentication tag to verify
+/// - `plaintext`: Output buffer for decrypted data
+///
+/// # Returns
+///
+/// - `Ok(())` if authentication succeeds and decryption is successful
+/// - `Err(InvalidLength)` if buffer sizes mismatch
+/// - `Err(AuthenticationFailed)` if tag verification fails
 ///
 /// # Algorithm
 ///
 /// 1. Recompute the Poly1305 one-time key
-/// 2. Rebuild the MAC input exactly as in `encrypt`
+/// 2. Rebuild the MAC input exactly as in `encrypt_with_aad`
 /// 3. Verify the authentication tag in constant time
 /// 4. Decrypt the ciphertext if authentication succeeds
 ///
@@ -128,9 +173,10 @@ pub fn encrypt(
 ///
 /// - Decryption is only performed after successful authentication
 /// - Tag comparison is constant-time
-pub fn decrypt(
+pub fn decrypt_with_aad(
     key: &[u8; 32],
     nonce: &[u8; 12],
+    aad: &[u8],
     ciphertext: &[u8],
     tag: &[u8; 16],
     plaintext: &mut [u8],
@@ -143,35 +189,101 @@ pub fn decrypt(
     let mut otk = [0u8; 32];
     otk.copy_from_slice(&block0[..32]);
 
-    let mut mac_data = Vec::new();
+    let mut expected_tag = [0u8; 16];
+    auth(&mut expected_tag, &otk, &mac_data(aad, ciphertext));
 
-    mac_data.extend_from_slice(AAD);
-    pad16(&mut mac_data);
+    let mut diff = 0u8;
+    for i in 0..16 {
+        diff |= expected_tag[i] ^ tag[i];
+    }
 
-    mac_data.extend_from_slice(ciphertext);
-    pad16(&mut mac_data);
+    otk.fill(0);
 
-    mac_data.extend_from_slice(&(AAD.len() as u64).to_le_bytes());
-    mac_data.extend_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+    if diff != 0 {
+        return Err(Chacha20Poly1305Error::AuthenticationFailed);
+    }
+
+    xor(key, nonce, 1, ciphertext, plaintext);
+    Ok(())
+}
+
+/// Encrypts a buffer in place and computes its tag.
+///
+/// Same construction as [`encrypt_with_aad`], without a separate output
+/// buffer.
+pub(crate) fn encrypt_in_place_with_aad(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &mut [u8; 16],
+) {
+    let block0 = block(key, 0, nonce);
+    let mut otk = [0u8; 32];
+    otk.copy_from_slice(&block0[..32]);
+
+    xor_in_place(key, nonce, 1, buffer);
+    auth(tag, &otk, &mac_data(aad, buffer));
+
+    otk.fill(0);
+}
+
+/// Verifies a tag and decrypts a buffer in place.
+///
+/// Same construction as [`decrypt_with_aad`]. The buffer is left
+/// untouched if authentication fails.
+pub(crate) fn decrypt_in_place_with_aad(
+    key: &[u8; 32],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &[u8; 16],
+) -> Result<(), Chacha20Poly1305Error> {
+    let block0 = block(key, 0, nonce);
+    let mut otk = [0u8; 32];
+    otk.copy_from_slice(&block0[..32]);
 
     let mut expected_tag = [0u8; 16];
-    auth(&mut expected_tag, &otk, &mac_data);
+    auth(&mut expected_tag, &otk, &mac_data(aad, buffer));
+    otk.fill(0);
 
     let mut diff = 0u8;
     for i in 0..16 {
         diff |= expected_tag[i] ^ tag[i];
     }
 
-    otk.fill(0);
-
     if diff != 0 {
         return Err(Chacha20Poly1305Error::AuthenticationFailed);
     }
 
-    xor(key, nonce, 1, ciphertext, plaintext);
+    xor_in_place(key, nonce, 1, buffer);
     Ok(())
 }
 
+/// Builds the Poly1305 input for the AEAD construction.
+///
+/// The layout is defined by RFC 8439:
+///
+/// ```text
+/// AAD || pad16 || ciphertext || pad16 || len(AAD) || len(ciphertext)
+/// ```
+///
+/// where both lengths are encoded as 64-bit little-endian integers.
+fn mac_data(aad: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let mut mac_data = Vec::with_capacity(aad.len() + ciphertext.len() + 48);
+
+    mac_data.extend_from_slice(aad);
+    pad16(&mut mac_data);
+
+    mac_data.extend_from_slice(ciphertext);
+    pad16(&mut mac_data);
+
+    mac_data.extend_from_slice(&(aad.len() as u64).to_le_bytes());
+    mac_data.extend_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+
+    mac_data
+}
+
 /// Pads a buffer with zero bytes until its length is a multiple of 16.
 ///
 /// This is required by the Poly1305 input format defined in RFC 8439.
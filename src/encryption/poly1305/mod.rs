@@ -8,6 +8,7 @@
 //!
 //! - `core`: low-level arithmetic and block processing
 //! - `mac`: safe, high-level MAC interface
+//! - `committing`: key-committing AEAD built on top of `core`
 //!
 //! This module is **not** intended to be used directly by end users.
 //! It is exposed internally to support authenticated encryption schemes.
@@ -35,3 +36,12 @@ pub mod core;
 /// It is intended to be used by higher-level constructions such as
 /// ChaCha20-Poly1305.
 pub(crate) mod mac;
+
+/// Key-committing ChaCha20-Poly1305.
+///
+/// This module wraps the AEAD in `core` with a BLAKE2b key commitment:
+/// - derives a per-nonce subkey and commitment from the user key
+/// - rejects ciphertexts whose commitment does not match the key
+///
+/// It protects password-derived keys against partitioning-oracle attacks.
+pub mod committing;
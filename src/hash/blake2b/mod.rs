@@ -14,6 +14,6 @@
 //! for higher-level constructions and does not provide streaming I/O APIs.
 
 pub(crate) mod consts;
-mod core;
+pub(crate) mod core;
 pub(crate) mod hash;
 pub(crate) mod utils;
@@ -0,0 +1,62 @@
+//! HMAC-SHA256 message authentication (RFC 2104, FIPS 198-1).
+//!
+//! HMAC turns a Merkle–Damgård hash function into a keyed pseudorandom
+//! function:
+//!
+//! ```text
+//! HMAC(K, m) = H((K' ⊕ opad) || H((K' ⊕ ipad) || m))
+//! ```
+//!
+//! where `K'` is the key padded (or first hashed, if longer than a block)
+//! to the 64-byte SHA-256 block size.
+//!
+//! HMAC-SHA256 is used as the building block of HKDF and PBKDF2.
+
+use crate::hash::sha256;
+
+/// SHA-256 block size in bytes.
+const BLOCK_SIZE: usize = 64;
+
+/// Computes the HMAC-SHA256 of a message.
+///
+/// # Parameters
+///
+/// - `key`: Secret key of arbitrary length
+/// - `message`: Message to authenticate
+///
+/// # Returns
+///
+/// The 32-byte authentication tag.
+///
+/// # Security Notes
+///
+/// - Tags must be compared in constant time by the caller.
+/// - Keys longer than 64 bytes are hashed first, as required by RFC 2104.
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block_key = [0u8; BLOCK_SIZE];
+
+    if key.len() > BLOCK_SIZE {
+        block_key[..32].copy_from_slice(sha256(key).as_ref());
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Vec::with_capacity(BLOCK_SIZE + message.len());
+    inner.extend(block_key.iter().map(|b| b ^ 0x36));
+    inner.extend_from_slice(message);
+    let inner_hash = sha256(&inner);
+
+    let mut outer = [0u8; BLOCK_SIZE + 32];
+    for (o, k) in outer.iter_mut().zip(block_key.iter()) {
+        *o = k ^ 0x5c;
+    }
+    outer[BLOCK_SIZE..].copy_from_slice(inner_hash.as_ref());
+
+    let tag = *sha256(&outer).as_ref();
+
+    inner.fill(0);
+    outer.fill(0);
+    block_key.fill(0);
+
+    tag
+}
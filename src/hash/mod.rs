@@ -11,13 +11,14 @@
 //!
 //! This module intentionally exposes **hashing primitives only**.
 //! It does not provide streaming, incremental, or stateful APIs.
-//! More complex constructions (KDFs, MACs, password hashing, etc.) are
+//! More complex constructions (KDFs, password hashing, etc.) are
 //! expected to be built on top of these functions.
 //!
 //! Currently supported primitives:
 //! - SHA-256
 //! - SHA-512
 //! - Blake2b (fixed-length and extendable-output variants)
+//! - HMAC-SHA256 (keyed hashing, the basis of HKDF)
 //!
 //! Blake2b is provided both as a standard cryptographic hash function
 //! (up to 512-bit output) and as an extendable-output function (XOF),
@@ -27,7 +28,8 @@
 //! The module is structured to allow additional hash functions to be added
 //! in the future without breaking existing users.
 
-mod blake2b;
+pub(crate) mod blake2b;
+mod hmac;
 mod sha256;
 mod sha512;
 
@@ -42,6 +44,9 @@ pub use sha256::core::sha256;
 /// output or higher collision resistance.
 pub use sha512::core::sha512;
 
+/// Incremental SHA-512 state, for streaming inputs inside the crate.
+pub(crate) use sha512::core::Sha512;
+
 /// Computes a Blake2b hash with a configurable output length (up to 64 bytes).
 ///
 /// This is the standard Blake2b hash function as defined in RFC 7693.
@@ -53,3 +58,8 @@ pub use blake2b::hash::blake2b;
 /// chained Blake2b-512 invocations. It is a low-level primitive commonly
 /// used in higher-level constructions (e.g. Argon2 initialization).
 pub use blake2b::hash::blake2b_long;
+
+/// Computes the HMAC-SHA256 of a message under a secret key.
+///
+/// This is the keyed pseudorandom function used by HKDF (RFC 5869).
+pub use hmac::hmac_sha256;
@@ -5,6 +5,7 @@
 //!
 //! It provides:
 //! - the compression function operating on 1024-bit blocks
+//! - an incremental hashing state, for crate-internal use
 //! - a complete SHA-512 hashing function for arbitrary-length input
 //!
 //! The implementation is intentionally minimal, explicit, and designed
@@ -38,6 +39,96 @@ pub fn compress(block: &[u8; 128], state: &mut [u64; 8]) {
     all_rounds(state, w);
 }
 
+/// Incremental SHA-512 state.
+///
+/// Used internally where the input is not available as a single slice,
+/// such as streaming prehashes. [`sha512`] is the one-shot equivalent.
+#[derive(Clone)]
+pub(crate) struct Sha512 {
+    /// Current chaining value.
+    state: [u64; 8],
+
+    /// Pending bytes that do not yet fill a block.
+    buffer: [u8; 128],
+
+    /// Number of valid bytes in `buffer`.
+    buffered: usize,
+
+    /// Total message length, in bytes.
+    length: u128,
+}
+
+impl Sha512 {
+    /// Creates a state for an empty message.
+    pub(crate) fn new() -> Self {
+        Self {
+            state: H512_INIT,
+            buffer: [0u8; 128],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    /// Absorbs `input`.
+    pub(crate) fn update(&mut self, mut input: &[u8]) {
+        self.length += input.len() as u128;
+
+        if self.buffered > 0 {
+            let take = input.len().min(128 - self.buffered);
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&input[..take]);
+            self.buffered += take;
+            input = &input[take..];
+
+            if self.buffered < 128 {
+                return;
+            }
+
+            compress(&self.buffer, &mut self.state);
+            self.buffered = 0;
+        }
+
+        let mut blocks = input.chunks_exact(128);
+        for block in &mut blocks {
+            compress(block.try_into().unwrap(), &mut self.state);
+        }
+
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+
+    /// Applies the padding and returns the digest.
+    pub(crate) fn finalize(mut self) -> [u8; 64] {
+        let rem = self.buffered;
+
+        // Append the padding bit (0x80) after the remaining bytes
+        self.buffer[rem..].fill(0);
+        self.buffer[rem] = 0x80;
+
+        // If there is not enough space for the 128-bit length field,
+        // process this block and use an additional zeroed block.
+        if rem > 111 {
+            compress(&self.buffer, &mut self.state);
+            self.buffer = [0; 128];
+        }
+
+        // Append the message length in bits as a 128-bit big-endian integer
+        let bit_len = self.length << 3;
+        self.buffer[112..128].copy_from_slice(&bit_len.to_be_bytes());
+
+        // Final compression
+        compress(&self.buffer, &mut self.state);
+
+        // Serialize final state into big-endian bytes
+        let mut out = [0u8; 64];
+        for (i, word) in self.state.iter().enumerate() {
+            out[i * 8..(i + 1) * 8].copy_from_slice(&word.to_be_bytes());
+        }
+
+        out
+    }
+}
+
 /// Computes the SHA-512 hash of the given input.
 ///
 /// This function processes the input message in 1024-bit blocks, applies
@@ -55,46 +146,7 @@ pub fn compress(block: &[u8; 128], state: &mut [u64; 8]) {
 /// - The internal state uses 8 × 64-bit words and is serialized in big-endian.
 /// - No heap allocations are performed.
 pub fn sha512(input: &[u8]) -> [u8; 64] {
-    // Initialize hash state
-    let mut state = H512_INIT;
-
-    let mut i = 0;
-    let len = input.len();
-
-    // Process full 1024-bit blocks
-    while i + 128 <= len {
-        let block: &[u8; 128] = input[i..i + 128].try_into().unwrap();
-        compress(block, &mut state);
-        i += 128;
-    }
-
-    // Prepare final padded block(s)
-    let mut block = [0u8; 128];
-    let rem = len - i;
-
-    // Copy remaining bytes and append the padding bit (0x80)
-    block[..rem].copy_from_slice(&input[i..]);
-    block[rem] = 0x80;
-
-    // If there is not enough space for the 128-bit length field,
-    // process this block and use an additional zeroed block.
-    if rem > 111 {
-        compress(&block, &mut state);
-        block = [0; 128];
-    }
-
-    // Append the message length in bits as a 128-bit big-endian integer
-    let bit_len = (len as u128) << 3;
-    block[112..128].copy_from_slice(&bit_len.to_be_bytes());
-
-    // Final compression
-    compress(&block, &mut state);
-
-    // Serialize final state into big-endian bytes
-    let mut out = [0u8; 64];
-    for (i, word) in state.iter().enumerate() {
-        out[i * 8..(i + 1) * 8].copy_from_slice(&word.to_be_bytes());
-    }
-
-    out
+    let mut hasher = Sha512::new();
+    hasher.update(input);
+    hasher.finalize()
 }
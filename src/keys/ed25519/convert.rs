@@ -0,0 +1,80 @@
+//! Ed25519 to X25519 key conversion.
+//!
+//! Ed25519 and X25519 use birationally equivalent curves, so an Ed25519
+//! identity key can also be used for Diffie–Hellman, e.g. as an HPKE or
+//! age recipient:
+//!
+//! ```text
+//! public:  u = (1 + y) / (1 - y)
+//! secret:  clamp(SHA-512(seed)[0..32])
+//! ```
+//!
+//! The results are identical to libsodium's
+//! `crypto_sign_ed25519_pk_to_curve25519` and
+//! `crypto_sign_ed25519_sk_to_curve25519`.
+//!
+//! ## Notes
+//!
+//! - Public key conversion rejects keys that would make the exchange
+//!   unsafe: undecodable points, small-order points and points with a
+//!   torsion component.
+//! - The X25519 secret is the Ed25519 secret scalar. It is only
+//!   consistent with the converted public key for keys derived from a
+//!   seed, not for keys updated with [`add_scalar`](super::add_scalar).
+//! - Using one key for both signatures and key exchange is safe for
+//!   these two schemes, but keeping separate keys remains preferable
+//!   where possible.
+
+use super::core::{Ed25519Error, PrivateKey, PublicKey, SigningKey};
+use super::field::FieldElement;
+use super::group::GeP3;
+use crate::keys::x25519;
+
+impl PublicKey {
+    /// Converts this key to an X25519 public key.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(public)` with the Montgomery u-coordinate of the point
+    /// - `Err(InvalidPublicKey)` if the key does not decode, has small
+    ///   order, or is not in the prime-order subgroup
+    pub fn to_x25519(&self) -> Result<x25519::PublicKey, Ed25519Error> {
+        let bytes = self.to_bytes();
+        let (point, invalid) = GeP3::decompress(&bytes);
+
+        if invalid != 0 || point.is_small_order() || !point.is_torsion_free() {
+            return Err(Ed25519Error::InvalidPublicKey);
+        }
+
+        // Birational map from Edwards to Montgomery: u = (1 + y) / (1 - y)
+        let y = FieldElement::from_bytes(&bytes);
+        let one = FieldElement::ONE;
+        let u = (one + y) * (one - y).invert();
+
+        Ok(x25519::PublicKey::from_bytes(&u.to_bytes()))
+    }
+}
+
+impl PrivateKey {
+    /// Converts this key to an X25519 secret.
+    ///
+    /// The secret is the clamped Ed25519 scalar, so that its X25519
+    /// public key is the conversion of the Ed25519 public key.
+    pub fn to_x25519(&self) -> x25519::StaticSecret {
+        let mut scalar = self.scalar().to_bytes();
+        let secret = x25519::StaticSecret::from_bytes(&scalar);
+        scalar.fill(0);
+
+        secret
+    }
+}
+
+impl SigningKey {
+    /// Converts this key pair's secret to an X25519 secret.
+    ///
+    /// This is `clamp(SHA-512(seed)[0..32])`, as in libsodium's
+    /// `crypto_sign_ed25519_sk_to_curve25519`.
+    pub fn to_x25519(&self) -> x25519::StaticSecret {
+        self.private_key().to_x25519()
+    }
+}
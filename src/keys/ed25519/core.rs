@@ -12,10 +12,24 @@
 use super::ct::ConstantTimeEq;
 use super::group::{GeCached, GeP1, GeP3};
 pub use super::scalar::Scalar;
-use crate::hash::sha512;
+use crate::hash::{Sha512, sha512};
 use crate::keys::x25519;
 use crate::rng::Csprng;
 
+/// Errors that can occur while importing Ed25519 keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ed25519Error {
+    /// The bytes do not encode a point on the curve.
+    InvalidPublicKey,
+    /// The expanded private key has an out-of-range scalar.
+    InvalidPrivateKey,
+    /// The public key does not belong to the seed.
+    KeyMismatch,
+    /// The Ed25519ctx / Ed25519ph context is empty where a context is
+    /// required, or longer than 255 bytes.
+    InvalidContext,
+}
+
 /// An Ed25519 public key.
 ///
 /// This type wraps the canonical 32-byte compressed encoding of a curve
@@ -27,6 +41,21 @@ use crate::rng::Csprng;
 pub struct PublicKey([u8; 32]);
 
 impl PublicKey {
+    /// Constructs a public key from its 32-byte compressed encoding.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(public)` if the bytes decode to a point on the curve
+    /// - `Err(InvalidPublicKey)` otherwise
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Ed25519Error> {
+        let (_, invalid) = GeP3::decompress(bytes);
+        if invalid != 0 {
+            return Err(Ed25519Error::InvalidPublicKey);
+        }
+
+        Ok(Self(*bytes))
+    }
+
     /// Returns the canonical byte encoding of this public key.
     ///
     /// The returned value is the compressed Edwards point representation
@@ -53,6 +82,51 @@ pub struct PrivateKey {
 }
 
 impl PrivateKey {
+    /// Constructs a private key from its 64-byte expanded form.
+    ///
+    /// This is the inverse of [`to_bytes`](Self::to_bytes): the secret
+    /// scalar followed by the nonce prefix. Prefer keeping the seed (see
+    /// [`SigningKey`]) when possible; this form exists for keys that have
+    /// no seed, such as those updated with [`add_scalar`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(private)` on success
+    /// - `Err(InvalidPrivateKey)` if the scalar has its top bit set
+    pub fn from_expanded(bytes: &[u8; 64]) -> Result<Self, Ed25519Error> {
+        if bytes[31] & 0x80 != 0 {
+            return Err(Ed25519Error::InvalidPrivateKey);
+        }
+
+        Ok(Self {
+            scalar: Scalar::from_bytes(&bytes[..32]),
+            prefix: bytes[32..].try_into().unwrap(),
+        })
+    }
+
+    /// Expands a 32-byte RFC 8032 seed.
+    ///
+    /// The seed is hashed with SHA-512; the first half is clamped into
+    /// the secret scalar and the second half becomes the nonce prefix.
+    fn from_seed(seed: &[u8; 32]) -> Self {
+        let mut digest = sha512(seed);
+
+        let mut a_bytes: [u8; 32] = digest[..32].try_into().unwrap();
+        a_bytes[0] &= 248;
+        a_bytes[31] &= 63;
+        a_bytes[31] |= 64;
+
+        let private = Self {
+            scalar: Scalar(a_bytes),
+            prefix: digest[32..].try_into().unwrap(),
+        };
+
+        digest.fill(0);
+        a_bytes.fill(0);
+
+        private
+    }
+
     /// Returns the secret scalar component of the private key.
     ///
     /// This value is used internally for scalar multiplication on the curve.
@@ -76,8 +150,9 @@ impl PrivateKey {
     /// The first 32 bytes correspond to the secret scalar encoding,
     /// and the remaining 32 bytes contain the nonce prefix.
     ///
-    /// This format is primarily intended for internal use and debugging,
-    /// not for key serialization or storage.
+    /// This is the format accepted by [`from_expanded`](Self::from_expanded).
+    /// It contains the secret key and must be stored as securely as the
+    /// seed; prefer storing the seed when there is one.
     pub fn to_bytes(&self) -> [u8; 64] {
         let mut out = [0u8; 64];
 
@@ -119,46 +194,125 @@ impl Signature {
     }
 }
 
-/// Generates a fresh Ed25519 keypair.
+/// An Ed25519 key pair derived from a 32-byte seed.
+///
+/// The seed is the private key format of RFC 8032 and of most other
+/// implementations; the expanded private key and the public key are both
+/// derived from it. Keep the seed to persist an identity, and restore it
+/// with [`from_seed`](Self::from_seed).
 ///
-/// This function creates a new public/private keypair using a
-/// cryptographically secure random seed and the Ed25519 key
-/// derivation procedure.
+/// The seed and the expanded key are wiped on drop.
+pub struct SigningKey {
+    seed: [u8; 32],
+    private: PrivateKey,
+    public: PublicKey,
+}
+
+impl SigningKey {
+    /// Generates a key pair from a fresh random seed.
+    pub fn generate() -> Self {
+        let mut seed = [0u8; 32];
+        Csprng::new().fill_bytes(&mut seed);
+
+        let key = Self::from_seed(&seed);
+        seed.fill(0);
+
+        key
+    }
+
+    /// Derives the key pair for a 32-byte seed.
+    ///
+    /// The process is:
+    /// - Hash the seed with SHA-512
+    /// - Derive the secret scalar from the first half of the hash,
+    ///   applying Ed25519 clamping rules
+    /// - Use the second half of the hash as the private nonce prefix
+    /// - Compute the public key as a scalar multiplication of the
+    ///   curve base point
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        let private = PrivateKey::from_seed(seed);
+        let public = PublicKey(GeP3::from_scalar_mul(private.scalar).to_bytes());
+
+        Self {
+            seed: *seed,
+            private,
+            public,
+        }
+    }
+
+    /// Restores a key pair from the 64-byte `seed || public key` format
+    /// used by libsodium and many other libraries.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(key)` on success
+    /// - `Err(KeyMismatch)` if the public key does not match the seed
+    pub fn from_keypair_bytes(bytes: &[u8; 64]) -> Result<Self, Ed25519Error> {
+        let key = Self::from_seed(bytes[..32].try_into().unwrap());
+
+        if !key.public.0.ct_eq(bytes[32..].try_into().unwrap()) {
+            return Err(Ed25519Error::KeyMismatch);
+        }
+
+        Ok(key)
+    }
+
+    /// Returns the 32-byte seed.
+    pub fn to_seed(&self) -> [u8; 32] {
+        self.seed
+    }
+
+    /// Returns the key pair as `seed || public key`.
+    pub fn to_keypair_bytes(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+
+        out[..32].copy_from_slice(&self.seed);
+        out[32..].copy_from_slice(&self.public.0);
+
+        out
+    }
+
+    /// Returns the public key.
+    pub fn public_key(&self) -> PublicKey {
+        self.public
+    }
+
+    /// Returns the expanded private key.
+    pub fn private_key(&self) -> PrivateKey {
+        self.private
+    }
+
+    /// Signs a message with this key pair.
+    ///
+    /// This is equivalent to [`sign`] with the derived keys.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        sign(message, self.public, self.private)
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.seed.fill(0);
+        self.private.scalar.0.fill(0);
+        self.private.prefix.fill(0);
+    }
+}
+
+/// Generates a fresh Ed25519 keypair.
 ///
-/// The process is:
-/// - Generate a 32-byte random seed using a CSPRNG
-/// - Hash the seed with SHA-512
-/// - Derive the secret scalar from the first half of the hash,
-///   applying Ed25519 clamping rules
-/// - Use the second half of the hash as the private nonce prefix
-/// - Compute the public key as a scalar multiplication of the
-///   curve base point
+/// This function creates a new public/private keypair from a
+/// cryptographically secure random seed, as [`SigningKey::generate`]
+/// does, and returns only the derived keys.
 ///
 /// The returned keys are:
 /// - [`PublicKey`]: the encoded Edwards curve point
 /// - [`PrivateKey`]: containing the secret scalar and nonce prefix
 ///
-/// This implementation follows the Ed25519 specification
-/// (RFC 8032) and mirrors the structure of the reference C
-/// implementations, while remaining explicit and auditable.
+/// Use [`SigningKey`] instead when the seed must be stored.
 pub fn generate_keypair() -> (PublicKey, PrivateKey) {
-    let mut seed = [0u8; 32];
-    Csprng::new().fill_bytes(&mut seed);
-
-    let digest = sha512(&seed);
+    let key = SigningKey::generate();
 
-    let mut a_bytes: [u8; 32] = digest[..32].try_into().unwrap();
-    a_bytes[0] &= 248;
-    a_bytes[31] &= 63;
-    a_bytes[31] |= 64;
-    let scalar = Scalar::from_bytes(&a_bytes);
-
-    let prefix: [u8; 32] = digest[32..].try_into().unwrap();
-
-    let public = PublicKey(GeP3::from_scalar_mul(scalar).to_bytes());
-    let private = PrivateKey { scalar, prefix };
-
-    (public, private)
+    (key.public_key(), key.private_key())
 }
 
 /// Computes an Ed25519 signature over a message.
@@ -185,22 +339,31 @@ pub fn generate_keypair() -> (PublicKey, PrivateKey) {
 /// Ed25519 implementations and avoids side-channel leakage by
 /// relying on constant-time primitives.
 pub fn sign(message: &[u8], public: PublicKey, private: PrivateKey) -> Signature {
+    sign_with_domain(&[], message, public, private)
+}
+
+/// Signs `message` with a domain separator prepended to both hashes.
+///
+/// Plain Ed25519 uses an empty `dom`; Ed25519ctx and Ed25519ph use the
+/// `dom2` prefix of RFC 8032.
+pub(crate) fn sign_with_domain(
+    dom: &[u8],
+    message: &[u8],
+    public: PublicKey,
+    private: PrivateKey,
+) -> Signature {
     let a = private.scalar();
 
-    let mut r_input = Vec::with_capacity(32 + message.len());
-    r_input.extend_from_slice(&private.prefix());
-    r_input.extend_from_slice(message);
+    let mut hasher = Sha512::new();
+    hasher.update(dom);
+    hasher.update(&private.prefix());
+    hasher.update(message);
 
-    let r = Scalar::reduce(sha512(&r_input));
+    let r = Scalar::reduce(hasher.finalize());
 
     let r_bytes = GeP3::from_scalar_mul(r).to_bytes();
 
-    let mut k_input = Vec::with_capacity(64 + message.len());
-    k_input.extend_from_slice(&r_bytes);
-    k_input.extend_from_slice(&public.to_bytes());
-    k_input.extend_from_slice(message);
-
-    let k = Scalar::reduce(sha512(&k_input));
+    let k = challenge(dom, &r_bytes, &public.to_bytes(), message);
 
     let s = Scalar::from_mul_sum(k, a, r).0;
 
@@ -218,7 +381,7 @@ pub fn sign(message: &[u8], public: PublicKey, private: PrivateKey) -> Signature
 ///
 /// Verification proceeds as follows:
 ///
-/// - Ensure the scalar `S` is canonically encoded
+/// - Reject `S` if any of its top three bits is set
 /// - Decompress the public key `A`
 /// - Compute `h = H(R || A || M) mod ℓ`
 /// - Check that:
@@ -233,6 +396,10 @@ pub fn sign(message: &[u8], public: PublicKey, private: PrivateKey) -> Signature
 /// This implementation mirrors the reference Ed25519 verification logic
 /// and avoids side-channel leakage by relying exclusively on
 /// constant-time group and scalar operations.
+///
+/// It does not reject `S ≥ ℓ`, non-canonical encodings or small-order
+/// keys. Where all parties must agree on validity, use
+/// [`verify_strict`] or [`verify_zip215`] instead.
 pub fn verify(signature: Signature, message: &[u8], public: PublicKey) -> bool {
     if (signature.0[63] & 0b1110_0000) != 0 {
         return false;
@@ -257,6 +424,223 @@ pub fn verify(signature: Signature, message: &[u8], public: PublicKey) -> bool {
     r_check.ct_eq((&signature.0[..32]).try_into().unwrap())
 }
 
+/// Verifies an Ed25519 signature with strict RFC 8032 checks.
+///
+/// On top of the verification equation, this mode rejects:
+///
+/// - a non-canonical scalar `S ≥ ℓ`
+/// - non-canonical encodings of `A` or `R` (`y ≥ p`, or `x = 0` with the
+///   sign bit set)
+/// - small-order `A` or `R`, for which a signature can be valid for many
+///   messages or keys
+///
+/// The check is cofactorless:
+///
+/// ```text
+/// S · B == R + h · A
+/// ```
+///
+/// This matches `verify_strict` in ed25519-dalek, and accepts a subset of
+/// what [`verify_zip215`] accepts.
+pub fn verify_strict(signature: Signature, message: &[u8], public: PublicKey) -> bool {
+    verify_strict_with_domain(&[], signature, message, public)
+}
+
+/// Strict verification with a domain separator, see [`verify_strict`].
+pub(crate) fn verify_strict_with_domain(
+    dom: &[u8],
+    signature: Signature,
+    message: &[u8],
+    public: PublicKey,
+) -> bool {
+    let r_bytes: [u8; 32] = signature.0[..32].try_into().unwrap();
+    let s_bytes: [u8; 32] = signature.0[32..].try_into().unwrap();
+
+    if !Scalar::is_canonical(&s_bytes) {
+        return false;
+    }
+
+    let Some(minus_a) = decompress_strict(&public.0) else {
+        return false;
+    };
+
+    if decompress_strict(&r_bytes).is_none() {
+        return false;
+    }
+
+    let h = challenge(dom, &r_bytes, &public.0, message);
+    let r_check = minus_a.double_scalar_mul(h, Scalar(s_bytes)).to_bytes();
+
+    r_check.ct_eq(&r_bytes)
+}
+
+/// Verifies an Ed25519 signature with the ZIP-215 rules.
+///
+/// ZIP-215 fixes the validity of every signature so that independent
+/// implementations always agree, which consensus systems require:
+///
+/// - `S` must be canonical (`S < ℓ`)
+/// - `A` and `R` may use non-canonical encodings, and may have small
+///   order; the hash uses their encodings as given
+/// - the check is cofactored:
+///
+/// ```text
+/// [8] (S · B − R − h · A) == 0
+/// ```
+///
+/// Every signature accepted by [`verify_strict`] is accepted here too.
+pub fn verify_zip215(signature: Signature, message: &[u8], public: PublicKey) -> bool {
+    let r_bytes: [u8; 32] = signature.0[..32].try_into().unwrap();
+    let s_bytes: [u8; 32] = signature.0[32..].try_into().unwrap();
+
+    if !Scalar::is_canonical(&s_bytes) {
+        return false;
+    }
+
+    let (minus_a, invalid) = GeP3::decompress(&public.0);
+    if invalid != 0 {
+        return false;
+    }
+
+    let (minus_r, invalid) = GeP3::decompress(&r_bytes);
+    if invalid != 0 {
+        return false;
+    }
+
+    let h = challenge(&[], &r_bytes, &public.0, message);
+
+    // S·B − h·A, then subtract R.
+    let sb_minus_ha = GeP3::from_gep2(&minus_a.double_scalar_mul(h, Scalar(s_bytes)));
+    let difference = GeP3::from_gep1(&GeP1::from_sum(&sb_minus_ha, &GeCached::from_p3(&minus_r)));
+
+    difference.is_small_order()
+}
+
+/// Verifies a batch of Ed25519 signatures at once.
+///
+/// Each item is `(signature, message, public key)`. All signatures are
+/// checked together with a random linear combination:
+///
+/// ```text
+/// [8] (Σ zᵢ·Sᵢ · B − Σ zᵢ·Rᵢ − Σ (zᵢ·hᵢ)·Aᵢ) == 0
+/// ```
+///
+/// where the `zᵢ` are fresh random 128-bit scalars. The sum is evaluated
+/// with a single multi-scalar multiplication, which is faster than
+/// verifying each signature on its own.
+///
+/// If the batch equation fails, every signature is verified individually
+/// to find the invalid ones.
+///
+/// # Returns
+///
+/// - `Ok(())` if every signature is valid
+/// - `Err(indices)` with the positions of the invalid signatures
+///
+/// # Notes
+///
+/// - Validity follows the [`verify_zip215`] rules, so that the batch and
+///   individual results always agree. A signature rejected by
+///   [`verify_strict`] may still be accepted here.
+/// - Only public data is processed; the multiplication runs in variable
+///   time.
+pub fn verify_batch(items: &[(Signature, &[u8], PublicKey)]) -> Result<(), Vec<usize>> {
+    if batch_equation_holds(items) {
+        return Ok(());
+    }
+
+    let failed: Vec<usize> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, (signature, message, public))| !verify_zip215(*signature, message, *public))
+        .map(|(index, _)| index)
+        .collect();
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(failed)
+    }
+}
+
+/// Evaluates the randomized batch equation of [`verify_batch`].
+fn batch_equation_holds(items: &[(Signature, &[u8], PublicKey)]) -> bool {
+    let mut weights = vec![0u8; 16 * items.len()];
+    Csprng::new().fill_bytes(&mut weights);
+
+    let mut points = Vec::with_capacity(2 * items.len());
+    let mut scalars = Vec::with_capacity(2 * items.len());
+    let mut b = Scalar([0u8; 32]);
+    let zero = Scalar([0u8; 32]);
+
+    for ((signature, message, public), weight) in items.iter().zip(weights.chunks_exact(16)) {
+        let r_bytes: [u8; 32] = signature.0[..32].try_into().unwrap();
+        let s_bytes: [u8; 32] = signature.0[32..].try_into().unwrap();
+
+        if !Scalar::is_canonical(&s_bytes) {
+            return false;
+        }
+
+        let (minus_a, invalid_a) = GeP3::decompress(&public.0);
+        let (minus_r, invalid_r) = GeP3::decompress(&r_bytes);
+        if invalid_a != 0 || invalid_r != 0 {
+            return false;
+        }
+
+        let h = challenge(&[], &r_bytes, &public.0, message);
+
+        let mut z = [0u8; 32];
+        z[..16].copy_from_slice(weight);
+        let z = Scalar(z);
+
+        b = Scalar::from_mul_sum(z, Scalar(s_bytes), b);
+
+        // Decompression yields −R and −A, as the equation needs.
+        points.push(minus_r);
+        scalars.push(z);
+        points.push(minus_a);
+        scalars.push(Scalar::from_mul_sum(z, h, zero));
+    }
+
+    GeP3::vartime_multiscalar_mul(&points, &scalars, b).is_small_order()
+}
+
+/// Computes the challenge `h = H(dom || R || A || M) mod ℓ`.
+fn challenge(dom: &[u8], r: &[u8; 32], a: &[u8; 32], message: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(dom);
+    hasher.update(r);
+    hasher.update(a);
+    hasher.update(message);
+
+    Scalar::reduce(hasher.finalize())
+}
+
+/// Decompresses a point, requiring a canonical encoding and a point that
+/// is not of small order.
+///
+/// Like [`GeP3::decompress`], this returns the **negated** point.
+fn decompress_strict(bytes: &[u8; 32]) -> Option<GeP3> {
+    let (minus_p, invalid) = GeP3::decompress(bytes);
+    if invalid != 0 || minus_p.is_small_order() {
+        return None;
+    }
+
+    // Re-encoding the point must give back the same bytes.
+    let p = GeP3 {
+        x: -minus_p.x,
+        y: minus_p.y,
+        z: minus_p.z,
+        t: -minus_p.t,
+    };
+
+    if p.to_bytes() != *bytes {
+        return None;
+    }
+
+    Some(minus_p)
+}
+
 /// Adds a scalar to an Ed25519 keypair and/or public key.
 ///
 /// This function updates keys in-place by adding a scalar `n` modulo the
@@ -343,7 +727,25 @@ pub fn add_scalar(
 ///   Montgomery coordinate.
 ///
 /// All algorithmic details and security properties are documented
-/// in the `x25519` module.
+/// in the `x25519` module. The result is all zeros for small-order peer
+/// keys; use [`checked_exchange`] to reject them.
 pub fn exchange(private: &PrivateKey, public: &PublicKey) -> [u8; 32] {
     x25519::exchange(&private.scalar().to_bytes(), &public.to_bytes())
 }
+
+/// Computes a Diffie–Hellman shared secret using X25519, rejecting
+/// small-order peer keys.
+///
+/// This is [`exchange`] with the all-zero check of
+/// `x25519::checked_exchange`.
+///
+/// # Returns
+///
+/// - `Ok(shared)` on success
+/// - `Err(NonContributory)` if the shared secret is all zeros
+pub fn checked_exchange(
+    private: &PrivateKey,
+    public: &PublicKey,
+) -> Result<x25519::SharedSecret, x25519::X25519Error> {
+    x25519::checked_exchange(&private.scalar().to_bytes(), &public.to_bytes())
+}
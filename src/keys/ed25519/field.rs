@@ -46,6 +46,7 @@
 //! The implementation is compatible with the Ed25519 reference behavior
 //! and suitable for cryptographic use.
 
+use super::table::SQRTM1;
 use std::array;
 use std::ops::{Add, Mul, Neg, Sub};
 
@@ -1045,6 +1046,62 @@ impl FieldElement {
 
         t1 * t0
     }
+
+    /// Negates `self` in constant time if `condition == 1`.
+    pub(crate) fn conditional_negate(&mut self, condition: u32) {
+        let negated = -*self;
+        self.conditional_move(&negated, condition);
+    }
+
+    /// Returns the non-negative one of `self` and `-self`, in constant
+    /// time.
+    pub(crate) fn abs(self) -> Self {
+        let mut r = self;
+        r.conditional_negate(self.is_negative() as u32);
+
+        r
+    }
+
+    /// Returns `1` if `self` and `rhs` are equal modulo `p`, `0` otherwise.
+    ///
+    /// Both values are fully reduced before the comparison, which does
+    /// not branch on their contents.
+    #[inline(always)]
+    pub(crate) fn ct_eq(&self, rhs: &Self) -> u32 {
+        ((*self - *rhs).is_non_zero() ^ 1) as u32
+    }
+
+    /// Computes the square root of the ratio `u / v` (RFC 9496 §4.2).
+    ///
+    /// ## Returns
+    ///
+    /// A pair `(was_square, r)` where:
+    /// - if `u / v` is a square, `was_square == 1` and `r = sqrt(u / v)`
+    /// - otherwise, `was_square == 0` and `r = sqrt(i · u / v)`, with `i`
+    ///   the square root of `-1`
+    /// - if `u == 0`, `was_square == 1` and `r = 0`; if `v == 0` and
+    ///   `u != 0`, `was_square == 0` and `r = 0`
+    ///
+    /// `r` is always the non-negative root.
+    ///
+    /// ## Constant-time behavior
+    ///
+    /// The computation and all selections are branch-free.
+    pub(crate) fn sqrt_ratio_m1(u: &Self, v: &Self) -> (u32, Self) {
+        let v3 = v.square() * *v;
+        let v7 = v3.square() * *v;
+        let mut r = (*u * v3) * (*u * v7).pow22523();
+        let check = *v * r.square();
+
+        let correct_sign = check.ct_eq(u);
+        let flipped_sign = check.ct_eq(&-*u);
+        let flipped_sign_i = check.ct_eq(&(-*u * SQRTM1));
+
+        let r_prime = r * SQRTM1;
+        r.conditional_move(&r_prime, flipped_sign | flipped_sign_i);
+
+        (correct_sign | flipped_sign, r.abs())
+    }
 }
 
 /// Field element addition.
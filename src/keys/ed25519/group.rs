@@ -48,6 +48,7 @@
 //! - Point doubling
 //! - Fixed-base scalar multiplication
 //! - Double-scalar multiplication
+//! - Variable-time multi-scalar multiplication (batch verification)
 //! - Point compression and decompression
 //!
 //! ## Algorithms
@@ -84,9 +85,10 @@
 
 use super::ct::ConstantTimeEq;
 use super::field::FieldElement;
-use super::scalar::Scalar;
+use super::scalar::{L, Scalar};
 use super::table::BASE;
 use super::table::{BI, D, D2, SQRTM1};
+use std::ops::{Add, Neg, Sub};
 
 /// Group element in extended projective coordinates (P1 representation).
 ///
@@ -403,6 +405,7 @@ impl GeP2 {
 ///
 /// This type is the primary working representation for group
 /// operations in Ed25519.
+#[derive(Clone, Copy)]
 pub(crate) struct GeP3 {
     pub(crate) x: FieldElement,
     pub(crate) y: FieldElement,
@@ -437,29 +440,11 @@ impl GeP3 {
     /// The computation is performed in constant time with respect
     /// to the scalar values.
     pub(crate) fn double_scalar_mul(&self, a: Scalar, b: Scalar) -> GeP2 {
-        let mut ai = [
-            GeCached::ZERO,
-            GeCached::ZERO,
-            GeCached::ZERO,
-            GeCached::ZERO,
-            GeCached::ZERO,
-            GeCached::ZERO,
-            GeCached::ZERO,
-            GeCached::ZERO,
-        ];
+        let ai = self.odd_multiples();
 
         let aslide = a.slide();
         let bslide = b.slide();
 
-        ai[0] = GeCached::from_p3(self);
-
-        let a2 = GeP3::from_gep1(&self.double());
-
-        for j in 1..8 {
-            let t = GeP1::from_sum(&a2, &ai[j - 1]);
-            ai[j] = GeCached::from_p3(&GeP3::from_gep1(&t));
-        }
-
         let mut r = GeP2::ONE;
         let mut started = false;
 
@@ -491,6 +476,75 @@ impl GeP3 {
         r
     }
 
+    /// Computes a multi-scalar multiplication:
+    ///
+    /// ```text
+    /// r = Σ scalars[i] · points[i] + b · B
+    /// ```
+    ///
+    /// using Straus's method: every scalar is recoded with `slide`, and all
+    /// terms share a single chain of 256 doublings, so the cost grows with
+    /// one table and a few additions per extra point.
+    ///
+    /// This runs in **variable time** and must only be used with public
+    /// scalars and points, as in batch signature verification.
+    pub(crate) fn vartime_multiscalar_mul(points: &[GeP3], scalars: &[Scalar], b: Scalar) -> Self {
+        let tables: Vec<[GeCached; 8]> = points.iter().map(GeP3::odd_multiples).collect();
+        let slides: Vec<_> = scalars.iter().map(Scalar::slide).collect();
+        let bslide = b.slide();
+
+        let top = (0..256)
+            .rev()
+            .find(|&i| bslide[i] != 0 || slides.iter().any(|slide| slide[i] != 0));
+
+        let Some(top) = top else {
+            return GeP3::ONE;
+        };
+
+        let mut r = GeP2::ONE;
+
+        for i in (0..=top).rev() {
+            let mut t = r.double();
+
+            for (slide, table) in slides.iter().zip(&tables) {
+                let digit = slide[i];
+
+                if digit > 0 {
+                    t = GeP1::from_sum(&GeP3::from_gep1(&t), &table[(digit / 2) as usize]);
+                } else if digit < 0 {
+                    t = GeP1::from_difference(&GeP3::from_gep1(&t), &table[(-digit / 2) as usize]);
+                }
+            }
+
+            let digit = bslide[i];
+            if digit > 0 {
+                t = GeP1::from_mixed_sum(&GeP3::from_gep1(&t), &BI[(digit / 2) as usize]);
+            } else if digit < 0 {
+                t = GeP1::from_mixed_difference(&GeP3::from_gep1(&t), &BI[(-digit / 2) as usize]);
+            }
+
+            r = GeP2::from_gep1(&t);
+        }
+
+        GeP3::from_gep2(&r)
+    }
+
+    /// Returns the cached odd multiples `[1, 3, 5, …, 15] · self`, as
+    /// indexed by sliding-window digits.
+    fn odd_multiples(&self) -> [GeCached; 8] {
+        let mut ai = [GeCached::ZERO; 8];
+        ai[0] = GeCached::from_p3(self);
+
+        let a2 = GeP3::from_gep1(&self.double());
+
+        for j in 1..8 {
+            let t = GeP1::from_sum(&a2, &ai[j - 1]);
+            ai[j] = GeCached::from_p3(&GeP3::from_gep1(&t));
+        }
+
+        ai
+    }
+
     /// Doubles this point on the curve.
     ///
     /// This is implemented by converting to projective coordinates
@@ -513,11 +567,57 @@ impl GeP3 {
         Self { x, y, z, t }
     }
 
+    /// Converts a projective `(P2)` point into extended `(P3)` coordinates.
+    ///
+    /// ```text
+    /// (X : Y : Z) -> (X·Z : Y·Z : Z² : X·Y)
+    /// ```
+    ///
+    /// which satisfies `T·Z = X·Y` without any field inversion.
+    pub(crate) fn from_gep2(g: &GeP2) -> Self {
+        let x = g.x * g.z;
+        let y = g.y * g.z;
+        let z = g.z.square();
+        let t = g.x * g.y;
+
+        Self { x, y, z, t }
+    }
+
+    /// Multiplies the point by the cofactor 8, with three doublings.
+    pub(crate) fn mul_by_cofactor(&self) -> Self {
+        let p2 = GeP3::from_gep1(&self.double());
+        let p4 = GeP3::from_gep1(&p2.double());
+
+        GeP3::from_gep1(&p4.double())
+    }
+
+    /// Returns `true` if this is the neutral element `(0, 1)`.
+    ///
+    /// Intended for public points only: the result is returned as a
+    /// plain boolean.
+    pub(crate) fn is_identity(&self) -> bool {
+        (self.x.is_non_zero() | (self.y - self.z).is_non_zero()) == 0
+    }
+
+    /// Returns `true` if the point lies in the torsion subgroup of order 8.
+    pub(crate) fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor().is_identity()
+    }
+
+    /// Returns `true` if the point lies in the prime-order subgroup, i.e.
+    /// `ℓ · P` is the identity.
+    ///
+    /// Runs in variable time; intended for public points only.
+    pub(crate) fn is_torsion_free(&self) -> bool {
+        let lp = self.double_scalar_mul(Scalar(L), Scalar([0u8; 32]));
+        GeP3::from_gep2(&lp).is_identity()
+    }
+
     /// Encodes the point into its compressed 32-byte representation.
     ///
     /// The `y` coordinate is serialized in little-endian form,
     /// and the least significant bit of `x` is stored as the sign bit.
-    pub(crate) fn to_bytes(&self) -> [u8; 32] {
+    pub(crate) fn to_bytes(self) -> [u8; 32] {
         let recip = self.z.invert();
         let x = self.x * recip;
         let y = self.y * recip;
@@ -663,20 +763,7 @@ impl GeP3 {
     ///
     /// A point in extended coordinates (`GeP3`) equal to `a * B`.
     pub(crate) fn from_scalar_mul(a: Scalar) -> Self {
-        let mut e = [0i8; 64];
-        for (i, &byte) in a.0.iter().enumerate() {
-            e[2 * i] = (byte & 0x0f) as i8;
-            e[2 * i + 1] = (byte >> 4) as i8;
-        }
-
-        let mut carry = 0i8;
-        for v in e.iter_mut().take(63) {
-            *v += carry;
-            carry = (*v + 8) >> 4;
-            *v -= carry << 4;
-        }
-
-        e[63] += carry;
+        let e = radix16(&a);
 
         let mut h = Self::ONE;
         for i in (1..64).step_by(2) {
@@ -695,6 +782,89 @@ impl GeP3 {
 
         h
     }
+
+    /// Computes `a · self` in constant time.
+    ///
+    /// The scalar is recoded into 64 signed radix-16 digits in `[-8, 8]`,
+    /// and the point is processed with a fixed window of 4 bits:
+    ///
+    /// ```text
+    /// h = Σ e[i] · 16^i · P
+    /// ```
+    ///
+    /// Each digit selects one of `[1, 8] · P` with a constant-time table
+    /// scan and a conditional negation.
+    ///
+    /// ## Notes
+    ///
+    /// - The scalar must be below 2²⁵⁵ (`a[31] <= 127`), as for
+    ///   [`from_scalar_mul`](Self::from_scalar_mul). Reduced scalars
+    ///   always are.
+    pub(crate) fn scalar_mul(&self, a: Scalar) -> Self {
+        let e = radix16(&a);
+        let table = self.multiples();
+
+        let mut h = Self::ONE;
+        for &digit in e.iter().rev() {
+            let mut r = GeP2::from_gep3(&h);
+            for _ in 0..3 {
+                r = GeP2::from_gep1(&r.double());
+            }
+            h = GeP3::from_gep1(&r.double());
+
+            let t = GeCached::select(&table, digit);
+            h = GeP3::from_gep1(&GeP1::from_sum(&h, &t));
+        }
+
+        h
+    }
+
+    /// Returns the cached multiples `[1, 2, …, 8] · self`, as indexed by
+    /// radix-16 digits.
+    fn multiples(&self) -> [GeCached; 8] {
+        let mut table = [GeCached::ZERO; 8];
+        table[0] = GeCached::from_p3(self);
+
+        let mut acc = *self;
+        for j in 1..8 {
+            acc = GeP3::from_gep1(&GeP1::from_sum(&acc, &table[0]));
+            table[j] = GeCached::from_p3(&acc);
+        }
+
+        table
+    }
+}
+
+/// Point addition in extended coordinates.
+impl Add for GeP3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        GeP3::from_gep1(&GeP1::from_sum(&self, &GeCached::from_p3(&rhs)))
+    }
+}
+
+/// Point subtraction in extended coordinates.
+impl Sub for GeP3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        GeP3::from_gep1(&GeP1::from_difference(&self, &GeCached::from_p3(&rhs)))
+    }
+}
+
+/// Point negation: `-(x, y) = (-x, y)`.
+impl Neg for GeP3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: self.y,
+            z: self.z,
+            t: -self.t,
+        }
+    }
 }
 
 /// Cached representation of an Edwards curve point.
@@ -717,6 +887,7 @@ impl GeP3 {
 ///
 /// This layout matches the one used in the Ed25519 reference
 /// implementations (ref10 / orlp).
+#[derive(Clone, Copy)]
 pub struct GeCached {
     /// Precomputed value `y + x`.
     pub(crate) yplusx: FieldElement,
@@ -744,6 +915,14 @@ impl GeCached {
         t2d: FieldElement::ZERO,
     };
 
+    /// The identity element `(0, 1)` in cached form.
+    pub(crate) const ONE: Self = Self {
+        yplusx: FieldElement::ONE,
+        yminusx: FieldElement::ONE,
+        z: FieldElement::ONE,
+        t2d: FieldElement::ZERO,
+    };
+
     /// Converts a point from extended coordinates (`GeP3`) into cached form.
     ///
     /// The resulting `GeCached` value can be used efficiently in
@@ -778,6 +957,43 @@ impl GeCached {
             t2d,
         }
     }
+
+    /// Conditionally replaces `self` with `rhs` in constant time.
+    ///
+    /// If `b == 1`, `self` is replaced by `rhs`.
+    /// If `b == 0`, `self` is left unchanged.
+    pub(crate) fn conditional_move(&mut self, rhs: &Self, b: u8) {
+        self.yplusx.conditional_move(&rhs.yplusx, b as u32);
+        self.yminusx.conditional_move(&rhs.yminusx, b as u32);
+        self.z.conditional_move(&rhs.z, b as u32);
+        self.t2d.conditional_move(&rhs.t2d, b as u32);
+    }
+
+    /// Selects `b · P` from a table of multiples `[1, 8] · P` in constant
+    /// time.
+    ///
+    /// This mirrors [`GePrecomp::select`] for a variable base point:
+    /// `b` is a signed digit in `[-8, 8]`, and `0` selects the identity.
+    pub(crate) fn select(table: &[GeCached; 8], b: i8) -> Self {
+        let mut t = GeCached::ONE;
+
+        let bnegative = b.ct_neg();
+        let babs = (b as i16 - (((-(bnegative as i16)) & (b as i16)) << 1)) as i8;
+
+        for (i, elem) in table.iter().enumerate() {
+            t.conditional_move(elem, babs.ct_eq(&((i + 1) as i8)) as u8);
+        }
+
+        let minust = GeCached {
+            yplusx: t.yminusx,
+            yminusx: t.yplusx,
+            z: t.z,
+            t2d: -t.t2d,
+        };
+        t.conditional_move(&minust, bnegative);
+
+        t
+    }
 }
 
 /// Precomputed representation of an Edwards curve point.
@@ -902,3 +1118,24 @@ impl GePrecomp {
         t
     }
 }
+
+/// Recodes a scalar below 2²⁵⁵ into 64 signed radix-16 digits in
+/// `[-8, 8]`, least significant first.
+fn radix16(a: &Scalar) -> [i8; 64] {
+    let mut e = [0i8; 64];
+    for (i, &byte) in a.0.iter().enumerate() {
+        e[2 * i] = (byte & 0x0f) as i8;
+        e[2 * i + 1] = (byte >> 4) as i8;
+    }
+
+    let mut carry = 0i8;
+    for v in e.iter_mut().take(63) {
+        *v += carry;
+        carry = (*v + 8) >> 4;
+        *v -= carry << 4;
+    }
+
+    e[63] += carry;
+
+    e
+}
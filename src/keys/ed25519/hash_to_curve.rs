@@ -0,0 +1,281 @@
+//! Hashing to edwards25519 (RFC 9380).
+//!
+//! This module implements the two edwards25519 suites of RFC 9380:
+//!
+//! ```text
+//! edwards25519_XMD:SHA-512_ELL2_RO_   hash_to_curve    (random oracle)
+//! edwards25519_XMD:SHA-512_ELL2_NU_   encode_to_curve  (nonuniform)
+//! ```
+//!
+//! Both derive a point with no known discrete logarithm from arbitrary
+//! bytes, as needed by VRFs, PAKEs and blind tokens:
+//!
+//! 1. `expand_message_xmd` with SHA-512 stretches the message into
+//!    uniform bytes, bound to a domain separation tag (DST)
+//! 2. each 48-byte chunk is reduced to a field element
+//! 3. Elligator 2 maps the field element to curve25519, and the rational
+//!    map carries the result to edwards25519
+//! 4. the cofactor is cleared
+//!
+//! `hash_to_curve` maps two field elements and adds the results, so its
+//! output is indistinguishable from a random point. `encode_to_curve`
+//! maps a single element and is cheaper, but its output is not uniform.
+//!
+//! ## Notes
+//!
+//! - Every protocol must use its own non-empty DST, e.g.
+//!   `"MYAPP-V01-CS01-with-" || SUITE_RO`.
+//! - DSTs longer than 255 bytes are hashed first, as required by the
+//!   RFC.
+//! - The mapping is constant-time; the message and DST are treated as
+//!   public lengths.
+
+use super::field::FieldElement;
+use super::group::GeP3;
+use super::point::EdwardsPoint;
+use crate::hash::Sha512;
+
+/// Suite identifier of [`hash_to_curve`].
+pub const SUITE_RO: &str = "edwards25519_XMD:SHA-512_ELL2_RO_";
+
+/// Suite identifier of [`encode_to_curve`].
+pub const SUITE_NU: &str = "edwards25519_XMD:SHA-512_ELL2_NU_";
+
+/// Prefix used to hash DSTs longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Input block size of SHA-512, in bytes.
+const BLOCK_LEN: usize = 128;
+
+/// Output size of SHA-512, in bytes.
+const HASH_LEN: usize = 64;
+
+/// Bytes per field element in `hash_to_field` (`L = ceil((255 + 128) / 8)`).
+const FIELD_LEN: usize = 48;
+
+/// Montgomery coefficient `A = 486662` of curve25519.
+pub(crate) const MONTGOMERY_A: FieldElement = FieldElement([486662, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+/// `sqrt(−486664)`, the non-negative root, for the rational map to
+/// edwards25519.
+const SQRT_MINUS_A_MINUS_2: FieldElement = FieldElement([
+    54885894, 25242303, 55597453, 9067496, 51808079, 33312638, 25456129, 14121551, 54921728,
+    3972023,
+]);
+
+/// `2¹⁹²`, used to reduce 48-byte integers modulo `p`.
+const TWO_192: FieldElement = FieldElement([0, 0, 0, 0, 0, 0, 0, 8192, 0, 0]);
+
+/// Errors that can occur while hashing to the curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// The domain separation tag is empty.
+    InvalidDst,
+    /// The requested output length exceeds `255 · 64` bytes.
+    InvalidLength,
+}
+
+/// Expands a message into `len` uniform bytes (RFC 9380 §5.3.1), using
+/// SHA-512.
+///
+/// # Parameters
+///
+/// - `msg`: Input message
+/// - `dst`: Domain separation tag, non-empty
+/// - `len`: Output length, at most 16320 bytes
+///
+/// # Returns
+///
+/// - `Ok(bytes)` with exactly `len` bytes
+/// - `Err(InvalidDst)` if `dst` is empty
+/// - `Err(InvalidLength)` if `len` is too large
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Result<Vec<u8>, HashToCurveError> {
+    if dst.is_empty() {
+        return Err(HashToCurveError::InvalidDst);
+    }
+
+    let ell = len.div_ceil(HASH_LEN);
+    if ell > 255 {
+        return Err(HashToCurveError::InvalidLength);
+    }
+
+    let oversize;
+    let dst = if dst.len() > 255 {
+        let mut hasher = Sha512::new();
+        hasher.update(OVERSIZE_DST_PREFIX);
+        hasher.update(dst);
+        oversize = hasher.finalize();
+        &oversize[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || I2OSP(len, 2) || I2OSP(0, 1) || DST_prime)
+    let mut hasher = Sha512::new();
+    hasher.update(&[0u8; BLOCK_LEN]);
+    hasher.update(msg);
+    hasher.update(&(len as u16).to_be_bytes());
+    hasher.update(&[0]);
+    hasher.update(dst);
+    hasher.update(&dst_len);
+    let b0 = hasher.finalize();
+
+    let mut out = Vec::with_capacity(ell * HASH_LEN);
+    let mut chain = [0u8; HASH_LEN];
+
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+    for i in 1..=ell {
+        for (c, b) in chain.iter_mut().zip(b0.iter()) {
+            *c ^= b;
+        }
+
+        let mut hasher = Sha512::new();
+        hasher.update(&chain);
+        hasher.update(&[i as u8]);
+        hasher.update(dst);
+        hasher.update(&dst_len);
+        chain = hasher.finalize();
+
+        out.extend_from_slice(&chain);
+    }
+
+    out.truncate(len);
+    Ok(out)
+}
+
+/// Hashes a message to an edwards25519 point with the
+/// `edwards25519_XMD:SHA-512_ELL2_RO_` suite (RFC 9380 §3).
+///
+/// The output is indistinguishable from a uniformly random point of the
+/// prime-order subgroup.
+///
+/// # Returns
+///
+/// - `Ok(point)` on success
+/// - `Err(InvalidDst)` if `dst` is empty
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Result<EdwardsPoint, HashToCurveError> {
+    let [u0, u1] = hash_to_field::<2>(msg, dst)?;
+    let q = map_to_curve(u0) + map_to_curve(u1);
+
+    Ok(EdwardsPoint(q.mul_by_cofactor()))
+}
+
+/// Encodes a message to an edwards25519 point with the
+/// `edwards25519_XMD:SHA-512_ELL2_NU_` suite (RFC 9380 §3).
+///
+/// Faster than [`hash_to_curve`], but the output only covers about half
+/// of the points; use it only where the protocol allows a nonuniform
+/// encoding.
+///
+/// # Returns
+///
+/// - `Ok(point)` on success
+/// - `Err(InvalidDst)` if `dst` is empty
+pub fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Result<EdwardsPoint, HashToCurveError> {
+    let [u] = hash_to_field::<1>(msg, dst)?;
+
+    Ok(EdwardsPoint(map_to_curve(u).mul_by_cofactor()))
+}
+
+/// Hashes a message to `N` field elements (RFC 9380 §5.2).
+fn hash_to_field<const N: usize>(
+    msg: &[u8],
+    dst: &[u8],
+) -> Result<[FieldElement; N], HashToCurveError> {
+    let mut uniform = expand_message_xmd(msg, dst, N * FIELD_LEN)?;
+
+    let elements = core::array::from_fn(|i| {
+        let chunk = &uniform[i * FIELD_LEN..(i + 1) * FIELD_LEN];
+        from_be_bytes_wide(chunk.try_into().unwrap())
+    });
+    uniform.fill(0);
+
+    Ok(elements)
+}
+
+/// Reduces a 48-byte big-endian integer modulo `p`.
+///
+/// The integer is split into two 192-bit halves, `hi · 2¹⁹² + lo`, each
+/// of which fits in a field element.
+fn from_be_bytes_wide(bytes: &[u8; FIELD_LEN]) -> FieldElement {
+    let mut lo = [0u8; 32];
+    let mut hi = [0u8; 32];
+
+    for i in 0..24 {
+        lo[i] = bytes[FIELD_LEN - 1 - i];
+        hi[i] = bytes[23 - i];
+    }
+
+    let r = FieldElement::from_bytes(&lo) + FieldElement::from_bytes(&hi) * TWO_192;
+    lo.fill(0);
+    hi.fill(0);
+
+    r
+}
+
+/// Maps a field element to edwards25519, without clearing the cofactor
+/// (RFC 9380 §6.8.2).
+fn map_to_curve(u: FieldElement) -> GeP3 {
+    let (s, t) = map_to_curve25519(u);
+    montgomery_to_edwards(s, t)
+}
+
+/// Elligator 2 map to curve25519 (RFC 9380 §6.7.1), with `Z = 2`.
+///
+/// Returns the Montgomery coordinates `(s, t)`. The same map decodes
+/// the Elligator 2 representatives of X25519 public keys.
+pub(crate) fn map_to_curve25519(u: FieldElement) -> (FieldElement, FieldElement) {
+    let one = FieldElement::ONE;
+    let zero = FieldElement::ZERO;
+
+    // x1 = -A / (1 + Z·u²), or -A if the denominator is zero
+    let zu2 = u.square();
+    let zu2 = zu2 + zu2;
+    let mut x1 = -(MONTGOMERY_A * (one + zu2).invert());
+    x1.conditional_move(&-MONTGOMERY_A, x1.ct_eq(&zero));
+
+    let x2 = -x1 - MONTGOMERY_A;
+
+    // g(x) = x³ + A·x² + x
+    let gx1 = ((x1 + MONTGOMERY_A) * x1 + one) * x1;
+    let gx2 = ((x2 + MONTGOMERY_A) * x2 + one) * x2;
+
+    let (gx1_is_square, y1) = FieldElement::sqrt_ratio_m1(&gx1, &one);
+    let (_, y2) = FieldElement::sqrt_ratio_m1(&gx2, &one);
+
+    // sgn0(y) = 1 for x1, 0 for x2; the roots above are non-negative.
+    let mut x = x2;
+    let mut y = y2;
+    x.conditional_move(&x1, gx1_is_square);
+    y.conditional_move(&-y1, gx1_is_square);
+
+    (x, y)
+}
+
+/// Rational map from curve25519 to edwards25519 (RFC 9380 §6.8.2):
+///
+/// ```text
+/// x = sqrt(-486664) · s / t
+/// y = (s - 1) / (s + 1)
+/// ```
+///
+/// The exceptional inputs `t = 0` and `s = -1` map to the identity.
+fn montgomery_to_edwards(s: FieldElement, t: FieldElement) -> GeP3 {
+    let one = FieldElement::ONE;
+
+    let s_plus_one = s + one;
+    let den = t * s_plus_one;
+    let inv = den.invert();
+
+    let x = SQRT_MINUS_A_MINUS_2 * s * s_plus_one * inv;
+    let mut y = (s - one) * t * inv;
+    y.conditional_move(&one, den.ct_eq(&FieldElement::ZERO));
+
+    GeP3 {
+        x,
+        y,
+        z: one,
+        t: x * y,
+    }
+}
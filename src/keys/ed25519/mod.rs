@@ -41,6 +41,13 @@
 /// This is the only module most users should interact with directly.
 pub(crate) mod core;
 
+/// Ed25519 to X25519 key conversion.
+///
+/// This module adds `to_x25519` conversions to the Ed25519 key types:
+/// - public keys, with validation of the point,
+/// - secret keys, compatible with libsodium.
+pub(crate) mod convert;
+
 /// Constant-time utilities.
 ///
 /// This module contains helpers and traits for constant-time comparisons
@@ -75,6 +82,23 @@ pub(crate) mod field;
 /// - cached and precomputed representations for efficiency.
 pub(crate) mod group;
 
+/// Public Edwards25519 points.
+///
+/// Exposes the curve as a safe `EdwardsPoint` type:
+/// - canonical compression and decompression,
+/// - point addition, subtraction and negation,
+/// - fixed-base, variable-base and multi-scalar multiplication,
+/// - cofactor clearing and small-order checks.
+pub(crate) mod point;
+
+/// Hashing to the curve (RFC 9380).
+///
+/// Implements the edwards25519 hash-to-curve suites:
+/// - `expand_message_xmd` with SHA-512,
+/// - the Elligator 2 map and the rational map to edwards25519,
+/// - `hash_to_curve` (RO) and `encode_to_curve` (NU).
+pub(crate) mod hash_to_curve;
+
 /// Scalar arithmetic.
 ///
 /// Implements arithmetic modulo the Ed25519 group order ℓ.
@@ -85,6 +109,13 @@ pub(crate) mod group;
 /// - scalar reduction and linear combinations.
 pub(crate) mod scalar;
 
+/// Ed25519ctx and Ed25519ph.
+///
+/// This module adds the RFC 8032 variants with a `dom2` domain separator:
+/// - context-bound signatures (Ed25519ctx),
+/// - prehashed signatures with a streaming SHA-512 input (Ed25519ph).
+pub(crate) mod variants;
+
 /// Precomputed tables.
 ///
 /// Contains static precomputed constants and tables used to accelerate
@@ -95,3 +126,6 @@ pub(crate) mod table;
 
 // Re-export the public API at the `ed25519` level.
 pub use core::*;
+pub use hash_to_curve::*;
+pub use point::*;
+pub use variants::*;
@@ -0,0 +1,210 @@
+//! Public Edwards25519 point arithmetic.
+//!
+//! This module exposes the curve underlying Ed25519 as a safe point type,
+//! so that protocols (Schnorr proofs, Pedersen commitments, key
+//! blinding, …) can be built without access to the internal coordinate
+//! representations:
+//!
+//! ```text
+//! EdwardsPoint  ⟷  32-byte compressed encoding (RFC 8032 §5.1.2)
+//! ```
+//!
+//! Together with the [`Scalar`] field API, it provides addition,
+//! subtraction, negation, variable-base and fixed-base scalar
+//! multiplication, multi-scalar multiplication, cofactor clearing and
+//! small-order checks.
+//!
+//! ## Design goals
+//!
+//! - Constant-time operations wherever secrets may be involved: scalar
+//!   multiplication, encoding, equality
+//! - Variable-time functions are explicitly marked `vartime`
+//! - Canonical encodings only
+//!
+//! ## Notes
+//!
+//! - The curve has cofactor 8. Points decoded from untrusted input may
+//!   have a small-order component; use [`EdwardsPoint::is_torsion_free`]
+//!   or [`EdwardsPoint::mul_by_cofactor`] where the protocol requires it,
+//!   or use [`ristretto255`](crate::keys::ristretto255) instead.
+//! - Scalars are reduced modulo `ℓ` before multiplication. For a point
+//!   with a torsion component, this differs from multiplying by the
+//!   unreduced integer.
+
+use super::group::GeP3;
+use super::scalar::Scalar;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Errors that can occur while decoding points and scalars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveError {
+    /// The bytes are not the canonical encoding of a curve point.
+    InvalidPoint,
+    /// The scalar is not strictly below the group order `ℓ`.
+    NonCanonicalScalar,
+}
+
+/// A point on the Edwards25519 curve.
+///
+/// Points are kept in extended coordinates and compared projectively, so
+/// two values are equal if and only if they represent the same point.
+#[derive(Clone, Copy)]
+pub struct EdwardsPoint(pub(crate) GeP3);
+
+impl EdwardsPoint {
+    /// Returns the identity point `(0, 1)`.
+    pub fn identity() -> Self {
+        Self(GeP3::ONE)
+    }
+
+    /// Returns the Ed25519 base point `B`.
+    pub fn generator() -> Self {
+        Self(GeP3::from_scalar_mul(Scalar::ONE))
+    }
+
+    /// Computes `scalar · B` with the precomputed base point table.
+    ///
+    /// Runs in constant time.
+    pub fn mul_base(scalar: &Scalar) -> Self {
+        Self(GeP3::from_scalar_mul(scalar.reduced()))
+    }
+
+    /// Decodes a point from its 32-byte compressed encoding.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(point)` if the bytes are the canonical encoding of a point
+    /// - `Err(InvalidPoint)` otherwise, including for non-canonical
+    ///   `y` coordinates and for `x = 0` with the sign bit set
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, CurveError> {
+        let (minus_p, invalid) = GeP3::decompress(bytes);
+        if invalid != 0 {
+            return Err(CurveError::InvalidPoint);
+        }
+
+        let point = -minus_p;
+        if point.to_bytes() != *bytes {
+            return Err(CurveError::InvalidPoint);
+        }
+
+        Ok(Self(point))
+    }
+
+    /// Encodes this point into its canonical 32-byte compressed form.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Computes `Σ scalars[i] · points[i]`.
+    ///
+    /// Uses Straus's method with a shared doubling chain, which is much
+    /// faster than separate multiplications.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `scalars` have different lengths.
+    ///
+    /// # Security Notes
+    ///
+    /// - Runs in **variable time**: use only with public points and
+    ///   scalars, e.g. for verification equations.
+    pub fn vartime_multiscalar_mul(points: &[EdwardsPoint], scalars: &[Scalar]) -> Self {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must have the same length"
+        );
+
+        let points: Vec<GeP3> = points.iter().map(|p| p.0).collect();
+        let scalars: Vec<Scalar> = scalars.iter().map(|s| s.reduced()).collect();
+
+        Self(GeP3::vartime_multiscalar_mul(
+            &points,
+            &scalars,
+            Scalar::ZERO,
+        ))
+    }
+
+    /// Returns `[8] · self`, which lies in the prime-order subgroup.
+    pub fn mul_by_cofactor(&self) -> Self {
+        Self(self.0.mul_by_cofactor())
+    }
+
+    /// Returns `true` if this is the identity point.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+
+    /// Returns `true` if the point lies in the torsion subgroup of
+    /// order 8, including the identity.
+    pub fn is_small_order(&self) -> bool {
+        self.0.is_small_order()
+    }
+
+    /// Returns `true` if the point lies in the prime-order subgroup,
+    /// i.e. `ℓ · P` is the identity.
+    ///
+    /// Runs in variable time; intended for public points.
+    pub fn is_torsion_free(&self) -> bool {
+        self.0.is_torsion_free()
+    }
+}
+
+impl PartialEq for EdwardsPoint {
+    /// Compares two points projectively, in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        let a = &self.0;
+        let b = &other.0;
+
+        let x = (a.x * b.z).ct_eq(&(b.x * a.z));
+        let y = (a.y * b.z).ct_eq(&(b.y * a.z));
+
+        (x & y) == 1
+    }
+}
+
+impl Eq for EdwardsPoint {}
+
+impl fmt::Debug for EdwardsPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EdwardsPoint")
+            .field(&self.to_bytes())
+            .finish()
+    }
+}
+
+impl Add for EdwardsPoint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for EdwardsPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Neg for EdwardsPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Variable-base scalar multiplication, in constant time.
+///
+/// The scalar is reduced modulo `ℓ` first.
+impl Mul<&Scalar> for EdwardsPoint {
+    type Output = Self;
+
+    fn mul(self, scalar: &Scalar) -> Self::Output {
+        Self(self.0.scalar_mul(scalar.reduced()))
+    }
+}
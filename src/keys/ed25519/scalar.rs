@@ -42,6 +42,8 @@
 //! - Reduction of wide integers modulo ℓ (`reduce`)
 //! - Modular linear combinations (`a * b + c mod ℓ`)
 //! - Sliding-window scalar recoding (`slide`)
+//! - The public field API: canonical decoding, addition, subtraction,
+//!   multiplication, negation and inversion modulo ℓ
 //!
 //! These primitives are sufficient to support:
 //!
@@ -84,9 +86,14 @@
 //! Higher-level guarantees (key clamping, protocol correctness) are enforced
 //! by the layers that use this module.
 
+use super::ct::ConstantTimeEq;
+use super::point::CurveError;
 use crate::keys::ed25519::field::{load_3, load_4};
+use crate::rng::Csprng;
 
 use std::array;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
 
 /// A 256-bit scalar used in Ed25519 operations.
 ///
@@ -105,7 +112,22 @@ use std::array;
 #[derive(Clone, Copy)]
 pub struct Scalar(pub [u8; 32]);
 
+/// The group order `ℓ`, little-endian.
+pub(crate) const L: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
 impl Scalar {
+    /// The scalar `0`.
+    pub const ZERO: Self = Scalar([0u8; 32]);
+
+    /// The scalar `1`.
+    pub const ONE: Self = Scalar([
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
     /// Constructs a scalar from a 32-byte little-endian slice.
     ///
     /// This function performs no validation, clamping, or modular
@@ -128,6 +150,104 @@ impl Scalar {
         self.0
     }
 
+    /// Decodes a canonical scalar, strictly below `ℓ`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(scalar)` on success
+    /// - `Err(NonCanonicalScalar)` if `bytes` encodes a value `>= ℓ`
+    ///
+    /// # Security Notes
+    ///
+    /// - The range check runs in constant time.
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Result<Self, CurveError> {
+        if !Self::is_canonical(bytes) {
+            return Err(CurveError::NonCanonicalScalar);
+        }
+
+        Ok(Scalar(*bytes))
+    }
+
+    /// Reduces a 256-bit little-endian integer modulo `ℓ`.
+    pub fn from_bytes_mod_order(bytes: &[u8; 32]) -> Self {
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(bytes);
+
+        let reduced = Self::reduce(wide);
+        wide.fill(0);
+
+        reduced
+    }
+
+    /// Reduces a 512-bit little-endian integer modulo `ℓ`.
+    ///
+    /// With 64 uniformly random bytes (e.g. a SHA-512 output), the result
+    /// is a uniformly distributed scalar.
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Self {
+        Self::reduce(*bytes)
+    }
+
+    /// Generates a uniformly random scalar from the operating system
+    /// CSPRNG.
+    pub fn random() -> Self {
+        let mut wide = [0u8; 64];
+        Csprng::new().fill_bytes(&mut wide);
+
+        let scalar = Self::reduce(wide);
+        wide.fill(0);
+
+        scalar
+    }
+
+    /// Returns `self` reduced modulo `ℓ`.
+    pub fn reduced(self) -> Self {
+        Self::from_bytes_mod_order(&self.0)
+    }
+
+    /// Returns `true` if the scalar is zero modulo `ℓ`, in constant time.
+    pub fn is_zero(&self) -> bool {
+        self.reduced().0.ct_eq(&[0u8; 32])
+    }
+
+    /// Computes the multiplicative inverse modulo `ℓ`.
+    ///
+    /// Uses Fermat's little theorem, `a⁻¹ = a^(ℓ − 2)`, with a fixed
+    /// square-and-multiply chain over the public exponent. The inverse of
+    /// zero is zero.
+    ///
+    /// # Security Notes
+    ///
+    /// - Runs in constant time with respect to `self`.
+    pub fn invert(self) -> Self {
+        let mut exponent = L;
+        exponent[0] -= 2;
+
+        let base = self.reduced();
+        let mut acc = Self::ONE;
+
+        for i in (0..253).rev() {
+            acc = acc * acc;
+            if (exponent[i >> 3] >> (i & 7)) & 1 == 1 {
+                acc = acc * base;
+            }
+        }
+
+        acc
+    }
+
+    /// Returns `true` if `bytes` encodes an integer strictly below `ℓ`.
+    ///
+    /// Signature verification uses this to reject malleable encodings
+    /// `S + k·ℓ`. The comparison computes the borrow of `bytes − ℓ` over
+    /// all bytes, so it also runs in constant time on secret scalars.
+    pub(crate) fn is_canonical(bytes: &[u8; 32]) -> bool {
+        let borrow = bytes.iter().zip(L.iter()).fold(0i16, |borrow, (&b, &l)| {
+            ((b as i16 - l as i16 - borrow) >> 8) & 1
+        });
+
+        borrow == 1
+    }
+
     /// Reduces a 512-bit integer modulo the Ed25519 scalar field order `ℓ`.
     ///
     /// This function takes a 64-byte (512-bit) input and reduces it modulo
@@ -263,7 +383,7 @@ impl Scalar {
 
         let s12 = s[12];
         for (sx, coeff) in s.iter_mut().take(6).zip(coeffs.iter()) {
-            *sx = s12 * coeff;
+            *sx += s12 * coeff;
         }
         s[12] = 0;
 
@@ -280,7 +400,7 @@ impl Scalar {
 
         let s12 = s[12];
         for (sx, coeff) in s.iter_mut().take(6).zip(coeffs.iter()) {
-            *sx = s12 * coeff;
+            *sx += s12 * coeff;
         }
 
         for index in 0..11 {
@@ -646,6 +766,63 @@ impl Scalar {
     }
 }
 
+/// `ℓ − 1`, the representative of `−1`.
+const L_MINUS_ONE: Scalar = Scalar([
+    0xec, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+]);
+
+/// Addition modulo `ℓ`, in constant time.
+impl Add for Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Scalar::from_mul_sum(self, Scalar::ONE, rhs)
+    }
+}
+
+/// Subtraction modulo `ℓ`, in constant time.
+impl Sub for Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Scalar::from_mul_sum(rhs, L_MINUS_ONE, self)
+    }
+}
+
+/// Multiplication modulo `ℓ`, in constant time.
+impl Mul for Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Scalar::from_mul_sum(self, rhs, Scalar::ZERO)
+    }
+}
+
+/// Negation modulo `ℓ`, in constant time.
+impl Neg for Scalar {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Scalar::from_mul_sum(self, L_MINUS_ONE, Scalar::ZERO)
+    }
+}
+
+impl PartialEq for Scalar {
+    /// Compares two scalars modulo `ℓ`, in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        self.reduced().0.ct_eq(&other.reduced().0)
+    }
+}
+
+impl Eq for Scalar {}
+
+impl fmt::Debug for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Scalar").field(&self.0).finish()
+    }
+}
+
 /// Signed sliding-window representation of a scalar.
 ///
 /// This type represents a scalar decomposed into 256 signed digits,
@@ -0,0 +1,167 @@
+//! Ed25519ctx and Ed25519ph (RFC 8032 §5.1).
+//!
+//! Both variants prepend a domain separator to every hash of the signing
+//! and verification procedures:
+//!
+//! ```text
+//! dom2(F, C) = "SigEd25519 no Ed25519 collisions" || F || len(C) || C
+//! ```
+//!
+//! - **Ed25519ctx** (`F = 0`) binds a signature to a context string, so a
+//!   signature made for one protocol never verifies in another.
+//! - **Ed25519ph** (`F = 1`) signs `SHA-512(M)` instead of `M`, so very
+//!   large inputs can be hashed in a streaming fashion with [`Prehash`]
+//!   before signing.
+//!
+//! Signatures of the three schemes (Ed25519, Ed25519ctx, Ed25519ph) are
+//! never valid for one another, even with the same key and message.
+//!
+//! ## Notes
+//!
+//! - Contexts are at most 255 bytes. Ed25519ctx requires a non-empty
+//!   context; Ed25519ph allows an empty one.
+//! - Verification follows the [`verify_strict`](super::verify_strict)
+//!   rules: canonical encodings only and no small-order points.
+
+use super::core::{
+    Ed25519Error, PrivateKey, PublicKey, Signature, sign_with_domain, verify_strict_with_domain,
+};
+use crate::hash::Sha512;
+
+/// Prefix of the `dom2` domain separator.
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+
+/// Maximum context length, in bytes.
+pub const MAX_CONTEXT_LEN: usize = 255;
+
+/// Streaming SHA-512 prehash for Ed25519ph.
+///
+/// Feed the message in any number of pieces, then pass the result of
+/// [`finalize`](Self::finalize) to [`sign_ph`] or [`verify_ph`]:
+///
+/// ```text
+/// let mut prehash = Prehash::new();
+/// prehash.update(chunk_1);
+/// prehash.update(chunk_2);
+/// let digest = prehash.finalize();
+/// ```
+#[derive(Clone)]
+pub struct Prehash(Sha512);
+
+impl Prehash {
+    /// Starts a prehash of an empty message.
+    pub fn new() -> Self {
+        Self(Sha512::new())
+    }
+
+    /// Absorbs the next part of the message.
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    /// Returns `SHA-512(M)`.
+    pub fn finalize(self) -> [u8; 64] {
+        self.0.finalize()
+    }
+}
+
+impl Default for Prehash {
+    /// Starts a prehash of an empty message.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Signs a message with Ed25519ctx.
+///
+/// # Parameters
+///
+/// - `message`: Message to sign
+/// - `context`: Context string, 1 to 255 bytes
+/// - `public`, `private`: Signer's key pair
+///
+/// # Returns
+///
+/// - `Ok(signature)` on success
+/// - `Err(InvalidContext)` if the context is empty or too long
+pub fn sign_ctx(
+    message: &[u8],
+    context: &[u8],
+    public: PublicKey,
+    private: PrivateKey,
+) -> Result<Signature, Ed25519Error> {
+    if context.is_empty() {
+        return Err(Ed25519Error::InvalidContext);
+    }
+
+    let dom = dom2(0, context)?;
+    Ok(sign_with_domain(&dom, message, public, private))
+}
+
+/// Verifies an Ed25519ctx signature.
+///
+/// Returns `false` for an invalid signature, or a context that is empty
+/// or too long.
+pub fn verify_ctx(signature: Signature, message: &[u8], context: &[u8], public: PublicKey) -> bool {
+    if context.is_empty() {
+        return false;
+    }
+
+    match dom2(0, context) {
+        Ok(dom) => verify_strict_with_domain(&dom, signature, message, public),
+        Err(_) => false,
+    }
+}
+
+/// Signs a prehashed message with Ed25519ph.
+///
+/// # Parameters
+///
+/// - `prehash`: `SHA-512(M)`, e.g. from [`Prehash::finalize`]
+/// - `context`: Context string, up to 255 bytes (may be empty)
+/// - `public`, `private`: Signer's key pair
+///
+/// # Returns
+///
+/// - `Ok(signature)` on success
+/// - `Err(InvalidContext)` if the context is too long
+pub fn sign_ph(
+    prehash: &[u8; 64],
+    context: &[u8],
+    public: PublicKey,
+    private: PrivateKey,
+) -> Result<Signature, Ed25519Error> {
+    let dom = dom2(1, context)?;
+    Ok(sign_with_domain(&dom, prehash, public, private))
+}
+
+/// Verifies an Ed25519ph signature over a prehashed message.
+///
+/// Returns `false` for an invalid signature or a context that is too
+/// long.
+pub fn verify_ph(
+    signature: Signature,
+    prehash: &[u8; 64],
+    context: &[u8],
+    public: PublicKey,
+) -> bool {
+    match dom2(1, context) {
+        Ok(dom) => verify_strict_with_domain(&dom, signature, prehash, public),
+        Err(_) => false,
+    }
+}
+
+/// Builds the `dom2(flag, context)` prefix.
+fn dom2(flag: u8, context: &[u8]) -> Result<Vec<u8>, Ed25519Error> {
+    if context.len() > MAX_CONTEXT_LEN {
+        return Err(Ed25519Error::InvalidContext);
+    }
+
+    let mut dom = Vec::with_capacity(DOM2_PREFIX.len() + 2 + context.len());
+    dom.extend_from_slice(DOM2_PREFIX);
+    dom.push(flag);
+    dom.push(context.len() as u8);
+    dom.extend_from_slice(context);
+
+    Ok(dom)
+}
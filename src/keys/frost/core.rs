@@ -0,0 +1,550 @@
+//! FROST(Ed25519, SHA-512) signing rounds (RFC 9591).
+//!
+//! FROST lets any `t` of `n` participants produce an Ed25519 signature
+//! under a shared group key, without the private key ever existing in
+//! one place. Signing takes two rounds, orchestrated by a coordinator:
+//!
+//! ```text
+//! round one   each signer:   commit(key)                  → nonces, commitments
+//! round two   each signer:   sign(key, nonces, msg, list) → signature share
+//! coordinator:               aggregate(msg, list, shares) → Signature
+//! ```
+//!
+//! The result is a standard 64-byte Ed25519 signature, verifiable with
+//! [`ed25519::verify`](crate::keys::ed25519::verify) against the group
+//! public key.
+//!
+//! ## Design goals
+//!
+//! - Interoperable with other RFC 9591 implementations
+//!   (`FROST-ED25519-SHA512-v1`)
+//! - Single-use nonces, enforced by the type system
+//! - Misbehaving signers are identified during aggregation
+//!
+//! ## Notes
+//!
+//! - Nonces must never be reused: [`SigningNonces`] cannot be copied and
+//!   is consumed by [`sign`].
+//! - Participants must check that the message and commitment list sent
+//!   by the coordinator are the ones they agreed to sign.
+//! - Commitments are validated on decoding: canonical, not the identity
+//!   and in the prime-order subgroup.
+
+use super::keygen::{KeyPackage, PublicKeyPackage};
+use crate::hash::Sha512;
+use crate::keys::ed25519::ct::ConstantTimeEq;
+use crate::keys::ed25519::{EdwardsPoint, Scalar, Signature, verify};
+use crate::rng::Csprng;
+
+/// Context string of the ciphersuite.
+const CONTEXT: &[u8] = b"FROST-ED25519-SHA512-v1";
+
+/// Errors that can occur during key generation and signing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrostError {
+    /// The threshold is below 2 or above the number of participants.
+    InvalidThreshold,
+    /// An identifier is zero or out of range.
+    InvalidIdentifier,
+    /// Two commitments or shares have the same identifier.
+    DuplicateIdentifier,
+    /// The signer, or a share's author, is not in the commitment list.
+    UnknownIdentifier,
+    /// Fewer commitments or shares than the threshold were provided.
+    NotEnoughSigners,
+    /// A commitment does not decode, or does not match the nonces.
+    InvalidCommitment,
+    /// A signature share is not a canonical scalar.
+    InvalidShare,
+    /// The signature share of this participant does not verify.
+    InvalidSignatureShare(u16),
+    /// The verifying shares do not match the group public key.
+    InconsistentPublicKey,
+}
+
+/// A participant's secret nonces for one signing operation.
+///
+/// # Security Notes
+///
+/// - Nonces are consumed by [`sign`] and cannot be copied, so they are
+///   used at most once.
+/// - The nonces are wiped from memory on drop.
+pub struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+    commitments: SigningCommitments,
+}
+
+impl SigningNonces {
+    /// Returns the commitments to these nonces.
+    pub fn commitments(&self) -> SigningCommitments {
+        self.commitments
+    }
+}
+
+impl Drop for SigningNonces {
+    fn drop(&mut self) {
+        self.hiding.0.fill(0);
+        self.binding.0.fill(0);
+    }
+}
+
+/// A participant's public commitments to its nonces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningCommitments {
+    identifier: u16,
+    hiding: EdwardsPoint,
+    binding: EdwardsPoint,
+}
+
+impl SigningCommitments {
+    /// Decodes commitments from `hiding || binding`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(commitments)` on success
+    /// - `Err(InvalidIdentifier)` if `identifier` is zero
+    /// - `Err(InvalidCommitment)` if an element is not canonical, is the
+    ///   identity or is not in the prime-order subgroup
+    pub fn from_bytes(identifier: u16, bytes: &[u8; 64]) -> Result<Self, FrostError> {
+        if identifier == 0 {
+            return Err(FrostError::InvalidIdentifier);
+        }
+
+        Ok(Self {
+            identifier,
+            hiding: deserialize_element(bytes[..32].try_into().unwrap())?,
+            binding: deserialize_element(bytes[32..].try_into().unwrap())?,
+        })
+    }
+
+    /// Encodes the commitments as `hiding || binding`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.hiding.to_bytes());
+        out[32..].copy_from_slice(&self.binding.to_bytes());
+
+        out
+    }
+
+    /// Returns the identifier of the participant.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+/// A participant's signature share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureShare {
+    identifier: u16,
+    share: Scalar,
+}
+
+impl SignatureShare {
+    /// Decodes a signature share.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(share)` on success
+    /// - `Err(InvalidIdentifier)` if `identifier` is zero
+    /// - `Err(InvalidShare)` if the scalar is not canonical
+    pub fn from_bytes(identifier: u16, bytes: &[u8; 32]) -> Result<Self, FrostError> {
+        if identifier == 0 {
+            return Err(FrostError::InvalidIdentifier);
+        }
+
+        let share = Scalar::from_canonical_bytes(bytes).map_err(|_| FrostError::InvalidShare)?;
+        Ok(Self { identifier, share })
+    }
+
+    /// Encodes the share as a 32-byte scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.share.to_bytes()
+    }
+
+    /// Returns the identifier of the participant.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+/// Round one: generates nonces and their commitments (RFC 9591 §5.1).
+///
+/// The commitments are sent to the coordinator; the nonces are kept
+/// secret until round two.
+pub fn commit(key: &KeyPackage) -> SigningNonces {
+    let mut hiding_randomness = [0u8; 32];
+    let mut binding_randomness = [0u8; 32];
+
+    let mut rng = Csprng::new();
+    rng.fill_bytes(&mut hiding_randomness);
+    rng.fill_bytes(&mut binding_randomness);
+
+    let nonces = commit_with_randomness(key, &hiding_randomness, &binding_randomness);
+    hiding_randomness.fill(0);
+    binding_randomness.fill(0);
+
+    nonces
+}
+
+/// Round one with caller-provided randomness.
+///
+/// The nonces are `H3(randomness || signing_share)`, as in
+/// `nonce_generate` (RFC 9591 §4.1).
+///
+/// # Security Notes
+///
+/// - Reusing randomness for two signatures with the same key leaks the
+///   key share. Use [`commit`] unless the randomness comes from a
+///   trusted source, e.g. for test vectors.
+pub fn commit_with_randomness(
+    key: &KeyPackage,
+    hiding_randomness: &[u8; 32],
+    binding_randomness: &[u8; 32],
+) -> SigningNonces {
+    let secret = key.signing_share().to_bytes();
+    let hiding = h3(&[hiding_randomness, &secret]);
+    let binding = h3(&[binding_randomness, &secret]);
+
+    SigningNonces {
+        hiding,
+        binding,
+        commitments: SigningCommitments {
+            identifier: key.identifier(),
+            hiding: EdwardsPoint::mul_base(&hiding),
+            binding: EdwardsPoint::mul_base(&binding),
+        },
+    }
+}
+
+/// Round two: computes this participant's signature share
+/// (RFC 9591 §5.2).
+///
+/// # Parameters
+///
+/// - `key`: Participant's key package
+/// - `nonces`: Nonces from round one, consumed
+/// - `message`: Message to sign
+/// - `commitments`: Commitments of all signers, including this one
+///
+/// # Returns
+///
+/// - `Ok(share)` on success
+/// - `Err(UnknownIdentifier)` if this participant has no commitment
+/// - `Err(InvalidCommitment)` if its commitment does not match `nonces`
+/// - `Err(DuplicateIdentifier)` or `Err(NotEnoughSigners)` if the
+///   commitment list is malformed
+pub fn sign(
+    key: &KeyPackage,
+    nonces: SigningNonces,
+    message: &[u8],
+    commitments: &[SigningCommitments],
+) -> Result<SignatureShare, FrostError> {
+    let commitments = sorted_commitments(commitments, key.min_signers())?;
+
+    let own = commitments
+        .iter()
+        .find(|c| c.identifier == key.identifier())
+        .ok_or(FrostError::UnknownIdentifier)?;
+    if *own != nonces.commitments {
+        return Err(FrostError::InvalidCommitment);
+    }
+
+    let group_public = key.group_public_key().to_bytes();
+    let binding_factors = binding_factors(&group_public, &commitments, message);
+    let group_commitment = group_commitment(&commitments, &binding_factors);
+
+    let identifiers: Vec<u16> = commitments.iter().map(|c| c.identifier).collect();
+    let lambda = lagrange_coefficient(&identifiers, key.identifier());
+    let challenge = challenge(&group_commitment, &group_public, message);
+
+    let rho = binding_factor_for(&commitments, &binding_factors, key.identifier());
+    let share = nonces.hiding + nonces.binding * rho + lambda * key.signing_share() * challenge;
+
+    Ok(SignatureShare {
+        identifier: key.identifier(),
+        share,
+    })
+}
+
+/// Verifies a signature share against the signer's verifying share
+/// (RFC 9591 §5.4).
+///
+/// Returns `false` if the share is invalid, if its author has no
+/// commitment or verifying share, or if the commitment list is
+/// malformed.
+pub fn verify_signature_share(
+    share: &SignatureShare,
+    public: &PublicKeyPackage,
+    message: &[u8],
+    commitments: &[SigningCommitments],
+) -> bool {
+    let Ok(commitments) = sorted_commitments(commitments, public.min_signers()) else {
+        return false;
+    };
+
+    let group_public = public.group_public_key().to_bytes();
+    let binding_factors = binding_factors(&group_public, &commitments, message);
+    let group_commitment = group_commitment(&commitments, &binding_factors);
+    let challenge = challenge(&group_commitment, &group_public, message);
+
+    verify_share(share, public, &commitments, &binding_factors, challenge)
+}
+
+/// Aggregates signature shares into an Ed25519 signature
+/// (RFC 9591 §5.3).
+///
+/// # Parameters
+///
+/// - `message`: Signed message
+/// - `commitments`: Commitment list used in round two
+/// - `shares`: One signature share per commitment
+/// - `public`: Group public key package
+///
+/// # Returns
+///
+/// - `Ok(signature)`, a standard signature under the group public key
+/// - `Err(InvalidSignatureShare(id))` if the signature does not verify,
+///   with the first participant whose share is invalid
+/// - `Err(InconsistentPublicKey)` if every share is valid but the
+///   verifying shares do not match the group public key
+/// - `Err(UnknownIdentifier)`, `Err(DuplicateIdentifier)` or
+///   `Err(NotEnoughSigners)` if the shares do not match the commitments
+pub fn aggregate(
+    message: &[u8],
+    commitments: &[SigningCommitments],
+    shares: &[SignatureShare],
+    public: &PublicKeyPackage,
+) -> Result<Signature, FrostError> {
+    let commitments = sorted_commitments(commitments, public.min_signers())?;
+
+    if shares.len() != commitments.len() {
+        return Err(FrostError::NotEnoughSigners);
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if !commitments.iter().any(|c| c.identifier == share.identifier) {
+            return Err(FrostError::UnknownIdentifier);
+        }
+        if shares[..i].iter().any(|s| s.identifier == share.identifier) {
+            return Err(FrostError::DuplicateIdentifier);
+        }
+    }
+
+    let group_public_key = public.group_public_key();
+    let group_public = group_public_key.to_bytes();
+    let binding_factors = binding_factors(&group_public, &commitments, message);
+    let group_commitment = group_commitment(&commitments, &binding_factors);
+
+    let z = shares
+        .iter()
+        .fold(Scalar::ZERO, |acc, share| acc + share.share);
+
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&group_commitment.to_bytes());
+    bytes[32..].copy_from_slice(&z.to_bytes());
+    let signature = Signature::from_bytes(bytes);
+
+    if verify(signature, message, group_public_key) {
+        return Ok(signature);
+    }
+
+    // Identify the misbehaving participant.
+    let challenge = challenge(&group_commitment, &group_public, message);
+    for share in shares {
+        if !verify_share(share, public, &commitments, &binding_factors, challenge) {
+            return Err(FrostError::InvalidSignatureShare(share.identifier));
+        }
+    }
+
+    // Every share is valid, so the verifying shares do not interpolate
+    // to the group public key.
+    Err(FrostError::InconsistentPublicKey)
+}
+
+/// Checks `z_i·B == D_i + rho_i·E_i + (c·lambda_i)·PK_i`.
+fn verify_share(
+    share: &SignatureShare,
+    public: &PublicKeyPackage,
+    commitments: &[SigningCommitments],
+    binding_factors: &[Scalar],
+    challenge: Scalar,
+) -> bool {
+    let Some(verifying_share) = public.verifying_share(share.identifier) else {
+        return false;
+    };
+    let Some(commitment) = commitments
+        .iter()
+        .find(|c| c.identifier == share.identifier)
+    else {
+        return false;
+    };
+
+    let rho = binding_factor_for(commitments, binding_factors, share.identifier);
+    let identifiers: Vec<u16> = commitments.iter().map(|c| c.identifier).collect();
+    let lambda = lagrange_coefficient(&identifiers, share.identifier);
+
+    let left = EdwardsPoint::mul_base(&share.share);
+    let right = EdwardsPoint::vartime_multiscalar_mul(
+        &[commitment.hiding, commitment.binding, verifying_share],
+        &[Scalar::ONE, rho, challenge * lambda],
+    );
+
+    left.to_bytes().ct_eq(&right.to_bytes())
+}
+
+/// Sorts the commitment list by identifier and checks its shape.
+fn sorted_commitments(
+    commitments: &[SigningCommitments],
+    min_signers: u16,
+) -> Result<Vec<SigningCommitments>, FrostError> {
+    let mut sorted = commitments.to_vec();
+    sorted.sort_by_key(|c| c.identifier);
+
+    if sorted
+        .windows(2)
+        .any(|w| w[0].identifier == w[1].identifier)
+    {
+        return Err(FrostError::DuplicateIdentifier);
+    }
+    if sorted.len() < min_signers as usize {
+        return Err(FrostError::NotEnoughSigners);
+    }
+
+    Ok(sorted)
+}
+
+/// Computes one binding factor per signer, in list order
+/// (RFC 9591 §4.4).
+fn binding_factors(
+    group_public: &[u8; 32],
+    commitments: &[SigningCommitments],
+    message: &[u8],
+) -> Vec<Scalar> {
+    let msg_hash = h4(message);
+
+    let mut encoded = Vec::with_capacity(commitments.len() * 96);
+    for c in commitments {
+        encoded.extend_from_slice(&identifier_to_scalar(c.identifier).to_bytes());
+        encoded.extend_from_slice(&c.to_bytes());
+    }
+    let commitment_hash = h5(&encoded);
+
+    commitments
+        .iter()
+        .map(|c| {
+            h1(&[
+                group_public,
+                &msg_hash,
+                &commitment_hash,
+                &identifier_to_scalar(c.identifier).to_bytes(),
+            ])
+        })
+        .collect()
+}
+
+/// Returns the binding factor of `identifier`.
+fn binding_factor_for(
+    commitments: &[SigningCommitments],
+    binding_factors: &[Scalar],
+    identifier: u16,
+) -> Scalar {
+    let index = commitments
+        .iter()
+        .position(|c| c.identifier == identifier)
+        .expect("identifier is in the commitment list");
+
+    binding_factors[index]
+}
+
+/// `R = Σ D_i + rho_i·E_i` (RFC 9591 §4.5).
+fn group_commitment(
+    commitments: &[SigningCommitments],
+    binding_factors: &[Scalar],
+) -> EdwardsPoint {
+    let mut points = Vec::with_capacity(2 * commitments.len());
+    let mut scalars = Vec::with_capacity(2 * commitments.len());
+
+    for (c, rho) in commitments.iter().zip(binding_factors) {
+        points.extend_from_slice(&[c.hiding, c.binding]);
+        scalars.extend_from_slice(&[Scalar::ONE, *rho]);
+    }
+
+    EdwardsPoint::vartime_multiscalar_mul(&points, &scalars)
+}
+
+/// `c = H2(R || PK || msg)`, the Ed25519 challenge (RFC 9591 §4.6).
+fn challenge(group_commitment: &EdwardsPoint, group_public: &[u8; 32], message: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(&group_commitment.to_bytes());
+    hasher.update(group_public);
+    hasher.update(message);
+
+    Scalar::reduce(hasher.finalize())
+}
+
+/// Lagrange coefficient of `identifier` at zero (RFC 9591 §4.2).
+pub(super) fn lagrange_coefficient(identifiers: &[u16], identifier: u16) -> Scalar {
+    let x_i = identifier_to_scalar(identifier);
+
+    let mut numerator = Scalar::ONE;
+    let mut denominator = Scalar::ONE;
+    for &j in identifiers.iter().filter(|&&j| j != identifier) {
+        let x_j = identifier_to_scalar(j);
+        numerator = numerator * x_j;
+        denominator = denominator * (x_j - x_i);
+    }
+
+    numerator * denominator.invert()
+}
+
+/// Maps a participant identifier to a scalar.
+pub(super) fn identifier_to_scalar(identifier: u16) -> Scalar {
+    let mut bytes = [0u8; 32];
+    bytes[..2].copy_from_slice(&identifier.to_le_bytes());
+
+    Scalar(bytes)
+}
+
+/// Decodes a group element, rejecting the identity and points outside
+/// the prime-order subgroup.
+fn deserialize_element(bytes: &[u8; 32]) -> Result<EdwardsPoint, FrostError> {
+    let point = EdwardsPoint::from_bytes(bytes).map_err(|_| FrostError::InvalidCommitment)?;
+
+    if point.is_identity() || !point.is_torsion_free() {
+        return Err(FrostError::InvalidCommitment);
+    }
+
+    Ok(point)
+}
+
+/// `H1(m) = SHA-512(contextString || "rho" || m) mod q`.
+fn h1(parts: &[&[u8]]) -> Scalar {
+    Scalar::reduce(hash(b"rho", parts))
+}
+
+/// `H3(m) = SHA-512(contextString || "nonce" || m) mod q`.
+fn h3(parts: &[&[u8]]) -> Scalar {
+    Scalar::reduce(hash(b"nonce", parts))
+}
+
+/// `H4(m) = SHA-512(contextString || "msg" || m)`.
+fn h4(message: &[u8]) -> [u8; 64] {
+    hash(b"msg", &[message])
+}
+
+/// `H5(m) = SHA-512(contextString || "com" || m)`.
+fn h5(encoded_commitments: &[u8]) -> [u8; 64] {
+    hash(b"com", &[encoded_commitments])
+}
+
+/// `SHA-512(contextString || label || parts...)`.
+fn hash(label: &[u8], parts: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    hasher.update(CONTEXT);
+    hasher.update(label);
+    for part in parts {
+        hasher.update(part);
+    }
+
+    hasher.finalize()
+}
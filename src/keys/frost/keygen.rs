@@ -0,0 +1,310 @@
+//! Trusted-dealer key generation for FROST (RFC 9591, Appendix C).
+//!
+//! A dealer splits a group signing key into `n` shares with Shamir's
+//! scheme over the Ed25519 scalar field. Any `t` of them can sign
+//! together, while the group public key is a regular Ed25519 public key:
+//!
+//! ```text
+//! f(x)    = s + a₁·x + … + a₍t₋₁₎·x^(t−1)      (mod L)
+//! share_i = f(i),  verifying_share_i = share_i · B
+//! ```
+//!
+//! Unlike the byte-wise scheme of `recovery::shamirsecretsharing`, the
+//! shares are never combined back into the key: signers use them
+//! directly in the FROST rounds.
+//!
+//! ## Notes
+//!
+//! - The dealer learns the group key; the secret and the shares must be
+//!   erased once distributed.
+//! - Splitting an existing Ed25519 key keeps its public key, so
+//!   signatures from the group verify as before. The key prefix is not
+//!   used by FROST.
+
+use super::core::{FrostError, identifier_to_scalar, lagrange_coefficient};
+use crate::keys::ed25519::{EdwardsPoint, PrivateKey, PublicKey, Scalar};
+
+/// A participant's share of the group signing key.
+///
+/// # Security Notes
+///
+/// - The signing share is wiped from memory on drop.
+pub struct KeyPackage {
+    identifier: u16,
+    signing_share: Scalar,
+    verifying_share: EdwardsPoint,
+    group_public: PublicKey,
+    min_signers: u16,
+}
+
+impl KeyPackage {
+    /// Restores a key package from its signing share.
+    ///
+    /// The signing share is not checked against `group_public`; a
+    /// mismatch is detected by [`aggregate`](super::aggregate).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(key)` on success
+    /// - `Err(InvalidIdentifier)` if `identifier` is zero
+    /// - `Err(InvalidThreshold)` if `min_signers` is below 2
+    pub fn new(
+        identifier: u16,
+        signing_share: Scalar,
+        group_public: PublicKey,
+        min_signers: u16,
+    ) -> Result<Self, FrostError> {
+        if identifier == 0 {
+            return Err(FrostError::InvalidIdentifier);
+        }
+        if min_signers < 2 {
+            return Err(FrostError::InvalidThreshold);
+        }
+
+        Ok(Self {
+            identifier,
+            signing_share: signing_share.reduced(),
+            verifying_share: EdwardsPoint::mul_base(&signing_share),
+            group_public,
+            min_signers,
+        })
+    }
+
+    /// Returns the identifier of the participant.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the secret signing share.
+    pub fn signing_share(&self) -> Scalar {
+        self.signing_share
+    }
+
+    /// Returns the public verifying share, `signing_share · B`.
+    pub fn verifying_share(&self) -> EdwardsPoint {
+        self.verifying_share
+    }
+
+    /// Returns the group public key.
+    pub fn group_public_key(&self) -> PublicKey {
+        self.group_public
+    }
+
+    /// Returns the number of signers required to sign.
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+impl Drop for KeyPackage {
+    fn drop(&mut self) {
+        self.signing_share.0.fill(0);
+    }
+}
+
+/// The public information of a group, used to verify signature shares
+/// and aggregate them.
+#[derive(Clone)]
+pub struct PublicKeyPackage {
+    verifying_shares: Vec<(u16, EdwardsPoint)>,
+    group_public: PublicKey,
+    min_signers: u16,
+}
+
+impl PublicKeyPackage {
+    /// Restores a public key package.
+    ///
+    /// The verifying shares of the first `min_signers` participants must
+    /// interpolate to `group_public`. Shares split with a higher
+    /// threshold, or for another key, are rejected. The remaining shares
+    /// are not checked; an inconsistent one is reported by
+    /// [`aggregate`](super::aggregate).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(package)` on success
+    /// - `Err(InvalidIdentifier)` if an identifier is zero
+    /// - `Err(DuplicateIdentifier)` if an identifier appears twice
+    /// - `Err(InvalidThreshold)` if `min_signers` is below 2 or above
+    ///   the number of participants
+    /// - `Err(InconsistentPublicKey)` if the verifying shares do not
+    ///   match `group_public`
+    pub fn new(
+        verifying_shares: &[(u16, EdwardsPoint)],
+        group_public: PublicKey,
+        min_signers: u16,
+    ) -> Result<Self, FrostError> {
+        for (i, (identifier, _)) in verifying_shares.iter().enumerate() {
+            if *identifier == 0 {
+                return Err(FrostError::InvalidIdentifier);
+            }
+            if verifying_shares[..i].iter().any(|(j, _)| j == identifier) {
+                return Err(FrostError::DuplicateIdentifier);
+            }
+        }
+        if min_signers < 2 || min_signers as usize > verifying_shares.len() {
+            return Err(FrostError::InvalidThreshold);
+        }
+
+        // Y = Σ λᵢ·Yᵢ over any `min_signers` participants.
+        let (identifiers, shares): (Vec<u16>, Vec<EdwardsPoint>) = verifying_shares
+            [..min_signers as usize]
+            .iter()
+            .copied()
+            .unzip();
+        let lambdas: Vec<Scalar> = identifiers
+            .iter()
+            .map(|&identifier| lagrange_coefficient(&identifiers, identifier))
+            .collect();
+        let interpolated = EdwardsPoint::vartime_multiscalar_mul(&shares, &lambdas);
+        if interpolated.to_bytes() != group_public.to_bytes() {
+            return Err(FrostError::InconsistentPublicKey);
+        }
+
+        Ok(Self {
+            verifying_shares: verifying_shares.to_vec(),
+            group_public,
+            min_signers,
+        })
+    }
+
+    /// Returns the verifying share of a participant, if any.
+    pub fn verifying_share(&self, identifier: u16) -> Option<EdwardsPoint> {
+        self.verifying_shares
+            .iter()
+            .find(|(i, _)| *i == identifier)
+            .map(|(_, share)| *share)
+    }
+
+    /// Returns the group public key.
+    pub fn group_public_key(&self) -> PublicKey {
+        self.group_public
+    }
+
+    /// Returns the number of signers required to sign.
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+/// Generates a new group key and splits it into `max_signers` shares.
+///
+/// # Parameters
+///
+/// - `min_signers`: Number of signers required to sign, at least 2
+/// - `max_signers`: Number of participants
+///
+/// # Returns
+///
+/// - `Ok((keys, public))` with one key package per participant,
+///   identified by `1..=max_signers`
+/// - `Err(InvalidThreshold)` if `min_signers` is out of range
+pub fn generate(
+    min_signers: u16,
+    max_signers: u16,
+) -> Result<(Vec<KeyPackage>, PublicKeyPackage), FrostError> {
+    split_scalar(Scalar::random(), min_signers, max_signers)
+}
+
+/// Splits an existing Ed25519 private key into `max_signers` shares.
+///
+/// The group public key is the public key of `private`.
+///
+/// # Parameters
+///
+/// - `private`: Key to split
+/// - `min_signers`: Number of signers required to sign, at least 2
+/// - `max_signers`: Number of participants
+///
+/// # Returns
+///
+/// - `Ok((keys, public))` with one key package per participant,
+///   identified by `1..=max_signers`
+/// - `Err(InvalidThreshold)` if `min_signers` is out of range
+pub fn split(
+    private: PrivateKey,
+    min_signers: u16,
+    max_signers: u16,
+) -> Result<(Vec<KeyPackage>, PublicKeyPackage), FrostError> {
+    split_scalar(private.scalar().reduced(), min_signers, max_signers)
+}
+
+/// Splits a secret with caller-provided polynomial coefficients
+/// (`secret_share_shard`, RFC 9591 Appendix C.1).
+///
+/// The threshold is `coefficients.len() + 1`.
+///
+/// # Security Notes
+///
+/// - The coefficients must be secret and uniformly random. Use
+///   [`split`] or [`generate`] unless they come from a trusted source,
+///   e.g. for test vectors.
+pub fn split_with_coefficients(
+    secret: &Scalar,
+    coefficients: &[Scalar],
+    max_signers: u16,
+) -> Result<(Vec<KeyPackage>, PublicKeyPackage), FrostError> {
+    let min_signers = coefficients.len() + 1;
+    if min_signers < 2 || min_signers > max_signers as usize {
+        return Err(FrostError::InvalidThreshold);
+    }
+    let min_signers = min_signers as u16;
+
+    let secret = secret.reduced();
+    let group_public = PublicKey::from_bytes(&EdwardsPoint::mul_base(&secret).to_bytes())
+        .expect("a multiple of the base point is a valid public key");
+
+    let keys: Vec<KeyPackage> = (1..=max_signers)
+        .map(|identifier| {
+            let x = identifier_to_scalar(identifier);
+
+            // Horner's rule, highest degree first.
+            let signing_share = coefficients
+                .iter()
+                .rev()
+                .fold(Scalar::ZERO, |acc, a| acc * x + *a)
+                * x
+                + secret;
+
+            KeyPackage {
+                identifier,
+                signing_share,
+                verifying_share: EdwardsPoint::mul_base(&signing_share),
+                group_public,
+                min_signers,
+            }
+        })
+        .collect();
+
+    let public = PublicKeyPackage {
+        verifying_shares: keys
+            .iter()
+            .map(|key| (key.identifier, key.verifying_share))
+            .collect(),
+        group_public,
+        min_signers,
+    };
+
+    Ok((keys, public))
+}
+
+/// Splits `secret` with random coefficients.
+fn split_scalar(
+    mut secret: Scalar,
+    min_signers: u16,
+    max_signers: u16,
+) -> Result<(Vec<KeyPackage>, PublicKeyPackage), FrostError> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(FrostError::InvalidThreshold);
+    }
+
+    let mut coefficients: Vec<Scalar> = (1..min_signers).map(|_| Scalar::random()).collect();
+    let result = split_with_coefficients(&secret, &coefficients, max_signers);
+
+    for a in &mut coefficients {
+        a.0.fill(0);
+    }
+    secret.0.fill(0);
+
+    result
+}
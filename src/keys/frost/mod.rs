@@ -0,0 +1,33 @@
+//! Threshold Ed25519 signatures.
+//!
+//! This module provides an implementation of FROST(Ed25519, SHA-512) as
+//! specified in RFC 9591, with trusted-dealer key generation. Any `t` of
+//! `n` participants jointly produce a standard Ed25519 signature under
+//! the group public key, without reconstructing the private key.
+//!
+//! The public API exposes key splitting, the two signing rounds, share
+//! verification and aggregation, and re-exports everything defined in
+//! the internal modules.
+//!
+//! ## Structure
+//!
+//! - `keygen`
+//!   Contains the trusted dealer, which splits a key into shares over
+//!   the Ed25519 scalar field, and the key packages held by participants
+//!   and coordinators.
+//!
+//! - `core`
+//!   Contains the nonce commitments, signature shares, binding factors
+//!   and aggregation, built on the Edwards25519 arithmetic of the
+//!   `ed25519` module.
+//!
+//! The separation mirrors the structure used in other cryptographic
+//! modules of the crate, keeping algorithmic details isolated while
+//! exposing a small, explicit interface.
+
+mod core;
+mod keygen;
+
+// Re-export the public API at the `frost` level.
+pub use core::*;
+pub use keygen::*;
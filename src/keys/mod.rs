@@ -40,5 +40,27 @@
 //! It provides constant-time scalar multiplication on Montgomery
 //! coordinates and is intended for shared-secret derivation and
 //! key exchange, not for signatures.
+//!
+//! ## Ristretto255
+//!
+//! The `ristretto255` module implements the prime-order group of
+//! RFC 9496 on top of the Edwards25519 arithmetic. It is intended as a
+//! building block for protocols (proofs, OPRFs, PAKEs) that need a group
+//! without a cofactor.
+//!
+//! ## VRF
+//!
+//! The `vrf` module implements the ECVRF-EDWARDS25519-SHA512 verifiable
+//! random function of RFC 9381 over Ed25519 keys, for verifiable
+//! pseudorandom outputs such as leader election or private lookups.
+//!
+//! ## FROST
+//!
+//! The `frost` module implements FROST(Ed25519, SHA-512) threshold
+//! signatures as specified in RFC 9591. Shares of an Ed25519 key sign
+//! together in two rounds and produce a regular Ed25519 signature.
 pub mod ed25519;
+pub mod frost;
+pub mod ristretto255;
+pub mod vrf;
 pub mod x25519;
@@ -0,0 +1,288 @@
+//! Ristretto255 points (RFC 9496).
+//!
+//! Edwards25519 has a cofactor of 8: besides the prime-order subgroup,
+//! the curve contains small-order points that protocols must otherwise
+//! detect and reject. Ristretto255 removes the cofactor by construction.
+//! Each group element is a class of Edwards points differing by a point
+//! of order 4, and:
+//!
+//! - every element has exactly one 32-byte encoding,
+//! - decoding accepts only canonical encodings of valid elements,
+//! - equality compares classes, not representatives.
+//!
+//! The result is a group of prime order
+//! `ℓ = 2²⁵² + 27742317777372353535851937790883648493`, the same order as
+//! the Ed25519 scalar field, so [`Scalar`] is reused as is.
+//!
+//! ## Design goals
+//!
+//! - Constant-time encoding, decoding, equality and scalar
+//!   multiplication
+//! - No access to the underlying Edwards representative
+//! - Interoperable with other RFC 9496 implementations
+//!
+//! ## Notes
+//!
+//! - Ristretto255 encodings are not Ed25519 public keys, and the two
+//!   must not be mixed.
+//! - Use [`RistrettoPoint::from_uniform_bytes`] (or
+//!   [`RistrettoPoint::hash_from_bytes`]) to derive elements with no
+//!   known discrete logarithm.
+
+use crate::hash::sha512;
+use crate::keys::ed25519::Scalar;
+use crate::keys::ed25519::ct::ConstantTimeEq;
+use crate::keys::ed25519::field::FieldElement;
+use crate::keys::ed25519::group::GeP3;
+use crate::keys::ed25519::table::{D, SQRTM1};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// `sqrt(a·d − 1)`, with `a = −1`.
+const SQRT_AD_MINUS_ONE: FieldElement = FieldElement([
+    24849947, 33400850, 43495378, 6347714, 46036536, 32887293, 41837720, 18186727, 66238516,
+    14525638,
+]);
+
+/// `1 / sqrt(a − d)`, with `a = −1`.
+const INVSQRT_A_MINUS_D: FieldElement = FieldElement([
+    6111466, 4156064, 39310137, 12243467, 41204824, 120896, 20826367, 26493656, 6093567, 31568420,
+]);
+
+/// `1 − d²`.
+const ONE_MINUS_D_SQ: FieldElement = FieldElement([
+    6275446, 16937061, 44170319, 29780721, 11667076, 7397348, 39186143, 1766194, 42675006, 672202,
+]);
+
+/// `(d − 1)²`.
+const D_MINUS_ONE_SQ: FieldElement = FieldElement([
+    15551776, 22456977, 53683765, 23429360, 55212328, 10178283, 40474537, 4729243, 61826754,
+    23438029,
+]);
+
+/// Errors that can occur while decoding a ristretto255 element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RistrettoError {
+    /// The bytes are not the canonical encoding of a group element.
+    InvalidEncoding,
+}
+
+/// An element of the ristretto255 group.
+///
+/// Internally, this is any Edwards25519 point of the element's class.
+/// Two values compare equal if they represent the same element, even
+/// when their representatives differ.
+#[derive(Clone, Copy)]
+pub struct RistrettoPoint(GeP3);
+
+impl RistrettoPoint {
+    /// Returns the identity element.
+    pub fn identity() -> Self {
+        Self(GeP3::ONE)
+    }
+
+    /// Returns the generator, the class of the Ed25519 base point.
+    pub fn generator() -> Self {
+        Self::mul_base(&Scalar::ONE)
+    }
+
+    /// Computes `scalar · G` with the precomputed base point table.
+    ///
+    /// The scalar is reduced modulo `ℓ` first. Runs in constant time.
+    pub fn mul_base(scalar: &Scalar) -> Self {
+        Self(GeP3::from_scalar_mul(scalar.reduced()))
+    }
+
+    /// Decodes an element from its 32-byte encoding (RFC 9496 §4.3.1).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(point)` if the bytes are a canonical encoding
+    /// - `Err(InvalidEncoding)` otherwise
+    ///
+    /// # Security Notes
+    ///
+    /// - Runs in constant time; only the final validity check branches.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, RistrettoError> {
+        let s = FieldElement::from_bytes(bytes);
+
+        // The encoding must be the canonical, non-negative field element.
+        let canonical = s.to_bytes().ct_eq(bytes);
+        let negative = s.is_negative();
+
+        let one = FieldElement::ONE;
+        let ss = s.square();
+        let u1 = one - ss;
+        let u2 = one + ss;
+        let u2_sqr = u2.square();
+
+        let v = -(D * u1.square()) - u2_sqr;
+        let (was_square, invsqrt) = FieldElement::sqrt_ratio_m1(&one, &(v * u2_sqr));
+
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+
+        let x = (s + s) * den_x;
+        let x = x.abs();
+        let y = u1 * den_y;
+        let t = x * y;
+
+        let valid = was_square & (t.is_negative() as u32 ^ 1) & (y.is_non_zero() as u32);
+
+        if !canonical || negative != 0 || valid == 0 {
+            return Err(RistrettoError::InvalidEncoding);
+        }
+
+        Ok(Self(GeP3 { x, y, z: one, t }))
+    }
+
+    /// Encodes this element into its canonical 32-byte form
+    /// (RFC 9496 §4.3.2).
+    ///
+    /// Runs in constant time.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let GeP3 { x, y, z, t } = self.0;
+
+        let u1 = (z + y) * (z - y);
+        let u2 = x * y;
+        let (_, invsqrt) = FieldElement::sqrt_ratio_m1(&FieldElement::ONE, &(u1 * u2.square()));
+
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * t;
+
+        let ix = x * SQRTM1;
+        let iy = y * SQRTM1;
+        let enchanted_denominator = den1 * INVSQRT_A_MINUS_D;
+
+        let rotate = (t * z_inv).is_negative() as u32;
+
+        let mut x = x;
+        let mut y = y;
+        let mut den_inv = den2;
+        x.conditional_move(&iy, rotate);
+        y.conditional_move(&ix, rotate);
+        den_inv.conditional_move(&enchanted_denominator, rotate);
+
+        y.conditional_negate((x * z_inv).is_negative() as u32);
+
+        (den_inv * (z - y)).abs().to_bytes()
+    }
+
+    /// Maps 64 uniformly random bytes to an element (RFC 9496 §4.3.4).
+    ///
+    /// Each half is mapped to a point with the ristretto255 Elligator
+    /// map, and the two points are added. The output is uniformly
+    /// distributed and has no known discrete logarithm.
+    ///
+    /// Runs in constant time.
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        let r0 = FieldElement::from_bytes(bytes[..32].try_into().unwrap());
+        let r1 = FieldElement::from_bytes(bytes[32..].try_into().unwrap());
+
+        Self(map_to_point(r0) + map_to_point(r1))
+    }
+
+    /// Hashes arbitrary input to an element, as
+    /// `from_uniform_bytes(SHA-512(input))`.
+    ///
+    /// Protocols should include a domain separation tag in `input`.
+    pub fn hash_from_bytes(input: &[u8]) -> Self {
+        Self::from_uniform_bytes(&sha512(input))
+    }
+
+    /// Returns `true` if this is the identity element.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+}
+
+impl PartialEq for RistrettoPoint {
+    /// Compares two elements in constant time (RFC 9496 §4.3.3).
+    fn eq(&self, other: &Self) -> bool {
+        let a = &self.0;
+        let b = &other.0;
+
+        let xy = (a.x * b.y).ct_eq(&(a.y * b.x));
+        let yy = (a.y * b.y).ct_eq(&(a.x * b.x));
+
+        (xy | yy) == 1
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+impl fmt::Debug for RistrettoPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RistrettoPoint")
+            .field(&self.to_bytes())
+            .finish()
+    }
+}
+
+impl Add for RistrettoPoint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for RistrettoPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Neg for RistrettoPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Scalar multiplication, in constant time.
+///
+/// The scalar is reduced modulo `ℓ` first.
+impl Mul<&Scalar> for RistrettoPoint {
+    type Output = Self;
+
+    fn mul(self, scalar: &Scalar) -> Self::Output {
+        Self(self.0.scalar_mul(scalar.reduced()))
+    }
+}
+
+/// The ristretto255 Elligator map `MAP` (RFC 9496 §4.3.4).
+fn map_to_point(t: FieldElement) -> GeP3 {
+    let one = FieldElement::ONE;
+
+    let r = SQRTM1 * t.square();
+    let u = (r + one) * ONE_MINUS_D_SQ;
+    let v = (-one - r * D) * (r + D);
+
+    let (was_square, mut s) = FieldElement::sqrt_ratio_m1(&u, &v);
+
+    let s_prime = -(s * t).abs();
+    s.conditional_move(&s_prime, was_square ^ 1);
+
+    let mut c = -one;
+    c.conditional_move(&r, was_square ^ 1);
+
+    let n = c * (r - one) * D_MINUS_ONE_SQ - v;
+
+    let w0 = (s + s) * v;
+    let w1 = n * SQRT_AD_MINUS_ONE;
+    let ss = s.square();
+    let w2 = one - ss;
+    let w3 = one + ss;
+
+    GeP3 {
+        x: w0 * w3,
+        y: w2 * w1,
+        z: w1 * w3,
+        t: w0 * w2,
+    }
+}
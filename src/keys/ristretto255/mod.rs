@@ -0,0 +1,24 @@
+//! Ristretto255 prime-order group.
+//!
+//! This module provides an implementation of the ristretto255 group as
+//! specified in RFC 9496.
+//!
+//! The public API exposes a single point type together with its
+//! canonical encoding, and re-exports everything defined in the internal
+//! `core` module.
+//!
+//! ## Structure
+//!
+//! - `core`
+//!   Contains the encoding and decoding procedures, equality, the
+//!   Elligator-based hash-to-group map and the group operations, all
+//!   built on the Edwards25519 arithmetic of the `ed25519` module.
+//!
+//! The separation mirrors the structure used in other cryptographic
+//! modules of the crate, keeping algorithmic details isolated while
+//! exposing a small, explicit interface.
+
+mod core;
+
+// Re-export the public API at the `ristretto255` level.
+pub use core::*;
@@ -0,0 +1,286 @@
+//! ECVRF-EDWARDS25519-SHA512 (RFC 9381).
+//!
+//! A verifiable random function maps an input `alpha` to an output
+//! `beta` that only the holder of a secret key can compute, together
+//! with a proof `pi` that anyone can check against the public key:
+//!
+//! ```text
+//! prove(SK, alpha)               → pi
+//! verify(PK, pi, alpha)          → beta   (or INVALID)
+//! proof_to_hash(pi)              → beta
+//! ```
+//!
+//! For a given key and input, `beta` is unique and looks random to
+//! anyone without the secret key, which makes it suitable for leader
+//! election and private lookups.
+//!
+//! Keys are regular Ed25519 keys: the VRF secret is the Ed25519 secret
+//! scalar and the VRF public key is the Ed25519 public key.
+//!
+//! ## Suites
+//!
+//! - [`Suite::Tai`], ECVRF-EDWARDS25519-SHA512-TAI: inputs are hashed to
+//!   the curve by try-and-increment, which is simple but not
+//!   constant-time in `alpha`.
+//! - [`Suite::Ell2`], ECVRF-EDWARDS25519-SHA512-ELL2: inputs are hashed
+//!   with the RFC 9380 `encode_to_curve` map, in constant time.
+//!
+//! The two suites produce unrelated outputs for the same key and input.
+//!
+//! ## Notes
+//!
+//! - Verification always validates the public key (`validate_key =
+//!   TRUE`), rejecting keys of small order.
+//! - `proof_to_hash` does not verify the proof; use the output of
+//!   [`verify`] unless the proof is already known to be valid.
+
+use crate::hash::Sha512;
+use crate::keys::ed25519::ct::ConstantTimeEq;
+use crate::keys::ed25519::{EdwardsPoint, PrivateKey, PublicKey, Scalar, encode_to_curve};
+
+/// Length of a proof, in bytes.
+pub const PROOF_LEN: usize = 80;
+
+/// Length of a VRF output, in bytes.
+pub const OUTPUT_LEN: usize = 64;
+
+/// Length of the challenge `c`, in bytes.
+const CHALLENGE_LEN: usize = 16;
+
+/// Domain separator of `encode_to_curve`.
+const ENCODE_TO_CURVE_FRONT: u8 = 0x01;
+
+/// Domain separator of the challenge hash.
+const CHALLENGE_FRONT: u8 = 0x02;
+
+/// Domain separator of `proof_to_hash`.
+const PROOF_TO_HASH_FRONT: u8 = 0x03;
+
+/// Trailing domain separator of all hashes.
+const BACK: u8 = 0x00;
+
+/// Errors that can occur while verifying a proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VrfError {
+    /// The public key is not a valid point, or has small order.
+    InvalidPublicKey,
+    /// The proof is malformed or does not verify.
+    InvalidProof,
+}
+
+/// ECVRF cipher suites over edwards25519.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suite {
+    /// ECVRF-EDWARDS25519-SHA512-TAI (`suite_string = 0x03`).
+    Tai,
+    /// ECVRF-EDWARDS25519-SHA512-ELL2 (`suite_string = 0x04`).
+    Ell2,
+}
+
+impl Suite {
+    /// Returns the one-byte `suite_string`.
+    fn id(self) -> u8 {
+        match self {
+            Suite::Tai => 0x03,
+            Suite::Ell2 => 0x04,
+        }
+    }
+}
+
+/// A VRF proof `pi = Gamma || c || s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Proof([u8; PROOF_LEN]);
+
+impl Proof {
+    /// Constructs a proof from its 80-byte encoding.
+    ///
+    /// No validation is performed at construction time; malformed proofs
+    /// are rejected by [`verify`] and [`proof_to_hash`].
+    pub fn from_bytes(bytes: &[u8; PROOF_LEN]) -> Self {
+        Self(*bytes)
+    }
+
+    /// Returns the 80-byte encoding of this proof.
+    pub fn to_bytes(&self) -> [u8; PROOF_LEN] {
+        self.0
+    }
+
+    /// Decodes `(Gamma, c, s)` (RFC 9381 §5.4.4).
+    fn decode(&self) -> Result<(EdwardsPoint, Scalar, Scalar), VrfError> {
+        let gamma = EdwardsPoint::from_bytes(self.0[..32].try_into().unwrap())
+            .map_err(|_| VrfError::InvalidProof)?;
+
+        let mut c = [0u8; 32];
+        c[..CHALLENGE_LEN].copy_from_slice(&self.0[32..32 + CHALLENGE_LEN]);
+
+        let s = Scalar::from_canonical_bytes(self.0[48..].try_into().unwrap())
+            .map_err(|_| VrfError::InvalidProof)?;
+
+        Ok((gamma, Scalar(c), s))
+    }
+}
+
+/// Computes a VRF proof for `alpha` (RFC 9381 §5.1).
+///
+/// # Parameters
+///
+/// - `suite`: Cipher suite
+/// - `alpha`: VRF input
+/// - `private`: Prover's Ed25519 private key
+///
+/// # Returns
+///
+/// The proof `pi`. The VRF output is [`proof_to_hash`] of the proof.
+///
+/// # Security Notes
+///
+/// - Proving is deterministic: the nonce is derived from the private
+///   key prefix and the hashed input, as in Ed25519 signing.
+/// - With [`Suite::Tai`], the running time depends on `alpha`.
+pub fn prove(suite: Suite, alpha: &[u8], private: PrivateKey) -> Proof {
+    let x = private.scalar();
+    let public = EdwardsPoint::mul_base(&x);
+    let public_bytes = public.to_bytes();
+
+    let h = hash_to_curve(suite, &public_bytes, alpha);
+    let h_bytes = h.to_bytes();
+    let gamma = h * &x;
+
+    // k = SHA-512(prefix || h_string) mod q
+    let mut hasher = Sha512::new();
+    hasher.update(&private.prefix());
+    hasher.update(&h_bytes);
+    let k = Scalar::reduce(hasher.finalize());
+
+    let c = challenge(
+        suite,
+        &[public, h, gamma, EdwardsPoint::mul_base(&k), h * &k],
+    );
+    let s = k + c * x;
+
+    let mut pi = [0u8; PROOF_LEN];
+    pi[..32].copy_from_slice(&gamma.to_bytes());
+    pi[32..48].copy_from_slice(&c.to_bytes()[..CHALLENGE_LEN]);
+    pi[48..].copy_from_slice(&s.to_bytes());
+
+    Proof(pi)
+}
+
+/// Verifies a VRF proof and returns the VRF output (RFC 9381 §5.3).
+///
+/// # Parameters
+///
+/// - `suite`: Cipher suite
+/// - `public`: Prover's Ed25519 public key
+/// - `proof`: Proof to verify
+/// - `alpha`: VRF input
+///
+/// # Returns
+///
+/// - `Ok(beta)` with the 64-byte VRF output if the proof is valid
+/// - `Err(InvalidPublicKey)` if the key does not decode or has small
+///   order
+/// - `Err(InvalidProof)` otherwise
+pub fn verify(
+    suite: Suite,
+    public: PublicKey,
+    proof: &Proof,
+    alpha: &[u8],
+) -> Result<[u8; OUTPUT_LEN], VrfError> {
+    let public_bytes = public.to_bytes();
+    let y = EdwardsPoint::from_bytes(&public_bytes).map_err(|_| VrfError::InvalidPublicKey)?;
+    if y.is_small_order() {
+        return Err(VrfError::InvalidPublicKey);
+    }
+
+    let (gamma, c, s) = proof.decode()?;
+    let h = hash_to_curve(suite, &public_bytes, alpha);
+
+    // U = s·B − c·Y, V = s·H − c·Gamma
+    let u = EdwardsPoint::vartime_multiscalar_mul(&[EdwardsPoint::generator(), y], &[s, -c]);
+    let v = EdwardsPoint::vartime_multiscalar_mul(&[h, gamma], &[s, -c]);
+
+    let expected = challenge(suite, &[y, h, gamma, u, v]);
+    if !expected.to_bytes().ct_eq(&c.to_bytes()) {
+        return Err(VrfError::InvalidProof);
+    }
+
+    Ok(gamma_to_hash(suite, gamma))
+}
+
+/// Computes the VRF output of a proof (RFC 9381 §5.2).
+///
+/// The proof is not verified; only its `Gamma` component is decoded.
+///
+/// # Returns
+///
+/// - `Ok(beta)` with the 64-byte VRF output
+/// - `Err(InvalidProof)` if the proof is malformed
+pub fn proof_to_hash(suite: Suite, proof: &Proof) -> Result<[u8; OUTPUT_LEN], VrfError> {
+    let (gamma, _, _) = proof.decode()?;
+    Ok(gamma_to_hash(suite, gamma))
+}
+
+/// `beta = SHA-512(suite_string || 0x03 || cofactor·Gamma || 0x00)`.
+fn gamma_to_hash(suite: Suite, gamma: EdwardsPoint) -> [u8; OUTPUT_LEN] {
+    let mut hasher = Sha512::new();
+    hasher.update(&[suite.id(), PROOF_TO_HASH_FRONT]);
+    hasher.update(&gamma.mul_by_cofactor().to_bytes());
+    hasher.update(&[BACK]);
+
+    hasher.finalize()
+}
+
+/// Hashes the input to a point of the prime-order subgroup
+/// (RFC 9381 §5.4.1), with the public key as salt.
+fn hash_to_curve(suite: Suite, salt: &[u8; 32], alpha: &[u8]) -> EdwardsPoint {
+    match suite {
+        Suite::Tai => try_and_increment(salt, alpha),
+        Suite::Ell2 => {
+            let mut dst = b"ECVRF_edwards25519_XMD:SHA-512_ELL2_NU_".to_vec();
+            dst.push(suite.id());
+
+            let mut msg = Vec::with_capacity(salt.len() + alpha.len());
+            msg.extend_from_slice(salt);
+            msg.extend_from_slice(alpha);
+
+            encode_to_curve(&msg, &dst).expect("the DST is not empty")
+        }
+    }
+}
+
+/// `ECVRF_encode_to_curve_try_and_increment` (RFC 9381 §5.4.1.1).
+///
+/// Each attempt succeeds with probability about 1/2; 256 failed
+/// attempts in a row never happen in practice.
+fn try_and_increment(salt: &[u8; 32], alpha: &[u8]) -> EdwardsPoint {
+    (0..=255u8)
+        .find_map(|ctr| {
+            let mut hasher = Sha512::new();
+            hasher.update(&[Suite::Tai.id(), ENCODE_TO_CURVE_FRONT]);
+            hasher.update(salt);
+            hasher.update(alpha);
+            hasher.update(&[ctr, BACK]);
+            let digest = hasher.finalize();
+
+            EdwardsPoint::from_bytes(digest[..32].try_into().unwrap()).ok()
+        })
+        .expect("try-and-increment found no point")
+        .mul_by_cofactor()
+}
+
+/// `ECVRF_challenge_generation` (RFC 9381 §5.4.3).
+fn challenge(suite: Suite, points: &[EdwardsPoint; 5]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(&[suite.id(), CHALLENGE_FRONT]);
+    for point in points {
+        hasher.update(&point.to_bytes());
+    }
+    hasher.update(&[BACK]);
+    let digest = hasher.finalize();
+
+    let mut c = [0u8; 32];
+    c[..CHALLENGE_LEN].copy_from_slice(&digest[..CHALLENGE_LEN]);
+
+    Scalar(c)
+}
@@ -0,0 +1,24 @@
+//! Verifiable random functions.
+//!
+//! This module provides an implementation of ECVRF-EDWARDS25519-SHA512
+//! as specified in RFC 9381, with both the TAI and ELL2 suites.
+//!
+//! The public API exposes proving, verification and proof-to-hash
+//! functions over Ed25519 keys, and re-exports everything defined in the
+//! internal `core` module.
+//!
+//! ## Structure
+//!
+//! - `core`
+//!   Contains the proof generation and verification procedures, the two
+//!   hash-to-curve methods and the challenge computation, built on the
+//!   Edwards25519 arithmetic of the `ed25519` module.
+//!
+//! The separation mirrors the structure used in other cryptographic
+//! modules of the crate, keeping algorithmic details isolated while
+//! exposing a small, explicit interface.
+
+mod core;
+
+// Re-export the public API at the `vrf` level.
+pub use core::*;
@@ -1,26 +1,170 @@
+use crate::keys::ed25519::ct::ConstantTimeEq;
 use crate::keys::ed25519::field::FieldElement;
+use crate::keys::ed25519::group::GeP3;
+use crate::keys::ed25519::scalar::Scalar;
+use crate::rng::Csprng;
 
-/// X25519 key exchange (RFC 7748 compatible).
+/// Montgomery u-coordinate of the Curve25519 base point (`u = 9`).
+pub const BASEPOINT: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// Encodings of the small-order u-coordinates.
+///
+/// The eight points of the Curve25519 torsion subgroup (orders 1, 2, 4
+/// and 8), together with the order-4 point of the quadratic twist, have
+/// five distinct u-coordinates. With the non-canonical encodings of `0`
+/// and `1`, they give the seven inputs below (top bit clear). For any
+/// secret, [`x25519`] maps all of them to the all-zero output, which the
+/// checked functions reject.
+///
+/// The point at infinity has no u-coordinate; the Edwards identity maps
+/// to `u = 0` in [`exchange`].
+pub const LOW_ORDER_POINTS: [[u8; 32]; 7] = [
+    // `0`: order 2 (on the curve and its twist)
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    // `1`: order 4
+    [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    // order 8
+    [
+        0xe0, 0xeb, 0x7a, 0x7c, 0x3b, 0x41, 0xb8, 0xae, 0x16, 0x56, 0xe3, 0xfa, 0xf1, 0x9f, 0xc4,
+        0x6a, 0xda, 0x09, 0x8d, 0xeb, 0x9c, 0x32, 0xb1, 0xfd, 0x86, 0x62, 0x05, 0x16, 0x5f, 0x49,
+        0xb8, 0x00,
+    ],
+    // order 8
+    [
+        0x5f, 0x9c, 0x95, 0xbc, 0xa3, 0x50, 0x8c, 0x24, 0xb1, 0xd0, 0xb1, 0x55, 0x9c, 0x83, 0xef,
+        0x5b, 0x04, 0x44, 0x5c, 0xc4, 0x58, 0x1c, 0x8e, 0x86, 0xd8, 0x22, 0x4e, 0xdd, 0xd0, 0x9f,
+        0x11, 0x57,
+    ],
+    // `p - 1`: order 4 on the twist
+    [
+        0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+    // `p`: non-canonical encoding of `0`
+    [
+        0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+    // `p + 1`: non-canonical encoding of `1`
+    [
+        0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+];
+
+/// Errors that can occur during a checked key exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum X25519Error {
+    /// The shared secret is all zeros: the peer key has small order, so
+    /// the result does not depend on the local secret.
+    NonContributory,
+}
+
+/// The X25519 function (RFC 7748, Section 5).
+///
+/// Computes the scalar multiple of a Curve25519 point given by its
+/// Montgomery u-coordinate.
+///
+/// ## Inputs
+///
+/// - `scalar`: 32-byte secret scalar. It is clamped as specified by
+///   RFC 7748 before scalar multiplication.
+/// - `u`: 32-byte little-endian Montgomery u-coordinate. The most
+///   significant bit is ignored.
+///
+/// ## Return value
+///
+/// Returns the u-coordinate of `scalar · P` as 32 bytes.
+///
+/// **Note:** per RFC 7748, the output may be all zeros for certain inputs
+/// (e.g. low-order points). This function returns that value as-is; use
+/// [`checked_x25519`] to reject it.
+///
+/// ## Security
+///
+/// - Constant-time with respect to the scalar.
+/// - No secret-dependent branches.
+pub fn x25519(scalar: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    ladder(scalar, FieldElement::from_bytes(u))
+}
+
+/// The X25519 function, rejecting non-contributory results.
+///
+/// Computes [`x25519`] and checks that the output is not all zeros, as
+/// recommended by RFC 7748 §6.1.
+///
+/// ## Return value
+///
+/// - `Ok(shared)` on success
+/// - `Err(NonContributory)` if `u` is one of [`LOW_ORDER_POINTS`] (or
+///   any other small-order encoding)
+///
+/// ## Security
+///
+/// - The all-zero check runs in constant time over the whole output.
+pub fn checked_x25519(scalar: &[u8; 32], u: &[u8; 32]) -> Result<SharedSecret, X25519Error> {
+    contributory(x25519(scalar, u))
+}
+
+/// Derives the X25519 public u-coordinate of a secret scalar.
+///
+/// This is `x25519(scalar, BASEPOINT)`, computed on the Edwards curve
+/// instead of with the Montgomery ladder.
+///
+/// ## Algorithm
+///
+/// 1. Clamp the scalar as specified by RFC 7748.
+/// 2. Compute `k · B` with the precomputed Ed25519 base point table.
+/// 3. Map the Edwards point to Montgomery form with
+///    `u = (Z + Y) / (Z - Y)`.
+///
+/// The Ed25519 base point maps to `u = 9`, so both computations give the
+/// same result; the table-based one is several times faster.
+///
+/// ## Security
+///
+/// - Constant-time with respect to the scalar.
+/// - `Z - Y` is never zero: a clamped scalar is never a multiple of the
+///   group order, so `k · B` is never the identity.
+pub fn x25519_base(scalar: &[u8; 32]) -> [u8; 32] {
+    let mut e = clamp(scalar);
+    let point = GeP3::from_scalar_mul(Scalar(e));
+    e.fill(0);
+
+    // Birational map from Edwards to Montgomery: u = (1 + y) / (1 - y)
+    ((point.z + point.y) * (point.z - point.y).invert()).to_bytes()
+}
+
+/// X25519 key exchange with an Ed25519-encoded peer key.
 ///
 /// Computes the Diffie–Hellman shared secret between a private scalar
-/// and a peer public u-coordinate using Curve25519.
+/// and a peer public key given in Ed25519 (Edwards `y`) encoding.
 ///
 /// ## Inputs
 ///
 /// - `private`: Local secret key material. The scalar is clamped as specified
 ///   by RFC 7748 before scalar multiplication.
-/// - `public`: Peer public key as a 32-byte Montgomery u-coordinate.
+/// - `public`: Peer public key as a 32-byte Edwards `y` coordinate, as
+///   produced by Ed25519 key generation.
 ///
 /// ## Algorithm
 ///
-/// 1. Clamp the 32-byte scalar (`k`) into the RFC 7748 form.
-/// 2. Decode the peer public key as a field element `u`.
-/// 3. Run the Montgomery ladder for 255 bits using constant-time swaps.
-/// 4. Convert back to affine form with one inversion and return the result.
-///
-/// The ladder maintains two points `(x2:z2)` and `(x3:z3)` and updates them
-/// at each bit position to compute the scalar multiple without leaking
-/// scalar bits through branches or memory access patterns.
+/// 1. Decode the peer public key as a field element `y`.
+/// 2. Map it to the Montgomery form with `u = (1 + y) / (1 - y)`.
+/// 3. Run the X25519 Montgomery ladder (see [`x25519`]).
 ///
 /// ## Return value
 ///
@@ -28,7 +172,8 @@ use crate::keys::ed25519::field::FieldElement;
 /// resulting scalar multiple).
 ///
 /// **Note:** per RFC 7748, the output may be all zeros for certain peer public
-/// keys (e.g. low-order points). This function returns that value as-is.
+/// keys (e.g. low-order points). This function returns that value as-is; use
+/// [`checked_exchange`] to reject it.
 ///
 /// ## Security
 ///
@@ -36,18 +181,215 @@ use crate::keys::ed25519::field::FieldElement;
 /// - No secret-dependent branches.
 /// - Peer public keys are not fully validated (matches X25519 semantics).
 pub fn exchange(private: &[u8; 32], public: &[u8; 32]) -> [u8; 32] {
-    let mut e = *private;
-    e[0] &= 248;
-    e[31] &= 127;
-    e[31] |= 64;
-
-    let mut x1 = FieldElement::from_bytes(public);
+    let y = FieldElement::from_bytes(public);
 
-    // RFC 7748 decoding: u = (u + 1) / (1 - u)
+    // Birational map from Edwards to Montgomery: u = (1 + y) / (1 - y)
     let one = FieldElement::ONE;
-    let tmp0 = x1 + one;
-    let tmp1 = (one - x1).invert();
-    x1 = tmp0 * tmp1;
+    let u = (one + y) * (one - y).invert();
+
+    ladder(private, u)
+}
+
+/// X25519 key exchange with an Ed25519-encoded peer key, rejecting
+/// non-contributory results.
+///
+/// This is [`exchange`] followed by the all-zero check of
+/// [`checked_x25519`]. Small-order Edwards points, including the
+/// identity, are rejected.
+///
+/// ## Return value
+///
+/// - `Ok(shared)` on success
+/// - `Err(NonContributory)` if the peer key has small order
+pub fn checked_exchange(
+    private: &[u8; 32],
+    public: &[u8; 32],
+) -> Result<SharedSecret, X25519Error> {
+    contributory(exchange(private, public))
+}
+
+/// An X25519 public key.
+///
+/// This type wraps the 32-byte little-endian Montgomery u-coordinate of
+/// a Curve25519 point. Any 32 bytes are accepted, as in RFC 7748.
+///
+/// A `PublicKey` is immutable and copyable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey([u8; 32]);
+
+impl PublicKey {
+    /// Constructs a public key from its 32-byte encoding.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(*bytes)
+    }
+
+    /// Returns the byte encoding of this public key.
+    pub fn to_bytes(self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns a reference to the byte encoding of this public key.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// A long-term X25519 secret key.
+///
+/// The secret can be serialized and reused for any number of key
+/// exchanges. Prefer [`EphemeralSecret`] when a key is used only once.
+///
+/// The scalar is stored unclamped, as received; clamping is applied
+/// on every use.
+///
+/// # Security Notes
+///
+/// - The secret is wiped from memory on drop.
+pub struct StaticSecret([u8; 32]);
+
+impl StaticSecret {
+    /// Generates a secret from the operating system CSPRNG.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        Csprng::new().fill_bytes(&mut bytes);
+
+        Self(bytes)
+    }
+
+    /// Constructs a secret from 32 bytes.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(*bytes)
+    }
+
+    /// Returns the 32 secret bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Derives the public key for this secret.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(x25519_base(&self.0))
+    }
+
+    /// Computes the shared secret with a peer public key.
+    ///
+    /// **Note:** the result is all zeros for small-order peer keys; see
+    /// [`checked_diffie_hellman`](Self::checked_diffie_hellman).
+    pub fn diffie_hellman(&self, public: &PublicKey) -> SharedSecret {
+        SharedSecret(x25519(&self.0, &public.0))
+    }
+
+    /// Computes the shared secret with a peer public key, rejecting
+    /// small-order peer keys.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(shared)` on success
+    /// - `Err(NonContributory)` if the shared secret is all zeros
+    pub fn checked_diffie_hellman(&self, public: &PublicKey) -> Result<SharedSecret, X25519Error> {
+        checked_x25519(&self.0, &public.0)
+    }
+}
+
+impl Drop for StaticSecret {
+    fn drop(&mut self) {
+        self.0.fill(0);
+    }
+}
+
+/// A single-use X25519 secret key.
+///
+/// The secret cannot be serialized, and
+/// [`diffie_hellman`](Self::diffie_hellman) consumes it, so it is used
+/// for at most one key exchange.
+///
+/// # Security Notes
+///
+/// - The secret is wiped from memory on drop.
+pub struct EphemeralSecret(pub(super) [u8; 32]);
+
+impl EphemeralSecret {
+    /// Generates a secret from the operating system CSPRNG.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        Csprng::new().fill_bytes(&mut bytes);
+
+        Self(bytes)
+    }
+
+    /// Derives the public key for this secret.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(x25519_base(&self.0))
+    }
+
+    /// Computes the shared secret with a peer public key, consuming the
+    /// secret.
+    ///
+    /// **Note:** the result is all zeros for small-order peer keys; see
+    /// [`checked_diffie_hellman`](Self::checked_diffie_hellman).
+    pub fn diffie_hellman(self, public: &PublicKey) -> SharedSecret {
+        SharedSecret(x25519(&self.0, &public.0))
+    }
+
+    /// Computes the shared secret with a peer public key, consuming the
+    /// secret and rejecting small-order peer keys.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(shared)` on success
+    /// - `Err(NonContributory)` if the shared secret is all zeros
+    pub fn checked_diffie_hellman(self, public: &PublicKey) -> Result<SharedSecret, X25519Error> {
+        checked_x25519(&self.0, &public.0)
+    }
+}
+
+impl Drop for EphemeralSecret {
+    fn drop(&mut self) {
+        self.0.fill(0);
+    }
+}
+
+/// The result of an X25519 key exchange.
+///
+/// The raw output should not be used as a key directly; pass it through
+/// a KDF such as HKDF together with both public keys.
+///
+/// # Security Notes
+///
+/// - The secret is wiped from memory on drop.
+pub struct SharedSecret([u8; 32]);
+
+impl SharedSecret {
+    /// Returns the 32 shared secret bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns a reference to the shared secret bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.0.fill(0);
+    }
+}
+
+/// Montgomery ladder for Curve25519.
+///
+/// ## Algorithm
+///
+/// 1. Clamp the 32-byte scalar (`k`) into the RFC 7748 form.
+/// 2. Run the Montgomery ladder for 255 bits using constant-time swaps.
+/// 3. Convert back to affine form with one inversion and return the result.
+///
+/// The ladder maintains two points `(x2:z2)` and `(x3:z3)` and updates them
+/// at each bit position to compute the scalar multiple without leaking
+/// scalar bits through branches or memory access patterns.
+fn ladder(scalar: &[u8; 32], x1: FieldElement) -> [u8; 32] {
+    let mut e = clamp(scalar);
 
     // Montgomery ladder ---
     let mut x2 = FieldElement::ONE;
@@ -82,7 +424,7 @@ pub fn exchange(private: &[u8; 32], public: &[u8; 32]) -> [u8; 32] {
 
         let tmp1 = tmp1 - tmp0;
         z2 = z2.square();
-        let mut z3 = tmp1.mul121666();
+        z3 = tmp1.mul121666();
         x3 = x3.square();
         let tmp0 = tmp0 + z3;
 
@@ -93,5 +435,31 @@ pub fn exchange(private: &[u8; 32], public: &[u8; 32]) -> [u8; 32] {
     x2.swap(&mut x3, swap);
     z2.swap(&mut z3, swap);
 
+    e.fill(0);
+
     (x2 * z2.invert()).to_bytes()
 }
+
+/// Wraps a key exchange output, rejecting the all-zero value in
+/// constant time.
+fn contributory(output: [u8; 32]) -> Result<SharedSecret, X25519Error> {
+    let shared = SharedSecret(output);
+
+    if shared.0.ct_eq(&[0u8; 32]) {
+        return Err(X25519Error::NonContributory);
+    }
+
+    Ok(shared)
+}
+
+/// Clamps a 32-byte scalar into the RFC 7748 form: the three low bits
+/// and the top bit cleared, bit 254 set.
+#[inline(always)]
+pub(super) fn clamp(scalar: &[u8; 32]) -> [u8; 32] {
+    let mut e = *scalar;
+    e[0] &= 248;
+    e[31] &= 127;
+    e[31] |= 64;
+
+    e
+}
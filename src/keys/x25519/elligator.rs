@@ -0,0 +1,188 @@
+//! Elligator 2 representatives for X25519 public keys.
+//!
+//! An X25519 public key is a curve25519 u-coordinate. Only about half of
+//! the field elements are valid u-coordinates, so an observer can tell
+//! public keys apart from random bytes. Elligator 2 fixes this: about
+//! half of the public keys have a *representative*, a 254-bit string
+//! that maps back to the key and is indistinguishable from random:
+//!
+//! ```text
+//! sender:    generate_representable() → (secret, public, representative)
+//!            send representative (with two random high bits)
+//! receiver:  representative.to_public_key() → public
+//! ```
+//!
+//! ## Design goals
+//!
+//! - Every 32-byte string is a valid representative
+//! - Representatives of generated keys are uniformly distributed
+//! - Shared secrets are the same as with regular X25519 keys
+//!
+//! ## Notes
+//!
+//! - Regular public keys are multiples of the base point, and stay
+//!   distinguishable even when encoded. Representable public keys also
+//!   carry a random low-order component, selected by the three low bits
+//!   of the secret. Clamping removes it on the peer side, so the shared
+//!   secret is unchanged.
+//! - As a consequence, the public key returned with a representative
+//!   differs from [`StaticSecret::public_key`]. Use the returned key,
+//!   which is the one the peer decodes, wherever the protocol binds
+//!   public keys (e.g. in a transcript hash).
+//! - Key generation retries until the key is representable, about two
+//!   attempts on average. The number of attempts does not depend on the
+//!   final key.
+
+use super::core::{EphemeralSecret, PublicKey, StaticSecret, clamp};
+use crate::keys::ed25519::field::FieldElement;
+use crate::keys::ed25519::group::GeP3;
+use crate::keys::ed25519::hash_to_curve::{MONTGOMERY_A, map_to_curve25519};
+use crate::keys::ed25519::scalar::Scalar;
+use crate::rng::Csprng;
+
+/// Encoding of an Edwards25519 point of order 8.
+const EIGHT_TORSION: [u8; 32] = [
+    0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10, 0x67, 0x0f,
+    0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77, 0x92, 0xac, 0x03, 0x7a,
+];
+
+/// An Elligator 2 representative of an X25519 public key.
+///
+/// The low 254 bits encode the key; the two high bits are ignored when
+/// decoding and are random in generated representatives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Representative([u8; 32]);
+
+impl Representative {
+    /// Constructs a representative from 32 bytes.
+    ///
+    /// Any 32-byte string is a valid representative.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self(*bytes)
+    }
+
+    /// Returns the representative as bytes.
+    pub fn to_bytes(self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns a reference to the representative bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Decodes the public key encoded by this representative.
+    ///
+    /// Runs in constant time.
+    pub fn to_public_key(&self) -> PublicKey {
+        let mut bytes = self.0;
+        bytes[31] &= 0x3f;
+
+        let (u, _) = map_to_curve25519(FieldElement::from_bytes(&bytes));
+        PublicKey::from_bytes(&u.to_bytes())
+    }
+}
+
+impl StaticSecret {
+    /// Generates a secret whose public key has an Elligator 2
+    /// representative.
+    ///
+    /// # Returns
+    ///
+    /// `(secret, public, representative)`, where `public` is the key
+    /// encoded by `representative`.
+    pub fn generate_representable() -> (Self, PublicKey, Representative) {
+        let (secret, public, representative) = generate();
+        (Self::from_bytes(&secret), public, representative)
+    }
+}
+
+impl EphemeralSecret {
+    /// Generates a single-use secret whose public key has an Elligator 2
+    /// representative.
+    ///
+    /// # Returns
+    ///
+    /// `(secret, public, representative)`, where `public` is the key
+    /// encoded by `representative`.
+    pub fn generate_representable() -> (Self, PublicKey, Representative) {
+        let (secret, public, representative) = generate();
+        (Self(secret), public, representative)
+    }
+}
+
+/// Draws random secrets until the public key is representable.
+fn generate() -> ([u8; 32], PublicKey, Representative) {
+    let mut rng = Csprng::new();
+
+    loop {
+        let mut secret = [0u8; 32];
+        let mut tweak = [0u8; 1];
+        rng.fill_bytes(&mut secret);
+        rng.fill_bytes(&mut tweak);
+
+        let u = public_key_with_torsion(&secret);
+
+        if let Some(representative) = representative(&u, tweak[0]) {
+            return (
+                secret,
+                PublicKey::from_bytes(&u.to_bytes()),
+                Representative(representative),
+            );
+        }
+
+        secret.fill(0);
+    }
+}
+
+/// Computes the u-coordinate of `clamp(secret) · B + (secret mod 8) · T`,
+/// with `T` a point of order 8.
+///
+/// Runs in constant time.
+fn public_key_with_torsion(secret: &[u8; 32]) -> FieldElement {
+    let mut e = clamp(secret);
+    let point = GeP3::from_scalar_mul(Scalar(e));
+    e.fill(0);
+
+    let (torsion, _) = GeP3::decompress(&EIGHT_TORSION);
+    let mut t = [0u8; 32];
+    t[0] = secret[0] & 7;
+    let point = point + torsion.scalar_mul(Scalar(t));
+    t.fill(0);
+
+    // Birational map from Edwards to Montgomery: u = (1 + y) / (1 - y)
+    (point.z + point.y) * (point.z - point.y).invert()
+}
+
+/// Computes a representative of `u`, the inverse of the Elligator 2 map.
+///
+/// `u` has a representative if and only if `u ≠ −A` and `−2u(u + A)` is
+/// a square. The low bit of `tweak` selects one of the two preimages,
+/// and its two high bits become the high bits of the output.
+///
+/// # Returns
+///
+/// - `Some(bytes)` with a representative in `[0, (p − 1) / 2]`
+/// - `None` if `u` is not representable
+fn representative(u: &FieldElement, tweak: u8) -> Option<[u8; 32]> {
+    let u_plus_a = *u + MONTGOMERY_A;
+    let t = *u * u_plus_a;
+    let (is_square, inv_sqrt) = FieldElement::sqrt_ratio_m1(&FieldElement::ONE, &-(t + t));
+
+    // r = sqrt(-u / (2(u + A))) or sqrt(-(u + A) / (2u))
+    let mut numerator = *u;
+    numerator.conditional_move(&u_plus_a, (tweak & 1) as u32);
+    let mut r = numerator * inv_sqrt;
+
+    // Pick the root in [0, (p - 1) / 2], so the top two bits are free.
+    r.conditional_negate((r + r).is_negative() as u32);
+
+    if is_square == 0 {
+        return None;
+    }
+
+    let mut bytes = r.to_bytes();
+    bytes[31] |= tweak & 0xc0;
+
+    Some(bytes)
+}
@@ -3,20 +3,28 @@
 //! This module provides an implementation of the X25519 Diffie–Hellman
 //! key exchange as specified in RFC 7748.
 //!
-//! The public API is intentionally minimal and re-exports the high-level
-//! key exchange function defined in the internal `core` module.
+//! The public API is intentionally minimal and re-exports the key types
+//! (`StaticSecret`, `EphemeralSecret`, `PublicKey`, `SharedSecret`),
+//! the raw `x25519` function defined in the internal `core` module, and
+//! the `Representative` encoding of public keys.
 //!
 //! ## Structure
 //!
 //! - `core`  
 //!   Contains the full X25519 implementation, including scalar clamping,
-//!   Montgomery ladder arithmetic, and field operations.
+//!   Montgomery ladder arithmetic, fixed-base public key derivation on
+//!   the Edwards curve, and the typed key objects.
+//! - `elligator`  
+//!   Elligator 2 representatives, which encode public keys as strings
+//!   indistinguishable from random bytes.
 //!
 //! The separation mirrors the structure used in other cryptographic
 //! modules of the crate, keeping algorithmic details isolated while
 //! exposing a small, explicit interface.
 
 mod core;
+mod elligator;
 
 // Re-export the public API at the `x25519` level.
 pub use core::*;
+pub use elligator::*;
@@ -13,6 +13,13 @@
 //! It only generates a single 64-byte ChaCha20 keystream block.
 //! Higher-level constructions (such as ChaCha20-Poly1305) must be built
 //! on top of this primitive with strict nonce and key management.
+//!
+//! It also provides the Salsa20 family, ChaCha20's predecessor, which is
+//! still required for compatibility:
+//! - Salsa20 (64-bit nonce, 64-bit counter)
+//! - HSalsa20, which derives a subkey from a key and a 128-bit input
+//! - XSalsa20 (192-bit nonce), used by NaCl/libsodium `secretbox` and `box`
+//! - the reduced-round core used by scrypt's `BlockMix`
 
 /// ChaCha20 constant words.
 ///
@@ -179,3 +186,184 @@ pub(crate) fn xor(key: &[u8; 32], nonce: &[u8; 12], counter: u32, input: &[u8],
         offset += take;
     }
 }
+
+/// XORs a buffer with the ChaCha20 keystream, in place.
+///
+/// Identical to [`xor`] with the same buffer as input and output.
+pub(crate) fn xor_in_place(key: &[u8; 32], nonce: &[u8; 12], counter: u32, data: &mut [u8]) {
+    let mut block_counter = counter;
+
+    for chunk in data.chunks_mut(64) {
+        let keystream = block(key, block_counter, nonce);
+        block_counter = block_counter.wrapping_add(1);
+
+        for (d, k) in chunk.iter_mut().zip(keystream) {
+            *d ^= k;
+        }
+    }
+}
+
+/// Performs one Salsa20 quarter round.
+///
+/// Unlike ChaCha20, Salsa20 updates each word once, using the sum of the
+/// two preceding words, with rotations of 7, 9, 13 and 18 bits.
+#[inline(always)]
+fn salsa20_quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[b] ^= state[a].wrapping_add(state[d]).rotate_left(7);
+    state[c] ^= state[b].wrapping_add(state[a]).rotate_left(9);
+    state[d] ^= state[c].wrapping_add(state[b]).rotate_left(13);
+    state[a] ^= state[d].wrapping_add(state[c]).rotate_left(18);
+}
+
+/// Applies `rounds` rounds of the Salsa20 permutation.
+///
+/// Each double round performs:
+/// - 4 column quarter rounds
+/// - 4 row quarter rounds
+///
+/// Salsa20 uses 20 rounds; scrypt uses the reduced Salsa20/8.
+///
+/// # Panics
+///
+/// Panics if `rounds` is odd.
+pub(crate) fn salsa20_rounds(state: &mut [u32; 16], rounds: usize) {
+    assert!(rounds.is_multiple_of(2));
+
+    for _ in 0..rounds / 2 {
+        // Column rounds
+        salsa20_quarter_round(state, 0, 4, 8, 12);
+        salsa20_quarter_round(state, 5, 9, 13, 1);
+        salsa20_quarter_round(state, 10, 14, 2, 6);
+        salsa20_quarter_round(state, 15, 3, 7, 11);
+
+        // Row rounds
+        salsa20_quarter_round(state, 0, 1, 2, 3);
+        salsa20_quarter_round(state, 5, 6, 7, 4);
+        salsa20_quarter_round(state, 10, 11, 8, 9);
+        salsa20_quarter_round(state, 15, 12, 13, 14);
+    }
+}
+
+/// Builds the Salsa20 input state.
+///
+/// The constants (the same `"expand 32-byte k"` words as ChaCha20) sit on
+/// the diagonal, the key halves around them, and the 16-byte `input`
+/// (nonce and counter, or the HSalsa20 input) in words 6 to 9.
+fn salsa20_state(key: &[u8; 32], input: &[u8; 16]) -> [u32; 16] {
+    let word = |b: &[u8]| u32::from_le_bytes(b.try_into().unwrap());
+
+    let mut state = [0u32; 16];
+
+    state[0] = CHACHA20_CONSTANTS[0];
+    state[5] = CHACHA20_CONSTANTS[1];
+    state[10] = CHACHA20_CONSTANTS[2];
+    state[15] = CHACHA20_CONSTANTS[3];
+
+    for i in 0..4 {
+        state[1 + i] = word(&key[4 * i..4 * i + 4]);
+        state[11 + i] = word(&key[16 + 4 * i..16 + 4 * i + 4]);
+        state[6 + i] = word(&input[4 * i..4 * i + 4]);
+    }
+
+    state
+}
+
+/// Generates a single 64-byte Salsa20 keystream block.
+///
+/// # Parameters
+/// - `key`: 256-bit secret key (32 bytes)
+/// - `nonce`: 64-bit nonce
+/// - `counter`: 64-bit block counter
+///
+/// # Returns
+/// A 64-byte keystream block.
+///
+/// # Security Notes
+/// - Reusing the same `(key, nonce, counter)` tuple is catastrophic for
+///   security and must be prevented by higher-level protocols.
+pub(crate) fn salsa20_block(key: &[u8; 32], nonce: &[u8; 8], counter: u64) -> [u8; 64] {
+    let mut input = [0u8; 16];
+    input[..8].copy_from_slice(nonce);
+    input[8..].copy_from_slice(&counter.to_le_bytes());
+
+    let original = salsa20_state(key, &input);
+    let mut state = original;
+
+    salsa20_rounds(&mut state, 20);
+
+    let mut out = [0u8; 64];
+    out.chunks_exact_mut(4)
+        .zip(state.iter().zip(&original))
+        .for_each(|(chunk, (s, o))| {
+            chunk.copy_from_slice(&s.wrapping_add(*o).to_le_bytes());
+        });
+
+    out
+}
+
+/// Derives a 256-bit subkey with HSalsa20.
+///
+/// HSalsa20 runs the Salsa20 permutation without the feed-forward and
+/// outputs the diagonal and input words. It is used to extend the nonce
+/// of XSalsa20 and to hash X25519 shared secrets in NaCl `box`.
+///
+/// # Parameters
+/// - `key`: 256-bit secret key (32 bytes)
+/// - `input`: 128-bit input (the first 16 bytes of an XSalsa20 nonce)
+pub(crate) fn hsalsa20(key: &[u8; 32], input: &[u8; 16]) -> [u8; 32] {
+    let mut state = salsa20_state(key, input);
+    salsa20_rounds(&mut state, 20);
+
+    let mut out = [0u8; 32];
+    out.chunks_exact_mut(4)
+        .zip([0, 5, 10, 15, 6, 7, 8, 9])
+        .for_each(|(chunk, i)| {
+            chunk.copy_from_slice(&state[i].to_le_bytes());
+        });
+
+    state.fill(0);
+    out
+}
+
+/// XORs input data with the XSalsa20 keystream.
+///
+/// The subkey `HSalsa20(key, nonce[..16])` is used with Salsa20 and the
+/// remaining 8 nonce bytes.
+///
+/// # Parameters
+/// - `key`: 256-bit secret key (32 bytes)
+/// - `nonce`: 192-bit nonce
+/// - `counter`: Initial 64-bit block counter
+/// - `input`: Plaintext or ciphertext input
+/// - `output`: Output buffer (must be the same length as `input`)
+///
+/// # Notes
+/// - Encryption and decryption are identical operations.
+/// - This function performs no authentication.
+/// - The caller must ensure `(key, nonce)` uniqueness; random nonces are
+///   safe at this size.
+pub(crate) fn xsalsa20_xor(
+    key: &[u8; 32],
+    nonce: &[u8; 24],
+    counter: u64,
+    input: &[u8],
+    output: &mut [u8],
+) {
+    assert_eq!(input.len(), output.len());
+
+    let mut subkey = hsalsa20(key, nonce[..16].try_into().unwrap());
+    let inner: &[u8; 8] = nonce[16..].try_into().unwrap();
+
+    let mut block_counter = counter;
+
+    for (inp, out) in input.chunks(64).zip(output.chunks_mut(64)) {
+        let keystream = salsa20_block(&subkey, inner, block_counter);
+        block_counter = block_counter.wrapping_add(1);
+
+        for i in 0..inp.len() {
+            out[i] = inp[i] ^ keystream[i];
+        }
+    }
+
+    subkey.fill(0);
+}
@@ -0,0 +1,229 @@
+use cryptal::encryption::aead::{
+    Aead, AeadError, Aes128Gcm, Aes128GcmSiv, Aes256Gcm, Aes256GcmSiv, ChaCha20Poly1305,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Checks every form of the trait against a vector whose `sealed` is the
+/// ciphertext followed by the tag.
+fn check_vector<A: Aead>(key: &str, nonce: &str, aad: &str, pt: &str, sealed: &str) {
+    let cipher = A::new(&hex(key)).unwrap();
+    let nonce = hex(nonce);
+    let aad = hex(aad);
+    let pt = hex(pt);
+    let sealed = hex(sealed);
+    let (ct, tag) = sealed.split_at(pt.len());
+
+    assert_eq!(nonce.len(), A::NONCE_LEN);
+    assert_eq!(tag.len(), A::TAG_LEN);
+
+    // Attached.
+    assert_eq!(cipher.seal(&nonce, &aad, &pt).unwrap(), sealed);
+    assert_eq!(cipher.open(&nonce, &aad, &sealed).unwrap(), pt);
+
+    // Detached.
+    let mut ciphertext = vec![0u8; pt.len()];
+    let mut computed_tag = vec![0u8; A::TAG_LEN];
+    cipher
+        .seal_detached(&nonce, &aad, &pt, &mut ciphertext, &mut computed_tag)
+        .unwrap();
+    assert_eq!(ciphertext, ct);
+    assert_eq!(computed_tag, tag);
+
+    let mut plaintext = vec![0u8; ct.len()];
+    cipher
+        .open_detached(&nonce, &aad, ct, tag, &mut plaintext)
+        .unwrap();
+    assert_eq!(plaintext, pt);
+
+    // In place, attached.
+    let mut buffer = pt.clone();
+    cipher.seal_in_place(&nonce, &aad, &mut buffer).unwrap();
+    assert_eq!(buffer, sealed);
+    cipher.open_in_place(&nonce, &aad, &mut buffer).unwrap();
+    assert_eq!(buffer, pt);
+
+    // In place, detached.
+    let mut buffer = pt.clone();
+    let mut computed_tag = vec![0u8; A::TAG_LEN];
+    cipher
+        .seal_in_place_detached(&nonce, &aad, &mut buffer, &mut computed_tag)
+        .unwrap();
+    assert_eq!(buffer, ct);
+    assert_eq!(computed_tag, tag);
+    cipher
+        .open_in_place_detached(&nonce, &aad, &mut buffer, tag)
+        .unwrap();
+    assert_eq!(buffer, pt);
+}
+
+/// A format written once against the trait: `nonce || ciphertext || tag`.
+fn seal_framed<A: Aead>(key: &[u8], nonce: &[u8], msg: &[u8]) -> Vec<u8> {
+    let mut out = nonce.to_vec();
+    out.extend_from_slice(&A::new(key).unwrap().seal(nonce, b"framed", msg).unwrap());
+    out
+}
+
+fn open_framed<A: Aead>(key: &[u8], framed: &[u8]) -> Result<Vec<u8>, AeadError> {
+    let (nonce, sealed) = framed.split_at(A::NONCE_LEN);
+    A::new(key)?.open(nonce, b"framed", sealed)
+}
+
+fn check_behaviour<A: Aead>() {
+    let key: Vec<u8> = (0..A::KEY_LEN as u8).collect();
+    let nonce = vec![0x24; A::NONCE_LEN];
+    let cipher = A::new(&key).unwrap();
+
+    // Generic framing round trip.
+    let framed = seal_framed::<A>(&key, &nonce, b"generic message");
+    assert_eq!(framed.len(), A::NONCE_LEN + 15 + A::TAG_LEN);
+    assert_eq!(open_framed::<A>(&key, &framed).unwrap(), b"generic message");
+
+    // Tampering: ciphertext, tag, AAD and nonce.
+    let sealed = cipher.seal(&nonce, b"aad", b"some plaintext").unwrap();
+
+    for i in [0, sealed.len() - 1] {
+        let mut bad = sealed.clone();
+        bad[i] ^= 1;
+        assert_eq!(
+            cipher.open(&nonce, b"aad", &bad),
+            Err(AeadError::AuthenticationFailed)
+        );
+    }
+
+    assert_eq!(
+        cipher.open(&nonce, b"other", &sealed),
+        Err(AeadError::AuthenticationFailed)
+    );
+
+    let mut other_nonce = nonce.clone();
+    other_nonce[0] ^= 1;
+    assert_eq!(
+        cipher.open(&other_nonce, b"aad", &sealed),
+        Err(AeadError::AuthenticationFailed)
+    );
+
+    // A failed in-place open leaves no plaintext behind, whether the
+    // ciphertext or the tag was modified.
+    for i in [0, sealed.len() - 1] {
+        let mut buffer = sealed.clone();
+        buffer[i] ^= 1;
+        let tampered = buffer.clone();
+        assert!(cipher.open_in_place(&nonce, b"aad", &mut buffer).is_err());
+        assert_eq!(buffer, tampered);
+    }
+
+    // Length checks.
+    assert_eq!(A::new(&key[1..]).err(), Some(AeadError::InvalidKeyLength));
+    assert_eq!(
+        cipher.seal(&nonce[1..], b"", b"x"),
+        Err(AeadError::InvalidNonceLength)
+    );
+    assert_eq!(
+        cipher.open(&nonce, b"", &sealed[..A::TAG_LEN - 1]),
+        Err(AeadError::InvalidLength)
+    );
+
+    let mut short_tag = vec![0u8; A::TAG_LEN - 1];
+    let mut buffer = b"x".to_vec();
+    assert_eq!(
+        cipher.seal_in_place_detached(&nonce, b"", &mut buffer, &mut short_tag),
+        Err(AeadError::InvalidLength)
+    );
+
+    let mut out = [0u8; 2];
+    let mut tag = vec![0u8; A::TAG_LEN];
+    assert_eq!(
+        cipher.seal_detached(&nonce, b"", b"x", &mut out, &mut tag),
+        Err(AeadError::InvalidLength)
+    );
+}
+
+// -------------------------------------------------------
+// KNOWN-ANSWER VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn test_chacha20poly1305_rfc8439() {
+    check_vector::<ChaCha20Poly1305>(
+        "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f",
+        "070000004041424344454647",
+        "50515253c0c1c2c3c4c5c6c7",
+        "4c616469657320616e642047656e746c656d656e206f662074686520636c617373206f66202739393a204966204920636f756c64206f6666657220796f75206f6e6c79206f6e652074697020666f7220746865206675747572652c2073756e73637265656e20776f756c642062652069742e",
+        "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d63dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b3692ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc3ff4def08e4b7a9de576d26586cec64b61161ae10b594f09e26a7e902ecbd0600691",
+    );
+}
+
+#[test]
+fn test_aes_gcm_nist() {
+    check_vector::<Aes128Gcm>(
+        "b61553bb854895b929751cd0c5f80384",
+        "8863f999ae64e55d0bbd7457",
+        "d914b5f2d1b08ce53ea59cb310587245",
+        "9b1b113217d0c4ea7943cf123c69c6ad2e3c97368c51c9754145d155dde1ee8640c8cafff17a5c9737d26a137eee4bf369096d",
+        "acfab4632b8a25805112f13d85e082bc89dc49bd92164fa8a2dad242c3a1b2f2696f2fdff579025f3f146ea97da3e47dc34b655d9b5f4a9868c1c69cbd6fd851f01340",
+    );
+
+    check_vector::<Aes256Gcm>(
+        "5fe01c4baf01cbe07796d5aaef6ec1f45193a98a223594ae4f0ef4952e82e330",
+        "bd587321566c7f1a5dd8652d",
+        "9013617817dda947e135ee6dd3653382",
+        "881dc6c7a5d4509f3c4bd2daab08f165ddc204489aa8134562a4eac3d0bcad7965847b102733bb63d1e5c598ece0c3e5dadddd",
+        "16e375b4973b339d3f746c1c5a568bc7526e909ddff1e19c95c94a6ccff210c9a4a40679de5760c396ac0e2ceb1234f9f5fe26abd3d26d65a6275f7a4f56b422acab49",
+    );
+}
+
+#[test]
+fn test_aes_gcm_siv_rfc8452() {
+    check_vector::<Aes128GcmSiv>(
+        "f901cfe8a69615a93fdf7a98cad48179",
+        "6245709fb18853f68d833640",
+        "7576f7028ec6eb5ea7e298342a94d4b202b370ef9768ec6561c4fe6b7e7296fa859c21",
+        "e42a3c02c25b64869e146d7b233987bddfc240871d",
+        "391cc328d484a4f46406181bcd62efd9b3ee197d052d15506c84a9edd65e13e9d24a2a6e70",
+    );
+
+    check_vector::<Aes256GcmSiv>(
+        "3c535de192eaed3822a2fbbe2ca9dfc88255e14a661b8aa82cc54236093bbc23",
+        "688089e55540db1872504e1c",
+        "734320ccc9d9bbbb19cb81b2af4ecbc3e72834321f7aa0f70b7282b4f33df23f167541",
+        "ced532ce4159b035277d4dfbb7db62968b13cd4eec",
+        "626660c26ea6612fb17ad91e8e767639edd6c9faee9d6c7029675b89eaf4ba1ded1a286594",
+    );
+}
+
+// -------------------------------------------------------
+// GENERIC BEHAVIOUR
+// -------------------------------------------------------
+
+#[test]
+fn test_behaviour_all_ciphers() {
+    check_behaviour::<ChaCha20Poly1305>();
+    check_behaviour::<Aes128Gcm>();
+    check_behaviour::<Aes256Gcm>();
+    check_behaviour::<Aes128GcmSiv>();
+    check_behaviour::<Aes256GcmSiv>();
+}
+
+#[test]
+fn test_empty_message() {
+    fn check<A: Aead>() {
+        let cipher = A::new(&vec![7u8; A::KEY_LEN]).unwrap();
+        let nonce = vec![0u8; A::NONCE_LEN];
+
+        let sealed = cipher.seal(&nonce, b"header", b"").unwrap();
+        assert_eq!(sealed.len(), A::TAG_LEN);
+        assert_eq!(cipher.open(&nonce, b"header", &sealed).unwrap(), b"");
+    }
+
+    check::<ChaCha20Poly1305>();
+    check::<Aes128Gcm>();
+    check::<Aes256Gcm>();
+    check::<Aes128GcmSiv>();
+    check::<Aes256GcmSiv>();
+}
@@ -0,0 +1,175 @@
+use cryptal::encryption::aes_gcm::{
+    AesGcmError, decrypt, decrypt_with_aad, encrypt, encrypt_with_aad, force_software_backend,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Checks a vector with the default backend, then with the bitsliced AES
+/// and software GHASH forced.
+fn check_vector(key: &str, nonce: &str, pt: &str, aad: &str, ct: &str, tag: &str) {
+    let key = hex(key);
+    let nonce: [u8; 12] = hex(nonce).try_into().unwrap();
+    let pt = hex(pt);
+    let aad = hex(aad);
+    let ct = hex(ct);
+    let tag: [u8; 16] = hex(tag).try_into().unwrap();
+
+    for software in [false, true] {
+        force_software_backend(software);
+
+        let mut ciphertext = vec![0u8; pt.len()];
+        let mut computed_tag = [0u8; 16];
+        encrypt_with_aad(&key, &nonce, &aad, &pt, &mut ciphertext, &mut computed_tag).unwrap();
+
+        assert_eq!(ciphertext, ct, "ciphertext mismatch (software: {software})");
+        assert_eq!(computed_tag, tag, "tag mismatch (software: {software})");
+
+        let mut plaintext = vec![0u8; ct.len()];
+        decrypt_with_aad(&key, &nonce, &aad, &ct, &tag, &mut plaintext).unwrap();
+
+        assert_eq!(plaintext, pt, "plaintext mismatch (software: {software})");
+    }
+
+    force_software_backend(false);
+}
+
+// -------------------------------------------------------
+// NIST CAVP VECTORS (gcmEncryptExtIV)
+// -------------------------------------------------------
+
+#[test]
+fn test_aes128_gcm_empty() {
+    check_vector(
+        "11754cd72aec309bf52f7687212e8957",
+        "3c819d9a9bed087615030b65",
+        "",
+        "",
+        "",
+        "250327c674aaf477aef2675748cf6971",
+    );
+}
+
+#[test]
+fn test_aes128_gcm_vector() {
+    check_vector(
+        "b61553bb854895b929751cd0c5f80384",
+        "8863f999ae64e55d0bbd7457",
+        "9b1b113217d0c4ea7943cf123c69c6ad2e3c97368c51c9754145d155dde1ee8640c8cafff17a5c9737d26a137eee4bf369096d",
+        "d914b5f2d1b08ce53ea59cb310587245",
+        "acfab4632b8a25805112f13d85e082bc89dc49bd92164fa8a2dad242c3a1b2f2696f2fdff579025f3f146ea97da3e47dc34b65",
+        "5d9b5f4a9868c1c69cbd6fd851f01340",
+    );
+}
+
+#[test]
+fn test_aes256_gcm_empty() {
+    check_vector(
+        "b52c505a37d78eda5dd34f20c22540ea1b58963cf8e5bf8ffa85f9f2492505b4",
+        "516c33929df5a3284ff463d7",
+        "",
+        "",
+        "",
+        "bdc1ac884d332457a1d2664f168c76f0",
+    );
+}
+
+#[test]
+fn test_aes256_gcm_vector() {
+    check_vector(
+        "5fe01c4baf01cbe07796d5aaef6ec1f45193a98a223594ae4f0ef4952e82e330",
+        "bd587321566c7f1a5dd8652d",
+        "881dc6c7a5d4509f3c4bd2daab08f165ddc204489aa8134562a4eac3d0bcad7965847b102733bb63d1e5c598ece0c3e5dadddd",
+        "9013617817dda947e135ee6dd3653382",
+        "16e375b4973b339d3f746c1c5a568bc7526e909ddff1e19c95c94a6ccff210c9a4a40679de5760c396ac0e2ceb1234f9f5fe26",
+        "abd3d26d65a6275f7a4f56b422acab49",
+    );
+}
+
+// -------------------------------------------------------
+// API BEHAVIOUR
+// -------------------------------------------------------
+
+#[test]
+fn test_aes_gcm_roundtrip_without_aad() {
+    let key = [0x42u8; 32];
+    let nonce = [0x24u8; 12];
+    let plaintext: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+    let mut ciphertext = vec![0u8; plaintext.len()];
+    let mut tag = [0u8; 16];
+    encrypt(&key, &nonce, &plaintext, &mut ciphertext, &mut tag).unwrap();
+
+    assert_ne!(ciphertext, plaintext);
+
+    let mut decrypted = vec![0u8; ciphertext.len()];
+    decrypt(&key, &nonce, &ciphertext, &tag, &mut decrypted).unwrap();
+
+    assert_eq!(decrypted, plaintext);
+}
+
+#[test]
+fn test_aes_gcm_rejects_tampering() {
+    let key = [7u8; 16];
+    let nonce = [9u8; 12];
+    let aad = b"header";
+    let plaintext = b"attack at dawn";
+
+    let mut ciphertext = [0u8; 14];
+    let mut tag = [0u8; 16];
+    encrypt_with_aad(&key, &nonce, aad, plaintext, &mut ciphertext, &mut tag).unwrap();
+
+    let mut out = [0u8; 14];
+
+    let mut bad_ct = ciphertext;
+    bad_ct[3] ^= 1;
+    assert_eq!(
+        decrypt_with_aad(&key, &nonce, aad, &bad_ct, &tag, &mut out),
+        Err(AesGcmError::AuthenticationFailed)
+    );
+
+    let mut bad_tag = tag;
+    bad_tag[15] ^= 0x80;
+    assert_eq!(
+        decrypt_with_aad(&key, &nonce, aad, &ciphertext, &bad_tag, &mut out),
+        Err(AesGcmError::AuthenticationFailed)
+    );
+
+    assert_eq!(
+        decrypt_with_aad(&key, &nonce, b"Header", &ciphertext, &tag, &mut out),
+        Err(AesGcmError::AuthenticationFailed)
+    );
+
+    assert_eq!(
+        decrypt(&key, &nonce, &ciphertext, &tag, &mut out),
+        Err(AesGcmError::AuthenticationFailed)
+    );
+
+    assert_eq!(out, [0u8; 14], "plaintext must not be released");
+}
+
+#[test]
+fn test_aes_gcm_invalid_inputs() {
+    let nonce = [0u8; 12];
+    let mut tag = [0u8; 16];
+    let mut out = [0u8; 4];
+
+    assert_eq!(
+        encrypt(&[0u8; 24], &nonce, b"data", &mut out, &mut tag),
+        Err(AesGcmError::InvalidKeyLength)
+    );
+
+    assert_eq!(
+        encrypt(&[0u8; 16], &nonce, b"data!", &mut out, &mut tag),
+        Err(AesGcmError::InvalidLength)
+    );
+
+    assert_eq!(
+        decrypt(&[0u8; 32], &nonce, b"abc", &tag, &mut out),
+        Err(AesGcmError::InvalidLength)
+    );
+}
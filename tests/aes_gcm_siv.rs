@@ -0,0 +1,248 @@
+use cryptal::encryption::aes_gcm_siv::{
+    AesGcmSivError, decrypt, decrypt_deterministic, decrypt_with_aad, encrypt,
+    encrypt_deterministic, encrypt_with_aad, force_software_backend,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Checks a vector whose `ct` is the ciphertext followed by the tag, with
+/// the default backend, then with the bitsliced AES and software POLYVAL
+/// forced.
+fn check_vector(key: &str, nonce: &str, aad: &str, pt: &str, ct: &str) {
+    let key = hex(key);
+    let nonce: [u8; 12] = hex(nonce).try_into().unwrap();
+    let aad = hex(aad);
+    let pt = hex(pt);
+    let ct = hex(ct);
+    let (expected_ct, expected_tag) = ct.split_at(pt.len());
+
+    for software in [false, true] {
+        force_software_backend(software);
+
+        let mut ciphertext = vec![0u8; pt.len()];
+        let mut tag = [0u8; 16];
+        encrypt_with_aad(&key, &nonce, &aad, &pt, &mut ciphertext, &mut tag).unwrap();
+
+        assert_eq!(
+            ciphertext, expected_ct,
+            "ciphertext mismatch (software: {software})"
+        );
+        assert_eq!(tag, expected_tag, "tag mismatch (software: {software})");
+
+        let mut plaintext = vec![0u8; pt.len()];
+        decrypt_with_aad(&key, &nonce, &aad, &ciphertext, &tag, &mut plaintext).unwrap();
+
+        assert_eq!(plaintext, pt, "plaintext mismatch (software: {software})");
+    }
+
+    force_software_backend(false);
+}
+
+// -------------------------------------------------------
+// RFC 8452 APPENDIX C VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn test_aes128_gcm_siv_empty() {
+    check_vector(
+        "01000000000000000000000000000000",
+        "030000000000000000000000",
+        "",
+        "",
+        "dc20e2d83f25705bb49e439eca56de25",
+    );
+}
+
+#[test]
+fn test_aes128_gcm_siv_with_aad() {
+    check_vector(
+        "01000000000000000000000000000000",
+        "030000000000000000000000",
+        "01",
+        "020000000000000000000000",
+        "296c7889fd99f41917f4462008299c5102745aaa3a0c469fad9e075a",
+    );
+
+    check_vector(
+        "f901cfe8a69615a93fdf7a98cad48179",
+        "6245709fb18853f68d833640",
+        "7576f7028ec6eb5ea7e298342a94d4b202b370ef9768ec6561c4fe6b7e7296fa859c21",
+        "e42a3c02c25b64869e146d7b233987bddfc240871d",
+        "391cc328d484a4f46406181bcd62efd9b3ee197d052d15506c84a9edd65e13e9d24a2a6e70",
+    );
+}
+
+#[test]
+fn test_aes256_gcm_siv_empty() {
+    check_vector(
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "030000000000000000000000",
+        "",
+        "",
+        "07f5f4169bbf55a8400cd47ea6fd400f",
+    );
+}
+
+#[test]
+fn test_aes256_gcm_siv_with_aad() {
+    check_vector(
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "030000000000000000000000",
+        "01",
+        "020000000000000000000000",
+        "163d6f9cc1b346cd453a2e4cc1a4a19ae800941ccdc57cc8413c277f",
+    );
+
+    check_vector(
+        "3c535de192eaed3822a2fbbe2ca9dfc88255e14a661b8aa82cc54236093bbc23",
+        "688089e55540db1872504e1c",
+        "734320ccc9d9bbbb19cb81b2af4ecbc3e72834321f7aa0f70b7282b4f33df23f167541",
+        "ced532ce4159b035277d4dfbb7db62968b13cd4eec",
+        "626660c26ea6612fb17ad91e8e767639edd6c9faee9d6c7029675b89eaf4ba1ded1a286594",
+    );
+}
+
+#[test]
+fn test_aes256_gcm_siv_counter_wrap() {
+    check_vector(
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "000000000000000000000000",
+        "",
+        "000000000000000000000000000000004db923dc793ee6497c76dcc03a98e108",
+        "f3f80f2cf0cb2dd9c5984fcda908456cc537703b5ba70324a6793a7bf218d3eaffffffff000000000000000000000000",
+    );
+
+    check_vector(
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "000000000000000000000000",
+        "",
+        "eb3640277c7ffd1303c7a542d02d3e4c0000000000000000",
+        "18ce4f0b8cb4d0cac65fea8f79257b20888e53e72299e56dffffffff000000000000000000000000",
+    );
+}
+
+// -------------------------------------------------------
+// API BEHAVIOUR
+// -------------------------------------------------------
+
+#[test]
+fn test_aes_gcm_siv_roundtrip_without_aad() {
+    let key = [0x11u8; 16];
+    let nonce = [0x22u8; 12];
+    let plaintext: Vec<u8> = (0..333u32).map(|i| (i * 7) as u8).collect();
+
+    let mut ciphertext = vec![0u8; plaintext.len()];
+    let mut tag = [0u8; 16];
+    encrypt(&key, &nonce, &plaintext, &mut ciphertext, &mut tag).unwrap();
+
+    let mut decrypted = vec![0u8; ciphertext.len()];
+    decrypt(&key, &nonce, &ciphertext, &tag, &mut decrypted).unwrap();
+
+    assert_eq!(decrypted, plaintext);
+}
+
+#[test]
+fn test_aes_gcm_siv_deterministic_mode() {
+    let key = [0x33u8; 32];
+    let email = b"alice@example.com";
+
+    let mut ct1 = [0u8; 17];
+    let mut tag1 = [0u8; 16];
+    encrypt_deterministic(&key, b"users.email", email, &mut ct1, &mut tag1).unwrap();
+
+    let mut ct2 = [0u8; 17];
+    let mut tag2 = [0u8; 16];
+    encrypt_deterministic(&key, b"users.email", email, &mut ct2, &mut tag2).unwrap();
+
+    assert_eq!(ct1, ct2, "deterministic mode must be repeatable");
+    assert_eq!(tag1, tag2);
+
+    let mut ct3 = [0u8; 17];
+    let mut tag3 = [0u8; 16];
+    encrypt_deterministic(&key, b"users.backup", email, &mut ct3, &mut tag3).unwrap();
+
+    assert_ne!(tag1, tag3, "AAD must separate fields");
+
+    let mut out = [0u8; 17];
+    decrypt_deterministic(&key, b"users.email", &ct1, &tag1, &mut out).unwrap();
+    assert_eq!(&out, email);
+}
+
+#[test]
+fn test_aes_gcm_siv_nonce_reuse_only_reveals_equality() {
+    let key = [0x44u8; 16];
+    let nonce = [0u8; 12];
+
+    let mut ct1 = [0u8; 32];
+    let mut tag1 = [0u8; 16];
+    encrypt(&key, &nonce, &[0u8; 32], &mut ct1, &mut tag1).unwrap();
+
+    let mut ct2 = [0u8; 32];
+    let mut tag2 = [0u8; 16];
+    let mut other = [0u8; 32];
+    other[31] = 1;
+    encrypt(&key, &nonce, &other, &mut ct2, &mut tag2).unwrap();
+
+    // A one-bit plaintext change yields a different synthetic IV, so the
+    // keystreams differ and the ciphertexts do not XOR to the plaintexts.
+    let xor: Vec<u8> = ct1.iter().zip(&ct2).map(|(a, b)| a ^ b).collect();
+    assert_ne!(xor, other);
+    assert_ne!(tag1, tag2);
+}
+
+#[test]
+fn test_aes_gcm_siv_rejects_tampering() {
+    let key = [5u8; 32];
+    let nonce = [6u8; 12];
+    let aad = b"row:42";
+    let plaintext = b"secret value";
+
+    let mut ciphertext = [0u8; 12];
+    let mut tag = [0u8; 16];
+    encrypt_with_aad(&key, &nonce, aad, plaintext, &mut ciphertext, &mut tag).unwrap();
+
+    let mut out = [0u8; 12];
+
+    let mut bad_ct = ciphertext;
+    bad_ct[0] ^= 1;
+    assert_eq!(
+        decrypt_with_aad(&key, &nonce, aad, &bad_ct, &tag, &mut out),
+        Err(AesGcmSivError::AuthenticationFailed)
+    );
+    assert_eq!(out, [0u8; 12], "plaintext must be wiped on failure");
+
+    let mut bad_tag = tag;
+    bad_tag[8] ^= 4;
+    assert_eq!(
+        decrypt_with_aad(&key, &nonce, aad, &ciphertext, &bad_tag, &mut out),
+        Err(AesGcmSivError::AuthenticationFailed)
+    );
+
+    assert_eq!(
+        decrypt_with_aad(&key, &[7u8; 12], aad, &ciphertext, &tag, &mut out),
+        Err(AesGcmSivError::AuthenticationFailed)
+    );
+    assert_eq!(out, [0u8; 12]);
+}
+
+#[test]
+fn test_aes_gcm_siv_invalid_inputs() {
+    let nonce = [0u8; 12];
+    let mut tag = [0u8; 16];
+    let mut out = [0u8; 4];
+
+    assert_eq!(
+        encrypt(&[0u8; 24], &nonce, b"data", &mut out, &mut tag),
+        Err(AesGcmSivError::InvalidKeyLength)
+    );
+
+    assert_eq!(
+        decrypt(&[0u8; 16], &nonce, b"abc", &tag, &mut out),
+        Err(AesGcmSivError::InvalidLength)
+    );
+}
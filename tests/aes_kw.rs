@@ -0,0 +1,142 @@
+use cryptal::encryption::aes_kw::{AesKwError, OVERHEAD, unwrap, wrap};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn check_vector(kek: &str, key: &str, wrapped: &str) {
+    let kek = hex(kek);
+    let key = hex(key);
+    let wrapped = hex(wrapped);
+
+    assert_eq!(wrap(&kek, &key).unwrap(), wrapped, "wrap mismatch");
+    assert_eq!(unwrap(&kek, &wrapped).unwrap(), key, "unwrap mismatch");
+}
+
+// -------------------------------------------------------
+// RFC 3394 §4 TEST VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn test_rfc3394_128_kek_128_key() {
+    check_vector(
+        "000102030405060708090a0b0c0d0e0f",
+        "00112233445566778899aabbccddeeff",
+        "1fa68b0a8112b447aef34bd8fb5a7b829d3e862371d2cfe5",
+    );
+}
+
+#[test]
+fn test_rfc3394_256_kek_128_key() {
+    check_vector(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00112233445566778899aabbccddeeff",
+        "64e8c3f9ce0f5ba263e9777905818a2a93c8191e7d6e8ae7",
+    );
+}
+
+#[test]
+fn test_rfc3394_256_kek_192_key() {
+    check_vector(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00112233445566778899aabbccddeeff0001020304050607",
+        "a8f9bc1612c68b3ff6e6f4fbe30e71e4769c8b80a32cb8958cd5d17d6b254da1",
+    );
+}
+
+#[test]
+fn test_rfc3394_256_kek_256_key() {
+    check_vector(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        "00112233445566778899aabbccddeeff000102030405060708090a0b0c0d0e0f",
+        "28c9f404c4b810f4cbccb35cfb87f8263f5786e2d80ed326cbc7f0e71a99f43bfb988b9b7a02dd21",
+    );
+}
+
+// -------------------------------------------------------
+// ROUND TRIPS
+// -------------------------------------------------------
+
+#[test]
+fn test_round_trip_lengths() {
+    let kek = [0x42u8; 32];
+
+    for len in (16..=128).step_by(8) {
+        let key: Vec<u8> = (0..len).map(|i| (i * 7) as u8).collect();
+
+        let wrapped = wrap(&kek, &key).unwrap();
+        assert_eq!(wrapped.len(), len + OVERHEAD);
+        assert_eq!(unwrap(&kek, &wrapped).unwrap(), key);
+    }
+}
+
+#[test]
+fn test_wrap_is_deterministic() {
+    let kek = [1u8; 16];
+    let key = [2u8; 32];
+
+    assert_eq!(wrap(&kek, &key).unwrap(), wrap(&kek, &key).unwrap());
+}
+
+// -------------------------------------------------------
+// TAMPERING
+// -------------------------------------------------------
+
+#[test]
+fn test_tampering_detected() {
+    let kek = [0x11u8; 16];
+    let wrapped = wrap(&kek, &[0x22u8; 32]).unwrap();
+
+    for i in 0..wrapped.len() {
+        let mut bad = wrapped.clone();
+        bad[i] ^= 0x01;
+        assert_eq!(unwrap(&kek, &bad), Err(AesKwError::IntegrityCheckFailed));
+    }
+
+    // Swapped semiblocks.
+    let mut swapped = wrapped.clone();
+    let (a, b) = swapped[8..24].split_at_mut(8);
+    a.swap_with_slice(b);
+    assert_eq!(
+        unwrap(&kek, &swapped),
+        Err(AesKwError::IntegrityCheckFailed)
+    );
+}
+
+#[test]
+fn test_wrong_kek() {
+    let wrapped = wrap(&[0x11u8; 32], &[0x22u8; 16]).unwrap();
+
+    assert_eq!(
+        unwrap(&[0x12u8; 32], &wrapped),
+        Err(AesKwError::IntegrityCheckFailed)
+    );
+}
+
+// -------------------------------------------------------
+// INVALID INPUTS
+// -------------------------------------------------------
+
+#[test]
+fn test_invalid_lengths() {
+    let kek = [0u8; 16];
+
+    assert_eq!(
+        wrap(&[0u8; 24], &[0u8; 16]),
+        Err(AesKwError::InvalidKekLength)
+    );
+    assert_eq!(
+        unwrap(&[0u8; 8], &[0u8; 24]),
+        Err(AesKwError::InvalidKekLength)
+    );
+
+    assert_eq!(wrap(&kek, &[]), Err(AesKwError::InvalidKeyLength));
+    assert_eq!(wrap(&kek, &[0u8; 8]), Err(AesKwError::InvalidKeyLength));
+    assert_eq!(wrap(&kek, &[0u8; 20]), Err(AesKwError::InvalidKeyLength));
+
+    assert_eq!(unwrap(&kek, &[0u8; 16]), Err(AesKwError::InvalidLength));
+    assert_eq!(unwrap(&kek, &[0u8; 25]), Err(AesKwError::InvalidLength));
+}
@@ -0,0 +1,351 @@
+use cryptal::encryption::age::{
+    AgeError, Identity, Recipient, armor, dearmor, decode_identity, decode_recipient, decrypt,
+    encode_identity, encode_recipient, encrypt, generate_key_pair,
+};
+use cryptal::hash::sha256;
+use cryptal::keys::x25519::x25519_base;
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+const PASSPHRASE: Recipient<'static> = Recipient::Scrypt {
+    passphrase: b"correct horse battery staple",
+    work_factor: 10,
+};
+
+const PASSPHRASE_IDENTITY: Identity<'static> = Identity::Scrypt {
+    passphrase: b"correct horse battery staple",
+    max_work_factor: 10,
+};
+
+// -------------------------------------------------------
+// C2SP AGE TEST VECTORS
+// -------------------------------------------------------
+//
+// See tests/data/age/README.md.
+
+/// Maps a decryption result to the testkit's `expect:` category.
+fn outcome(result: &Result<Vec<u8>, AgeError>) -> &'static str {
+    match result {
+        Ok(_) => "success",
+        Err(AgeError::InvalidArmor) => "armor failure",
+        Err(AgeError::InvalidHeader)
+        | Err(AgeError::UnsupportedVersion)
+        | Err(AgeError::WorkFactorTooLarge) => "header failure",
+        Err(AgeError::HeaderMacMismatch) => "HMAC failure",
+        Err(AgeError::NoMatchingIdentity) => "no match",
+        Err(AgeError::InvalidPayload) => "payload failure",
+        Err(e) => panic!("unexpected error {e:?}"),
+    }
+}
+
+fn run_vector(name: &str, data: &[u8]) {
+    let mut expect = None;
+    let mut payload = None;
+    let mut armored = false;
+    let mut secrets = Vec::new();
+    let mut passphrases = Vec::new();
+
+    let mut rest = data;
+    loop {
+        let end = rest.iter().position(|&c| c == b'\n').unwrap();
+        let line = core::str::from_utf8(&rest[..end]).unwrap();
+        rest = &rest[end + 1..];
+
+        if line.is_empty() {
+            break;
+        }
+
+        let (key, value) = line.split_once(": ").unwrap();
+        match key {
+            "expect" => expect = Some(value.to_string()),
+            "payload" => payload = Some(hex(value)),
+            "identity" => secrets.push(decode_identity(value).unwrap()),
+            "passphrase" => passphrases.push(value.as_bytes().to_vec()),
+            "armored" => armored = value == "yes",
+            _ => {}
+        }
+    }
+
+    let mut identities: Vec<Identity<'_>> =
+        secrets.iter().map(|sk| Identity::X25519(*sk)).collect();
+    identities.extend(passphrases.iter().map(|p| Identity::Scrypt {
+        passphrase: p,
+        max_work_factor: 16,
+    }));
+
+    let result = if armored {
+        dearmor(rest).and_then(|file| decrypt(&identities, &file))
+    } else {
+        decrypt(&identities, rest)
+    };
+
+    let expect = expect.unwrap();
+    assert_eq!(outcome(&result), expect, "{name}: {result:?}");
+
+    if let (Ok(plaintext), Some(payload)) = (&result, &payload) {
+        let digest = sha256(plaintext);
+        let digest: &[u8] = digest.as_ref();
+        assert_eq!(digest, &payload[..], "{name}: payload hash");
+    }
+}
+
+#[test]
+fn test_testkit_vectors() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/age");
+
+    let mut count = 0;
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap().to_string();
+
+        if name.ends_with(".md") {
+            continue;
+        }
+
+        run_vector(&name, &std::fs::read(&path).unwrap());
+        count += 1;
+    }
+
+    assert!(count > 90);
+}
+
+// -------------------------------------------------------
+// KEY ENCODING
+// -------------------------------------------------------
+
+#[test]
+fn test_identity_from_testkit() {
+    let secret = decode_identity(
+        "AGE-SECRET-KEY-1XMWWC06LY3EE5RYTXM9MFLAZ2U56JJJ36S0MYPDRWSVLUL66MV4QX3S7F6",
+    )
+    .unwrap();
+
+    assert_eq!(
+        secret.to_vec(),
+        hex("36dcec3f5f24739a0c8b36cbb4ffa25729a94a51d41fb205a37419fe7f5adb2a")
+    );
+
+    assert_eq!(
+        encode_identity(&secret),
+        "AGE-SECRET-KEY-1XMWWC06LY3EE5RYTXM9MFLAZ2U56JJJ36S0MYPDRWSVLUL66MV4QX3S7F6"
+    );
+
+    let public = x25519_base(&secret);
+    assert_eq!(
+        encode_recipient(&public),
+        "age1w3tyke4gev25vaxxsvcgqu4484rf6ejpmavs57p6yz6lhy2sfs5swrvwyn"
+    );
+}
+
+#[test]
+fn test_key_encoding_round_trip() {
+    let (public, secret) = generate_key_pair();
+
+    assert_eq!(x25519_base(&secret), public);
+    assert_eq!(
+        decode_recipient(&encode_recipient(&public)).unwrap(),
+        public
+    );
+    assert_eq!(decode_identity(&encode_identity(&secret)).unwrap(), secret);
+
+    // Either case is accepted, but not a mix of both.
+    let upper = encode_recipient(&public).to_ascii_uppercase();
+    assert_eq!(decode_recipient(&upper).unwrap(), public);
+}
+
+#[test]
+fn test_invalid_keys() {
+    let (public, secret) = generate_key_pair();
+    let recipient = encode_recipient(&public);
+    let identity = encode_identity(&secret);
+
+    // Wrong type.
+    assert_eq!(decode_recipient(&identity), Err(AgeError::InvalidKey));
+    assert_eq!(decode_identity(&recipient), Err(AgeError::InvalidKey));
+
+    // Bad checksum.
+    let mut bad = recipient.clone().into_bytes();
+    let last = bad.len() - 1;
+    bad[last] = if bad[last] == b'q' { b'p' } else { b'q' };
+    assert_eq!(
+        decode_recipient(core::str::from_utf8(&bad).unwrap()),
+        Err(AgeError::InvalidKey)
+    );
+
+    // Mixed case.
+    let mixed = format!("AGE{}", &recipient[3..]);
+    assert_eq!(decode_recipient(&mixed), Err(AgeError::InvalidKey));
+
+    assert_eq!(decode_recipient(""), Err(AgeError::InvalidKey));
+    assert_eq!(decode_recipient("age1"), Err(AgeError::InvalidKey));
+}
+
+// -------------------------------------------------------
+// ROUND TRIPS
+// -------------------------------------------------------
+
+#[test]
+fn test_x25519_round_trip_chunk_boundaries() {
+    let (public, secret) = generate_key_pair();
+
+    for len in [0, 1, 65535, 65536, 65537, 2 * 65536, 2 * 65536 + 100] {
+        let plaintext: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+
+        let file = encrypt(&[Recipient::X25519(public)], &plaintext).unwrap();
+        assert_eq!(
+            decrypt(&[Identity::X25519(secret)], &file).unwrap(),
+            plaintext
+        );
+    }
+}
+
+#[test]
+fn test_multiple_recipients() {
+    let keys: Vec<_> = (0..3).map(|_| generate_key_pair()).collect();
+    let recipients: Vec<_> = keys.iter().map(|(pk, _)| Recipient::X25519(*pk)).collect();
+
+    let file = encrypt(&recipients, b"shared secret").unwrap();
+
+    for (_, sk) in &keys {
+        assert_eq!(
+            decrypt(&[Identity::X25519(*sk)], &file).unwrap(),
+            b"shared secret"
+        );
+    }
+
+    let (_, other) = generate_key_pair();
+    assert_eq!(
+        decrypt(&[Identity::X25519(other)], &file),
+        Err(AgeError::NoMatchingIdentity)
+    );
+
+    // The matching identity need not come first.
+    assert_eq!(
+        decrypt(
+            &[Identity::X25519(other), Identity::X25519(keys[2].1)],
+            &file
+        )
+        .unwrap(),
+        b"shared secret"
+    );
+}
+
+#[test]
+fn test_scrypt_round_trip() {
+    let file = encrypt(&[PASSPHRASE], b"passphrase protected").unwrap();
+
+    assert_eq!(
+        decrypt(&[PASSPHRASE_IDENTITY], &file).unwrap(),
+        b"passphrase protected"
+    );
+
+    let wrong = Identity::Scrypt {
+        passphrase: b"wrong",
+        max_work_factor: 10,
+    };
+    assert_eq!(decrypt(&[wrong], &file), Err(AgeError::NoMatchingIdentity));
+
+    let cheap = Identity::Scrypt {
+        passphrase: b"correct horse battery staple",
+        max_work_factor: 9,
+    };
+    assert_eq!(decrypt(&[cheap], &file), Err(AgeError::WorkFactorTooLarge));
+}
+
+#[test]
+fn test_invalid_recipients() {
+    let (public, _) = generate_key_pair();
+
+    assert_eq!(encrypt(&[], b"x"), Err(AgeError::InvalidRecipients));
+    assert_eq!(
+        encrypt(&[PASSPHRASE, Recipient::X25519(public)], b"x"),
+        Err(AgeError::InvalidRecipients)
+    );
+    assert_eq!(
+        encrypt(
+            &[Recipient::Scrypt {
+                passphrase: b"x",
+                work_factor: 0,
+            }],
+            b"x"
+        ),
+        Err(AgeError::WorkFactorTooLarge)
+    );
+
+    // Low-order point.
+    assert_eq!(
+        encrypt(&[Recipient::X25519([0u8; 32])], b"x"),
+        Err(AgeError::InvalidKey)
+    );
+}
+
+#[test]
+fn test_armor_round_trip() {
+    let (public, secret) = generate_key_pair();
+    let file = encrypt(&[Recipient::X25519(public)], &[0x5a; 1000]).unwrap();
+
+    let text = armor(&file);
+    assert!(text.starts_with("-----BEGIN AGE ENCRYPTED FILE-----\n"));
+    assert!(text.ends_with("-----END AGE ENCRYPTED FILE-----\n"));
+    assert!(text.lines().all(|l| l.len() <= 64));
+
+    assert_eq!(dearmor(text.as_bytes()).unwrap(), file);
+
+    // CRLF line endings and surrounding whitespace are tolerated.
+    let crlf = format!("\r\n  {}\n", text.replace('\n', "\r\n"));
+    assert_eq!(dearmor(crlf.as_bytes()).unwrap(), file);
+
+    assert_eq!(
+        decrypt(
+            &[Identity::X25519(secret)],
+            &dearmor(text.as_bytes()).unwrap()
+        )
+        .unwrap(),
+        vec![0x5a; 1000]
+    );
+}
+
+// -------------------------------------------------------
+// TAMPERING
+// -------------------------------------------------------
+
+#[test]
+fn test_tampering_detected() {
+    let (public, secret) = generate_key_pair();
+    let identity = [Identity::X25519(secret)];
+    let plaintext = vec![7u8; 70000];
+
+    let file = encrypt(&[Recipient::X25519(public)], &plaintext).unwrap();
+    let header_len = file.windows(4).position(|w| w == b"--- ").unwrap();
+
+    // Payload bit flip.
+    let mut bad = file.clone();
+    let last = bad.len() - 1;
+    bad[last] ^= 1;
+    assert_eq!(decrypt(&identity, &bad), Err(AgeError::InvalidPayload));
+
+    // Truncation at a chunk boundary, and extension.
+    let truncated = &file[..file.len() - (70000 - 65536) - 16];
+    assert_eq!(decrypt(&identity, truncated), Err(AgeError::InvalidPayload));
+
+    let mut extended = file.clone();
+    extended.push(0);
+    assert_eq!(decrypt(&identity, &extended), Err(AgeError::InvalidPayload));
+
+    // Header change other than the stanza body.
+    let mut bad = file.clone();
+    bad[header_len - 2] ^= 1;
+    assert!(decrypt(&identity, &bad).is_err());
+
+    // Missing payload nonce.
+    let nl = header_len + file[header_len..].iter().position(|&c| c == b'\n').unwrap();
+    assert_eq!(
+        decrypt(&identity, &file[..nl + 1]),
+        Err(AgeError::InvalidHeader)
+    );
+}
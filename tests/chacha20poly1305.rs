@@ -1,4 +1,6 @@
-use cryptal::encryption::chacha20poly1305::{Chacha20Poly1305Error, auth, decrypt, encrypt};
+use cryptal::encryption::chacha20poly1305::{
+    Chacha20Poly1305Error, auth, decrypt, decrypt_with_aad, encrypt, encrypt_with_aad,
+};
 
 #[test]
 fn test_poly1305_rfc8439_vector() {
@@ -101,3 +103,40 @@ fn test_rfc8439_ciphertext_only() {
         "ciphertext mismatch"
     );
 }
+
+#[test]
+fn test_rfc8439_aead_with_aad() {
+    let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    let key: [u8; 32] = [
+        0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e,
+        0x8f, 0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d,
+        0x9e, 0x9f,
+    ];
+
+    let nonce: [u8; 12] = [
+        0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    ];
+
+    let aad: [u8; 12] = [
+        0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
+    ];
+
+    let expected_tag: [u8; 16] = [
+        0x1a, 0xe1, 0x0b, 0x59, 0x4f, 0x09, 0xe2, 0x6a, 0x7e, 0x90, 0x2e, 0xcb, 0xd0, 0x60, 0x06,
+        0x91,
+    ];
+
+    let mut ciphertext = vec![0u8; plaintext.len()];
+    let mut tag = [0u8; 16];
+
+    encrypt_with_aad(&key, &nonce, &aad, plaintext, &mut ciphertext, &mut tag);
+    assert_eq!(tag, expected_tag, "tag mismatch");
+
+    let mut decrypted = vec![0u8; plaintext.len()];
+    decrypt_with_aad(&key, &nonce, &aad, &ciphertext, &tag, &mut decrypted).unwrap();
+    assert_eq!(&decrypted[..], &plaintext[..]);
+
+    let res = decrypt(&key, &nonce, &ciphertext, &tag, &mut decrypted);
+    assert_eq!(res, Err(Chacha20Poly1305Error::AuthenticationFailed));
+}
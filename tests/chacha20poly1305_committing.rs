@@ -0,0 +1,161 @@
+use cryptal::encryption::chacha20poly1305;
+use cryptal::encryption::chacha20poly1305::Chacha20Poly1305Error;
+use cryptal::encryption::chacha20poly1305_committing::{
+    decrypt_committing, decrypt_committing_with_aad, encrypt_committing,
+    encrypt_committing_with_aad,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn test_key() -> [u8; 32] {
+    core::array::from_fn(|i| i as u8)
+}
+
+fn test_nonce() -> [u8; 12] {
+    core::array::from_fn(|i| 0x40 + i as u8)
+}
+
+#[test]
+fn test_committing_known_answer() {
+    let key = test_key();
+    let nonce = test_nonce();
+    let plaintext = b"the vault contents";
+
+    let mut ciphertext = [0u8; 18];
+    let mut tag = [0u8; 16];
+    let mut commitment = [0u8; 32];
+
+    encrypt_committing_with_aad(
+        &key,
+        &nonce,
+        b"vault-header",
+        plaintext,
+        &mut ciphertext,
+        &mut tag,
+        &mut commitment,
+    );
+
+    assert_eq!(
+        commitment.to_vec(),
+        hex("d129fbb5d0b1be946fdab0d5127032582dcea448af31617b1714c3d1a2cd1346")
+    );
+    assert_eq!(
+        ciphertext.to_vec(),
+        hex("48e32f0e59e50d2db5ccd4a962cad9e17c40")
+    );
+    assert_eq!(tag.to_vec(), hex("5d9d8ac5e895e289a3f9bc4afc2c4e2a"));
+
+    let mut decrypted = [0u8; 18];
+    decrypt_committing_with_aad(
+        &key,
+        &nonce,
+        b"vault-header",
+        &ciphertext,
+        &tag,
+        &commitment,
+        &mut decrypted,
+    )
+    .unwrap();
+
+    assert_eq!(&decrypted, plaintext);
+}
+
+#[test]
+fn test_committing_rejects_wrong_key() {
+    let key = test_key();
+    let nonce = test_nonce();
+
+    let mut ciphertext = [0u8; 5];
+    let mut tag = [0u8; 16];
+    let mut commitment = [0u8; 32];
+    encrypt_committing(
+        &key,
+        &nonce,
+        b"hello",
+        &mut ciphertext,
+        &mut tag,
+        &mut commitment,
+    );
+
+    let mut other_key = key;
+    other_key[0] ^= 1;
+
+    let mut out = [0u8; 5];
+    assert_eq!(
+        decrypt_committing(&other_key, &nonce, &ciphertext, &tag, &commitment, &mut out),
+        Err(Chacha20Poly1305Error::AuthenticationFailed)
+    );
+    assert_eq!(out, [0u8; 5]);
+}
+
+#[test]
+fn test_committing_rejects_tampering() {
+    let key = test_key();
+    let nonce = test_nonce();
+
+    let mut ciphertext = [0u8; 5];
+    let mut tag = [0u8; 16];
+    let mut commitment = [0u8; 32];
+    encrypt_committing(
+        &key,
+        &nonce,
+        b"hello",
+        &mut ciphertext,
+        &mut tag,
+        &mut commitment,
+    );
+
+    let mut out = [0u8; 5];
+
+    let mut bad_commitment = commitment;
+    bad_commitment[31] ^= 1;
+    assert_eq!(
+        decrypt_committing(&key, &nonce, &ciphertext, &tag, &bad_commitment, &mut out),
+        Err(Chacha20Poly1305Error::AuthenticationFailed)
+    );
+
+    let mut bad_tag = tag;
+    bad_tag[0] ^= 1;
+    assert_eq!(
+        decrypt_committing(&key, &nonce, &ciphertext, &bad_tag, &commitment, &mut out),
+        Err(Chacha20Poly1305Error::AuthenticationFailed)
+    );
+
+    assert_eq!(
+        decrypt_committing(&key, &nonce, &ciphertext[..4], &tag, &commitment, &mut out),
+        Err(Chacha20Poly1305Error::InvalidLength)
+    );
+
+    decrypt_committing(&key, &nonce, &ciphertext, &tag, &commitment, &mut out).unwrap();
+    assert_eq!(&out, b"hello");
+}
+
+#[test]
+fn test_committing_differs_from_plain_aead() {
+    let key = test_key();
+    let nonce = test_nonce();
+
+    let mut committed = [0u8; 5];
+    let mut tag = [0u8; 16];
+    let mut commitment = [0u8; 32];
+    encrypt_committing(
+        &key,
+        &nonce,
+        b"hello",
+        &mut committed,
+        &mut tag,
+        &mut commitment,
+    );
+
+    let mut out = [0u8; 5];
+    assert_eq!(
+        chacha20poly1305::decrypt(&key, &nonce, &committed, &tag, &mut out),
+        Err(Chacha20Poly1305Error::AuthenticationFailed),
+        "committing ciphertexts must not open with the raw key"
+    );
+}
@@ -1,7 +1,17 @@
+use cryptal::hash::sha512;
 use cryptal::keys::ed25519::{
-    Scalar, Signature, add_scalar, exchange, generate_keypair, sign, verify,
+    Ed25519Error, EdwardsPoint, Prehash, PrivateKey, PublicKey, Scalar, Signature, SigningKey,
+    add_scalar, checked_exchange, exchange, generate_keypair, sign, sign_ctx, sign_ph, verify,
+    verify_batch, verify_ctx, verify_ph, verify_strict, verify_zip215,
 };
 
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
 #[test]
 fn test_sign_and_verify() {
     let message = b"Hello, world!";
@@ -46,3 +56,587 @@ fn test_key_exchange() {
 
     assert_eq!(alice_shared, bob_shared);
 }
+
+#[test]
+fn test_checked_key_exchange() {
+    let (alice_public, alice_private) = generate_keypair();
+    let (bob_public, bob_private) = generate_keypair();
+
+    let alice_shared = checked_exchange(&alice_private, &bob_public).unwrap();
+    let bob_shared = checked_exchange(&bob_private, &alice_public).unwrap();
+
+    assert_eq!(alice_shared.to_bytes(), bob_shared.to_bytes());
+    assert_eq!(
+        alice_shared.to_bytes(),
+        exchange(&alice_private, &bob_public)
+    );
+
+    // Small-order Edwards points, such as the identity, are rejected.
+    let mut identity = [0u8; 32];
+    identity[0] = 1;
+    let identity = PublicKey::from_bytes(&identity).unwrap();
+
+    assert_eq!(exchange(&alice_private, &identity), [0u8; 32]);
+    assert!(checked_exchange(&alice_private, &identity).is_err());
+}
+
+// -------------------------------------------------------
+// RFC 8032 §7.1 TEST VECTORS
+// -------------------------------------------------------
+
+fn check_rfc8032(seed: &str, public: &str, message: &str, signature: &str) {
+    let seed: [u8; 32] = hex(seed).try_into().unwrap();
+    let public: [u8; 32] = hex(public).try_into().unwrap();
+    let message = hex(message);
+    let signature: [u8; 64] = hex(signature).try_into().unwrap();
+
+    let key = SigningKey::from_seed(&seed);
+    assert_eq!(key.to_seed(), seed);
+    assert_eq!(key.public_key().to_bytes(), public, "public key mismatch");
+    assert_eq!(
+        key.sign(&message).to_bytes(),
+        signature,
+        "signature mismatch"
+    );
+
+    let public = PublicKey::from_bytes(&public).unwrap();
+    assert!(verify(Signature::from_bytes(signature), &message, public));
+}
+
+#[test]
+fn test_rfc8032_test_1() {
+    check_rfc8032(
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        "",
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+    );
+}
+
+#[test]
+fn test_rfc8032_test_2() {
+    check_rfc8032(
+        "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        "72",
+        "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+    );
+}
+
+#[test]
+fn test_rfc8032_test_3() {
+    check_rfc8032(
+        "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+        "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        "af82",
+        "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+    );
+}
+
+#[test]
+fn test_rfc8032_test_sha_abc() {
+    check_rfc8032(
+        "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+        "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+    );
+}
+
+// -------------------------------------------------------
+// KEY IMPORT / EXPORT
+// -------------------------------------------------------
+
+#[test]
+fn test_expanded_key_round_trip() {
+    let key = SigningKey::from_seed(
+        &hex("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+            .try_into()
+            .unwrap(),
+    );
+
+    let expanded = key.private_key().to_bytes();
+    assert_eq!(
+        expanded.to_vec(),
+        hex(concat!(
+            "307c83864f2833cb427a2ef1c00a013cfdff2768d980c0a3a520f006904de94f",
+            "9b4f0afe280b746a778684e75442502057b7473a03f08f96f5a38e9287e01f8f"
+        ))
+    );
+
+    let private = PrivateKey::from_expanded(&expanded).unwrap();
+    assert_eq!(private.to_bytes(), expanded);
+    assert_eq!(
+        sign(b"message", key.public_key(), private).to_bytes(),
+        key.sign(b"message").to_bytes()
+    );
+
+    let mut bad = expanded;
+    bad[31] |= 0x80;
+    assert!(matches!(
+        PrivateKey::from_expanded(&bad),
+        Err(Ed25519Error::InvalidPrivateKey)
+    ));
+}
+
+#[test]
+fn test_keypair_bytes_round_trip() {
+    let key = SigningKey::generate();
+    let bytes = key.to_keypair_bytes();
+
+    assert_eq!(bytes[..32], key.to_seed());
+    assert_eq!(bytes[32..], key.public_key().to_bytes());
+
+    let restored = SigningKey::from_keypair_bytes(&bytes).unwrap();
+    assert_eq!(restored.to_seed(), key.to_seed());
+    assert_eq!(
+        restored.private_key().to_bytes(),
+        key.private_key().to_bytes()
+    );
+
+    let mut bad = bytes;
+    bad[40] ^= 1;
+    assert!(matches!(
+        SigningKey::from_keypair_bytes(&bad),
+        Err(Ed25519Error::KeyMismatch)
+    ));
+}
+
+#[test]
+fn test_public_key_validation() {
+    let key = SigningKey::generate();
+    let public = PublicKey::from_bytes(&key.public_key().to_bytes()).unwrap();
+    assert_eq!(public.to_bytes(), key.public_key().to_bytes());
+
+    // y = 2 is not the y-coordinate of any curve point.
+    let mut off_curve = [0u8; 32];
+    off_curve[0] = 2;
+    assert!(matches!(
+        PublicKey::from_bytes(&off_curve),
+        Err(Ed25519Error::InvalidPublicKey)
+    ));
+}
+
+// -------------------------------------------------------
+// STRICT AND ZIP-215 VERIFICATION
+// -------------------------------------------------------
+
+/// The 8-torsion points, canonically encoded, followed by the six
+/// non-canonical encodings of low-order points.
+const SMALL_ORDER_ENCODINGS: [&str; 14] = [
+    "0100000000000000000000000000000000000000000000000000000000000000",
+    "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a",
+    "0000000000000000000000000000000000000000000000000000000000000080",
+    "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc05",
+    "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc85",
+    "0000000000000000000000000000000000000000000000000000000000000000",
+    "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac03fa",
+    "0100000000000000000000000000000000000000000000000000000000000080",
+    "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    "eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+];
+
+fn public_key(s: &str) -> PublicKey {
+    PublicKey::from_bytes(&hex(s).try_into().unwrap()).unwrap()
+}
+
+fn signature(s: &str) -> Signature {
+    Signature::from_bytes(hex(s).try_into().unwrap())
+}
+
+#[test]
+fn test_zip215_small_order_cases() {
+    // With A and R of small order and S = 0, the cofactored equation
+    // always holds: all 196 combinations are valid under ZIP-215, and
+    // all are rejected by the strict mode.
+    for a in SMALL_ORDER_ENCODINGS {
+        for r in SMALL_ORDER_ENCODINGS {
+            let public = public_key(a);
+            let mut bytes = [0u8; 64];
+            bytes[..32].copy_from_slice(&hex(r));
+            let sig = Signature::from_bytes(bytes);
+
+            assert!(verify_zip215(sig, b"Zcash", public), "A={a} R={r}");
+            assert!(!verify_strict(sig, b"Zcash", public), "A={a} R={r}");
+        }
+    }
+}
+
+#[test]
+fn test_non_canonical_s_rejected() {
+    // RFC 8032 test 1 with S replaced by S + ℓ.
+    let public = public_key("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+    let sig = signature(concat!(
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155",
+        "4c8c7872aa064e049dbb3013fbf29380d25bf5f0595bbe24655141438e7a101b"
+    ));
+
+    assert!(!verify_strict(sig, b"", public));
+    assert!(!verify_zip215(sig, b"", public));
+}
+
+#[test]
+fn test_mixed_order_public_key() {
+    // A = a·B + T with T of order 8; the challenge is not a multiple of
+    // 8, so only the cofactored equation holds.
+    let public = public_key("3cf5f6a5afe4ac0aae0026965ca6a7d6448f0ff5ebf291f16860132a3e15bbc9");
+    let sig = signature(concat!(
+        "5866666666666666666666666666666666666666666666666666666666666666",
+        "e0a70e79673b8e643d283f707e22d5914a5d32f5c51bf2f4f0ade3e8e1f48b04"
+    ));
+
+    assert!(verify_zip215(sig, b"mixed order key", public));
+    assert!(!verify_strict(sig, b"mixed order key", public));
+}
+
+#[test]
+fn test_small_order_r() {
+    let public = public_key("753f7ca918f700348681a37ec04d28d1cbc19bbbb218f89079d6ec10cef00360");
+    let sig = signature(concat!(
+        "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc05",
+        "5d7372ab1e9aab4825da88bde07e426d8907a56706d123717f98dec58b500d0b"
+    ));
+
+    assert!(verify_zip215(sig, b"small order R", public));
+    assert!(!verify_strict(sig, b"small order R", public));
+}
+
+#[test]
+fn test_strict_and_zip215_accept_valid_signatures() {
+    let key = SigningKey::generate();
+
+    for len in [0, 1, 32, 1000] {
+        let message = vec![0xa5; len];
+        let sig = key.sign(&message);
+
+        assert!(verify_strict(sig, &message, key.public_key()));
+        assert!(verify_zip215(sig, &message, key.public_key()));
+
+        let mut bytes = sig.to_bytes();
+        bytes[0] ^= 1;
+        let bad = Signature::from_bytes(bytes);
+        assert!(!verify_strict(bad, &message, key.public_key()));
+        assert!(!verify_zip215(bad, &message, key.public_key()));
+    }
+}
+
+// -------------------------------------------------------
+// BATCH VERIFICATION
+// -------------------------------------------------------
+
+fn signed_batch(count: usize) -> (Vec<SigningKey>, Vec<Vec<u8>>, Vec<Signature>) {
+    let keys: Vec<_> = (0..count).map(|_| SigningKey::generate()).collect();
+    let messages: Vec<_> = (0..count).map(|i| vec![i as u8; i]).collect();
+    let signatures = keys
+        .iter()
+        .zip(&messages)
+        .map(|(key, message)| key.sign(message))
+        .collect();
+
+    (keys, messages, signatures)
+}
+
+#[test]
+fn test_batch_all_valid() {
+    let (keys, messages, signatures) = signed_batch(32);
+
+    let items: Vec<_> = (0..32)
+        .map(|i| (signatures[i], &messages[i][..], keys[i].public_key()))
+        .collect();
+
+    assert_eq!(verify_batch(&items), Ok(()));
+    assert_eq!(verify_batch(&items[..1]), Ok(()));
+    assert_eq!(verify_batch(&[]), Ok(()));
+}
+
+#[test]
+fn test_batch_reports_invalid_signatures() {
+    let (keys, messages, mut signatures) = signed_batch(32);
+
+    // Wrong message, corrupted R, and S replaced by S + ℓ.
+    signatures[3] = keys[3].sign(b"other message");
+
+    let mut bytes = signatures[10].to_bytes();
+    bytes[5] ^= 0x40;
+    signatures[10] = Signature::from_bytes(bytes);
+
+    let mut bytes = signatures[31].to_bytes();
+    let mut carry = 0u16;
+    let l = hex("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+    for (b, l) in bytes[32..].iter_mut().zip(&l) {
+        let sum = *b as u16 + *l as u16 + carry;
+        *b = sum as u8;
+        carry = sum >> 8;
+    }
+    signatures[31] = Signature::from_bytes(bytes);
+
+    let items: Vec<_> = (0..32)
+        .map(|i| (signatures[i], &messages[i][..], keys[i].public_key()))
+        .collect();
+
+    assert_eq!(verify_batch(&items), Err(vec![3, 10, 31]));
+}
+
+/// Evaluates `[8] (Σ zᵢ·Sᵢ · B − Σ zᵢ·Rᵢ − Σ (zᵢ·hᵢ)·Aᵢ)` with the
+/// public point API, as in [`verify_batch`].
+fn batch_equation(items: &[(Signature, &[u8], PublicKey)]) -> EdwardsPoint {
+    let mut points = vec![EdwardsPoint::generator()];
+    let mut scalars = vec![Scalar::ZERO];
+
+    for (signature, message, public) in items {
+        let bytes = signature.to_bytes();
+        let r: [u8; 32] = bytes[..32].try_into().unwrap();
+        let s: [u8; 32] = bytes[32..].try_into().unwrap();
+
+        let h = Scalar::from_bytes_mod_order_wide(&sha512(
+            &[&r[..], &public.to_bytes(), message].concat(),
+        ));
+        let z = Scalar::random();
+
+        scalars[0] = scalars[0] + z * Scalar::from_canonical_bytes(&s).unwrap();
+        points.push(EdwardsPoint::from_bytes(&r).unwrap());
+        scalars.push(-z);
+        points.push(EdwardsPoint::from_bytes(&public.to_bytes()).unwrap());
+        scalars.push(-(z * h));
+    }
+
+    EdwardsPoint::vartime_multiscalar_mul(&points, &scalars).mul_by_cofactor()
+}
+
+#[test]
+fn test_batch_equation() {
+    let (keys, messages, mut signatures) = signed_batch(8);
+
+    let items: Vec<_> = (0..8)
+        .map(|i| (signatures[i], &messages[i][..], keys[i].public_key()))
+        .collect();
+    assert!(batch_equation(&items).is_identity());
+
+    // A valid signature over another message
+    signatures[5] = keys[5].sign(b"another message");
+    let items: Vec<_> = (0..8)
+        .map(|i| (signatures[i], &messages[i][..], keys[i].public_key()))
+        .collect();
+    assert!(!batch_equation(&items).is_identity());
+    assert_eq!(verify_batch(&items), Err(vec![5]));
+}
+
+#[test]
+fn test_batch_matches_zip215() {
+    // Small-order cases are valid under ZIP-215, alone or in a batch.
+    let (keys, messages, signatures) = signed_batch(4);
+
+    let mut items: Vec<_> = (0..4)
+        .map(|i| (signatures[i], &messages[i][..], keys[i].public_key()))
+        .collect();
+
+    let zero_sig = Signature::from_bytes([0u8; 64]);
+    for a in &SMALL_ORDER_ENCODINGS[8..] {
+        items.push((zero_sig, b"Zcash", public_key(a)));
+    }
+
+    assert_eq!(verify_batch(&items), Ok(()));
+}
+
+// -------------------------------------------------------
+// RFC 8032 §7.2 (Ed25519ctx) AND §7.3 (Ed25519ph) TEST VECTORS
+// -------------------------------------------------------
+
+fn check_ctx(seed: &str, public: &str, message: &str, context: &str, signature: &str) {
+    let key = SigningKey::from_seed(&hex(seed).try_into().unwrap());
+    let message = hex(message);
+    let context = hex(context);
+
+    assert_eq!(key.public_key().to_bytes().to_vec(), hex(public));
+
+    let sig = sign_ctx(&message, &context, key.public_key(), key.private_key()).unwrap();
+    assert_eq!(sig.to_bytes().to_vec(), hex(signature));
+    assert!(verify_ctx(sig, &message, &context, key.public_key()));
+
+    // Bound to the context, and not a plain Ed25519 signature.
+    assert!(!verify_ctx(sig, &message, b"other", key.public_key()));
+    assert!(!verify(sig, &message, key.public_key()));
+}
+
+#[test]
+fn test_rfc8032_ed25519ctx_foo() {
+    check_ctx(
+        "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+        "f726936d19c800494e3fdaff20b276a8",
+        "666f6f",
+        "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+    );
+}
+
+#[test]
+fn test_rfc8032_ed25519ctx_bar() {
+    check_ctx(
+        "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+        "f726936d19c800494e3fdaff20b276a8",
+        "626172",
+        "fc60d5872fc46b3aa69f8b5b4351d5808f92bcc044606db097abab6dbcb1aee3216c48e8b3b66431b5b186d1d28f8ee15a5ca2df6668346291c2043d4eb3e90d",
+    );
+}
+
+#[test]
+fn test_rfc8032_ed25519ctx_other_message() {
+    check_ctx(
+        "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+        "508e9e6882b979fea900f62adceaca35",
+        "666f6f",
+        "8b70c1cc8310e1de20ac53ce28ae6e7207f33c3295e03bb5c0732a1d20dc64908922a8b052cf99b7c4fe107a5abb5b2c4085ae75890d02df26269d8945f84b0b",
+    );
+}
+
+#[test]
+fn test_rfc8032_ed25519ctx_other_key() {
+    check_ctx(
+        "ab9c2853ce297ddab85c993b3ae14bcad39b2c682beabc27d6d4eb20711d6560",
+        "0f1d1274943b91415889152e893d80e93275a1fc0b65fd71b4b0dda10ad7d772",
+        "f726936d19c800494e3fdaff20b276a8",
+        "666f6f",
+        "21655b5f1aa965996b3f97b3c849eafba922a0a62992f73b3d1b73106a84ad85e9b86a7b6005ea868337ff2d20a7f5fbd4cd10b0be49a68da2b2e0dc0ad8960f",
+    );
+}
+
+#[test]
+fn test_rfc8032_ed25519ph_abc() {
+    let key = SigningKey::from_seed(
+        &hex("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42")
+            .try_into()
+            .unwrap(),
+    );
+    let expected = hex(concat!(
+        "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae41",
+        "31f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406"
+    ));
+
+    assert_eq!(
+        key.public_key().to_bytes().to_vec(),
+        hex("ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf")
+    );
+
+    let prehash = sha512(b"abc");
+    let sig = sign_ph(&prehash, b"", key.public_key(), key.private_key()).unwrap();
+    assert_eq!(sig.to_bytes().to_vec(), expected);
+    assert!(verify_ph(sig, &prehash, b"", key.public_key()));
+
+    // Ed25519ph signs dom2(1, "") || SHA-512(M), never M itself.
+    assert!(!verify(sig, &prehash, key.public_key()));
+    assert!(!verify(sig, b"abc", key.public_key()));
+    assert!(!verify_ph(sig, &prehash, b"ctx", key.public_key()));
+}
+
+#[test]
+fn test_streaming_prehash() {
+    let key = SigningKey::generate();
+    let message: Vec<u8> = (0..10_000).map(|i| (i * 31) as u8).collect();
+
+    let mut prehash = Prehash::new();
+    for chunk in message.chunks(333) {
+        prehash.update(chunk);
+    }
+    let digest = prehash.finalize();
+    assert_eq!(digest, sha512(&message));
+
+    let sig = sign_ph(&digest, b"artefact", key.public_key(), key.private_key()).unwrap();
+    assert!(verify_ph(
+        sig,
+        &sha512(&message),
+        b"artefact",
+        key.public_key()
+    ));
+}
+
+#[test]
+fn test_invalid_contexts() {
+    let key = SigningKey::generate();
+    let long = [0u8; 256];
+    let digest = sha512(b"m");
+
+    assert!(matches!(
+        sign_ctx(b"m", b"", key.public_key(), key.private_key()),
+        Err(Ed25519Error::InvalidContext)
+    ));
+    assert!(matches!(
+        sign_ctx(b"m", &long, key.public_key(), key.private_key()),
+        Err(Ed25519Error::InvalidContext)
+    ));
+    assert!(matches!(
+        sign_ph(&digest, &long, key.public_key(), key.private_key()),
+        Err(Ed25519Error::InvalidContext)
+    ));
+
+    let sig = sign_ctx(b"m", &long[..255], key.public_key(), key.private_key()).unwrap();
+    assert!(verify_ctx(sig, b"m", &long[..255], key.public_key()));
+    assert!(!verify_ctx(sig, b"m", &long, key.public_key()));
+}
+
+// -------------------------------------------------------
+// ED25519 TO X25519 CONVERSION
+// -------------------------------------------------------
+
+#[test]
+fn test_x25519_conversion_libsodium() {
+    // libsodium test/default/ed25519_convert.c
+    let seed: [u8; 32] = hex("421151a459faeade3d247115f94aedae42318124095afabe4d1451a559faedee")
+        .try_into()
+        .unwrap();
+    let key = SigningKey::from_seed(&seed);
+
+    assert_eq!(
+        key.public_key().to_x25519().unwrap().to_bytes().to_vec(),
+        hex("f1814f0e8ff1043d8a44d25babff3cedcae6c22c3edaa48f857ae70de2baae50")
+    );
+    assert_eq!(
+        key.to_x25519().to_bytes().to_vec(),
+        hex("8052030376d47112be7f73ed7a019293dd12ad910b654455798b4667d73de166")
+    );
+}
+
+#[test]
+fn test_x25519_conversion_consistent() {
+    let key = SigningKey::generate();
+    let secret = key.to_x25519();
+
+    assert_eq!(secret.public_key(), key.public_key().to_x25519().unwrap());
+    assert_eq!(secret.to_bytes(), key.private_key().to_x25519().to_bytes());
+
+    let peer = SigningKey::generate();
+    let shared = secret.diffie_hellman(&peer.public_key().to_x25519().unwrap());
+    let peer_shared = peer
+        .to_x25519()
+        .diffie_hellman(&key.public_key().to_x25519().unwrap());
+
+    assert_eq!(shared.to_bytes(), peer_shared.to_bytes());
+    assert_eq!(
+        shared.to_bytes(),
+        exchange(&key.private_key(), &peer.public_key())
+    );
+}
+
+#[test]
+fn test_x25519_conversion_rejects_weak_keys() {
+    // Small-order points.
+    for encoding in SMALL_ORDER_ENCODINGS {
+        let public = public_key(encoding);
+        assert_eq!(
+            public.to_x25519().err(),
+            Some(Ed25519Error::InvalidPublicKey)
+        );
+    }
+
+    // A valid key plus a point of order 8 has a torsion component.
+    let mixed = public_key("3cf5f6a5afe4ac0aae0026965ca6a7d6448f0ff5ebf291f16860132a3e15bbc9");
+    assert_eq!(
+        mixed.to_x25519().err(),
+        Some(Ed25519Error::InvalidPublicKey)
+    );
+}
@@ -0,0 +1,255 @@
+use cryptal::keys::ed25519::{CurveError, EdwardsPoint, Scalar};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().unwrap()
+}
+
+fn scalar(n: u64) -> Scalar {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&n.to_le_bytes());
+    Scalar(bytes)
+}
+
+const ORDER: &str = "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010";
+const ORDER_MINUS_ONE: &str = "ecd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010";
+
+/// `SHA-256("b")`, a full-width value above `ℓ`.
+const B: &str = "3e23e8160039594a33894f6564e1b1348bbd7a0088d42c4acb73eeaed59c009d";
+
+/// A point of order 8.
+const TORSION: &str = "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a";
+
+// -------------------------------------------------------
+// SCALAR ARITHMETIC
+// -------------------------------------------------------
+
+#[test]
+fn test_scalar_arithmetic_unreduced_inputs() {
+    let a = Scalar([0xff; 32]);
+    let b = Scalar(hex32(B));
+
+    assert_eq!(
+        (a + b).to_bytes(),
+        hex32("1871e6026d8b8db04438217ba67aeb2a89bd7a0088d42c4acb73eeaed59c000d")
+    );
+    assert_eq!(
+        (a - b).to_bytes(),
+        hex32("33e554bb617438a5c6c9e2c86343144e744285ff772bd3b5348c11512a63ff02")
+    );
+    assert_eq!(
+        (a * b).to_bytes(),
+        hex32("4d8b2a3c05d357b8b9d9a9a69ac23be8339725c1b7c6a5c0274771761302fe0b")
+    );
+    assert_eq!(
+        (-a).to_bytes(),
+        hex32("d13e5dcfa531268165cd792fea9def4d01000000000000000000000000000000")
+    );
+    assert_eq!(
+        b.invert().to_bytes(),
+        hex32("ef483fafaad86f7723113cbd376a20108ffad20060ad27726dd1e4c7b9c40c0c")
+    );
+}
+
+#[test]
+fn test_scalar_field_laws() {
+    let a = Scalar::random();
+    let b = Scalar::random();
+
+    assert_eq!(a + b - b, a);
+    assert_eq!(a + (-a), Scalar::ZERO);
+    assert_eq!(a * a.invert(), Scalar::ONE);
+    assert_eq!(a * (b + Scalar::ONE), a * b + a);
+    assert_eq!(a - a, Scalar::ZERO);
+    assert!((a - a).is_zero());
+    assert!(Scalar::ZERO.invert().is_zero());
+}
+
+#[test]
+fn test_scalar_equality_is_modulo_order() {
+    assert_eq!(Scalar(hex32(ORDER)), Scalar::ZERO);
+    assert_eq!(Scalar([0xff; 32]).reduced(), Scalar([0xff; 32]));
+    assert_eq!(
+        Scalar([0xff; 32]).reduced().to_bytes(),
+        hex32("1c95988d7431ecd670cf7d73f45befc6feffffffffffffffffffffffffffff0f")
+    );
+    assert_ne!(scalar(1), scalar(2));
+}
+
+#[test]
+fn test_scalar_canonical_decoding() {
+    let max = hex32(ORDER_MINUS_ONE);
+    assert_eq!(Scalar::from_canonical_bytes(&max).unwrap().to_bytes(), max);
+
+    assert_eq!(
+        Scalar::from_canonical_bytes(&hex32(ORDER)),
+        Err(CurveError::NonCanonicalScalar)
+    );
+    assert_eq!(
+        Scalar::from_canonical_bytes(&[0xff; 32]),
+        Err(CurveError::NonCanonicalScalar)
+    );
+
+    assert_eq!(
+        Scalar::from_bytes_mod_order(&hex32(ORDER)).to_bytes(),
+        [0u8; 32]
+    );
+}
+
+#[test]
+fn test_scalar_wide_reduction() {
+    let wide: [u8; 64] = core::array::from_fn(|i| i as u8);
+
+    assert_eq!(
+        Scalar::from_bytes_mod_order_wide(&wide).to_bytes(),
+        hex32("7a3c6282f02d37a05023b60d5428e6cc5961d4c31221937adae0b574e4d07205")
+    );
+}
+
+// -------------------------------------------------------
+// POINT ENCODING
+// -------------------------------------------------------
+
+#[test]
+fn test_generator_encoding() {
+    let g = EdwardsPoint::generator();
+
+    assert_eq!(
+        g.to_bytes(),
+        hex32("5866666666666666666666666666666666666666666666666666666666666666")
+    );
+    assert_eq!(EdwardsPoint::from_bytes(&g.to_bytes()).unwrap(), g);
+    assert_eq!(EdwardsPoint::identity().to_bytes(), scalar(1).to_bytes());
+}
+
+#[test]
+fn test_point_decode_encode_roundtrip() {
+    for n in 0..16 {
+        let p = EdwardsPoint::mul_base(&scalar(n));
+        let bytes = p.to_bytes();
+
+        assert_eq!(EdwardsPoint::from_bytes(&bytes).unwrap(), p);
+        assert_eq!(EdwardsPoint::from_bytes(&bytes).unwrap().to_bytes(), bytes);
+    }
+}
+
+#[test]
+fn test_point_rejects_invalid_encodings() {
+    // y = p (non-canonical encoding of y = 0)
+    let non_canonical = hex32("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+    assert_eq!(
+        EdwardsPoint::from_bytes(&non_canonical),
+        Err(CurveError::InvalidPoint)
+    );
+
+    // y = 1 with the sign bit set: x = 0 cannot be negative
+    let negative_zero = hex32("0100000000000000000000000000000000000000000000000000000000000080");
+    assert_eq!(
+        EdwardsPoint::from_bytes(&negative_zero),
+        Err(CurveError::InvalidPoint)
+    );
+
+    // y = 2 is not on the curve
+    assert_eq!(
+        EdwardsPoint::from_bytes(&scalar(2).to_bytes()),
+        Err(CurveError::InvalidPoint)
+    );
+}
+
+// -------------------------------------------------------
+// POINT ARITHMETIC
+// -------------------------------------------------------
+
+#[test]
+fn test_scalar_mul_matches_reference() {
+    let g = EdwardsPoint::generator();
+    let b = Scalar(hex32(B));
+
+    let seven = hex32("b862409fb5c4c4123df2abf7462b88f041ad36dd6864ce872fd5472be363c5b1");
+    assert_eq!((g * &scalar(7)).to_bytes(), seven);
+    assert_eq!(EdwardsPoint::mul_base(&scalar(7)).to_bytes(), seven);
+
+    let sb = hex32("67eab9d1f6104e22c26f4ba31eea293d0dfd98fa754e9b6501a32e75d6570afd");
+    assert_eq!((g * &b).to_bytes(), sb);
+    assert_eq!(EdwardsPoint::mul_base(&b).to_bytes(), sb);
+}
+
+#[test]
+fn test_group_operations() {
+    let g = EdwardsPoint::generator();
+    let p = EdwardsPoint::mul_base(&Scalar::random());
+
+    assert_eq!(g + g, g * &scalar(2));
+    assert_eq!(p + g - g, p);
+    assert_eq!(p + (-p), EdwardsPoint::identity());
+    assert_eq!(p - p, EdwardsPoint::identity());
+    assert!((p - p).is_identity());
+    assert!(!p.is_identity());
+    assert!((g * &Scalar(hex32(ORDER))).is_identity());
+}
+
+#[test]
+fn test_vartime_multiscalar_mul() {
+    let a = Scalar::random();
+    let b = Scalar::random();
+    let c = Scalar([0xff; 32]);
+
+    let p = EdwardsPoint::mul_base(&Scalar::random());
+    let q = EdwardsPoint::mul_base(&Scalar::random());
+    let g = EdwardsPoint::generator();
+
+    assert_eq!(
+        EdwardsPoint::vartime_multiscalar_mul(&[p, q, g], &[a, b, c]),
+        p * &a + q * &b + g * &c
+    );
+    assert_eq!(
+        EdwardsPoint::vartime_multiscalar_mul(&[], &[]),
+        EdwardsPoint::identity()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_vartime_multiscalar_mul_length_mismatch() {
+    let g = EdwardsPoint::generator();
+    EdwardsPoint::vartime_multiscalar_mul(&[g, g], &[Scalar::ONE]);
+}
+
+// -------------------------------------------------------
+// COFACTOR AND SMALL ORDER
+// -------------------------------------------------------
+
+#[test]
+fn test_small_order_and_torsion() {
+    let t = EdwardsPoint::from_bytes(&hex32(TORSION)).unwrap();
+    let g = EdwardsPoint::generator();
+
+    assert!(t.is_small_order());
+    assert!(!t.is_torsion_free());
+    assert!(t.mul_by_cofactor().is_identity());
+    assert!(EdwardsPoint::identity().is_small_order());
+
+    assert!(!g.is_small_order());
+    assert!(g.is_torsion_free());
+
+    // 5·B + T: not small order, not torsion-free; clearing the
+    // cofactor yields 40·B.
+    let mixed = g * &scalar(5) + t;
+    assert_eq!(
+        mixed.to_bytes(),
+        hex32("d0424b701d60255de5df705071059ccd22b8349bb4138202ebbd65e9e0ad211b")
+    );
+    assert!(!mixed.is_small_order());
+    assert!(!mixed.is_torsion_free());
+    assert_eq!(
+        mixed.mul_by_cofactor().to_bytes(),
+        hex32("94b367cbebbe9f22caf381284770a6012b92119c3818a3368672c6cdb5630bc2")
+    );
+    assert!(mixed.mul_by_cofactor().is_torsion_free());
+}
@@ -0,0 +1,178 @@
+use cryptal::encryption::envelope::{
+    Algorithm, Envelope, EnvelopeError, HEADER_LEN, Header, TAG_LEN, open, seal,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn key() -> [u8; 32] {
+    core::array::from_fn(|i| i as u8)
+}
+
+const ALGORITHMS: [Algorithm; 3] = [
+    Algorithm::ChaCha20Poly1305,
+    Algorithm::Aes256Gcm,
+    Algorithm::Aes256GcmSiv,
+];
+
+// -------------------------------------------------------
+// KNOWN-ANSWER ENVELOPES
+// -------------------------------------------------------
+//
+// Key 00..1f, key id 0x01020304, nonce 40..4b, AAD "ctx",
+// plaintext "envelope test".
+
+#[test]
+fn test_open_known_envelopes() {
+    let envelopes = [
+        (
+            Algorithm::ChaCha20Poly1305,
+            "43525054010101020304404142434445464748494a4b9d3a0ae41e259e6478bb9373cff9d7199373445c4ad60e7e4298b69b43",
+        ),
+        (
+            Algorithm::Aes256Gcm,
+            "43525054010201020304404142434445464748494a4b87d7d8464a53f766edb07245ef6958212ec3f13a1ca96d0b4bac537fb5",
+        ),
+    ];
+
+    for (algorithm, bytes) in envelopes {
+        let bytes = hex(bytes);
+        let envelope = Envelope::parse(&bytes).unwrap();
+
+        assert_eq!(
+            envelope.header,
+            Header {
+                algorithm,
+                key_id: 0x0102_0304,
+                nonce: core::array::from_fn(|i| 0x40 + i as u8),
+            }
+        );
+        assert_eq!(envelope.open(&key(), b"ctx").unwrap(), b"envelope test");
+    }
+}
+
+// -------------------------------------------------------
+// ROUND TRIPS
+// -------------------------------------------------------
+
+#[test]
+fn test_round_trip_all_algorithms() {
+    for algorithm in ALGORITHMS {
+        for len in [0, 1, 15, 16, 17, 100] {
+            let plaintext = vec![0xabu8; len];
+            let sealed = seal(algorithm, 42, &key(), b"aad", &plaintext).unwrap();
+
+            assert_eq!(sealed.len(), HEADER_LEN + len + TAG_LEN);
+
+            let envelope = Envelope::parse(&sealed).unwrap();
+            assert_eq!(envelope.header.algorithm, algorithm);
+            assert_eq!(envelope.header.key_id, 42);
+            assert_eq!(envelope.header.to_bytes(), sealed[..HEADER_LEN]);
+
+            assert_eq!(open(&key(), b"aad", &sealed).unwrap(), plaintext);
+        }
+    }
+}
+
+#[test]
+fn test_nonces_are_fresh() {
+    let a = seal(Algorithm::ChaCha20Poly1305, 1, &key(), b"", b"same").unwrap();
+    let b = seal(Algorithm::ChaCha20Poly1305, 1, &key(), b"", b"same").unwrap();
+
+    assert_ne!(a[10..HEADER_LEN], b[10..HEADER_LEN]);
+    assert_ne!(a, b);
+}
+
+// -------------------------------------------------------
+// PARSING
+// -------------------------------------------------------
+
+#[test]
+fn test_parse_rejects_malformed_framing() {
+    let sealed = seal(Algorithm::Aes256Gcm, 7, &key(), b"", b"msg").unwrap();
+
+    assert_eq!(
+        Envelope::parse(&sealed[..HEADER_LEN + TAG_LEN - 1]),
+        Err(EnvelopeError::Truncated)
+    );
+
+    let mut bad = sealed.clone();
+    bad[0] ^= 1;
+    assert_eq!(Envelope::parse(&bad), Err(EnvelopeError::InvalidMagic));
+
+    for version in [0u8, 2, 0xff] {
+        let mut bad = sealed.clone();
+        bad[4] = version;
+        assert_eq!(
+            Envelope::parse(&bad),
+            Err(EnvelopeError::UnsupportedVersion)
+        );
+    }
+
+    for algorithm in [0u8, 4, 0xff] {
+        let mut bad = sealed.clone();
+        bad[5] = algorithm;
+        assert_eq!(Envelope::parse(&bad), Err(EnvelopeError::UnknownAlgorithm));
+    }
+}
+
+// -------------------------------------------------------
+// AUTHENTICATION
+// -------------------------------------------------------
+
+#[test]
+fn test_header_is_authenticated() {
+    for algorithm in ALGORITHMS {
+        let sealed = seal(algorithm, 7, &key(), b"", b"message").unwrap();
+
+        // Key id and nonce bytes.
+        for i in 6..HEADER_LEN {
+            let mut bad = sealed.clone();
+            bad[i] ^= 0x01;
+            assert_eq!(
+                open(&key(), b"", &bad),
+                Err(EnvelopeError::AuthenticationFailed)
+            );
+        }
+
+        // Switching to another known algorithm.
+        let mut bad = sealed.clone();
+        bad[5] = if algorithm.id() == 1 { 2 } else { 1 };
+        assert_eq!(
+            open(&key(), b"", &bad),
+            Err(EnvelopeError::AuthenticationFailed)
+        );
+    }
+}
+
+#[test]
+fn test_open_rejects_wrong_key_aad_or_payload() {
+    for algorithm in ALGORITHMS {
+        let sealed = seal(algorithm, 7, &key(), b"aad", b"message").unwrap();
+
+        let mut other_key = key();
+        other_key[0] ^= 1;
+        assert_eq!(
+            open(&other_key, b"aad", &sealed),
+            Err(EnvelopeError::AuthenticationFailed)
+        );
+
+        assert_eq!(
+            open(&key(), b"other", &sealed),
+            Err(EnvelopeError::AuthenticationFailed)
+        );
+
+        for i in HEADER_LEN..sealed.len() {
+            let mut bad = sealed.clone();
+            bad[i] ^= 0x80;
+            assert_eq!(
+                open(&key(), b"aad", &bad),
+                Err(EnvelopeError::AuthenticationFailed)
+            );
+        }
+    }
+}
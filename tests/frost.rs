@@ -0,0 +1,324 @@
+use cryptal::keys::ed25519::{Scalar, SigningKey, verify};
+use cryptal::keys::frost::{
+    FrostError, KeyPackage, PublicKeyPackage, SignatureShare, SigningCommitments, aggregate,
+    commit, commit_with_randomness, generate, sign, split, split_with_coefficients,
+    verify_signature_share,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().unwrap()
+}
+
+fn scalar(s: &str) -> Scalar {
+    Scalar::from_canonical_bytes(&hex32(s)).unwrap()
+}
+
+// -------------------------------------------------------
+// RFC 9591 APPENDIX E.1: FROST(Ed25519, SHA-512)
+// -------------------------------------------------------
+
+#[test]
+fn test_frost_rfc9591_vector() {
+    let secret = scalar("7b1c33d3f5291d85de664833beb1ad469f7fb6025a0ec78b3a790c6e13a98304");
+    let coefficient = scalar("178199860edd8c62f5212ee91eff1295d0d670ab4ed4506866bae57e7030b204");
+    let message = hex("74657374");
+
+    let (keys, public) = split_with_coefficients(&secret, &[coefficient], 3).unwrap();
+    assert_eq!(
+        public.group_public_key().to_bytes(),
+        hex32("15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673")
+    );
+
+    let shares = [
+        "929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509",
+        "a91e66e012e4364ac9aaa405fcafd370402d9859f7b6685c07eed76bf409e80d",
+        "d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02",
+    ];
+    for (key, share) in keys.iter().zip(shares) {
+        assert_eq!(key.signing_share().to_bytes(), hex32(share));
+    }
+
+    // Participants 1 and 3
+    let n1 = commit_with_randomness(
+        &keys[0],
+        &hex32("0fd2e39e111cdc266f6c0f4d0fd45c947761f1f5d3cb583dfcb9bbaf8d4c9fec"),
+        &hex32("69cd85f631d5f7f2721ed5e40519b1366f340a87c2f6856363dbdcda348a7501"),
+    );
+    let n3 = commit_with_randomness(
+        &keys[2],
+        &hex32("86d64a260059e495d0fb4fcc17ea3da7452391baa494d4b00321098ed2a0062f"),
+        &hex32("13e6b25afb2eba51716a9a7d44130c0dbae0004a9ef8d7b5550c8a0e07c61775"),
+    );
+
+    assert_eq!(
+        n1.commitments().to_bytes().to_vec(),
+        hex(
+            "b5aa8ab305882a6fc69cbee9327e5a45e54c08af61ae77cb8207be3d2ce13de3\
+             67e98ab55aa310c3120418e5050c9cf76cf387cb20ac9e4b6fdb6f82a469f932"
+        )
+    );
+    assert_eq!(
+        n3.commitments().to_bytes().to_vec(),
+        hex(
+            "cfbdb165bd8aad6eb79deb8d287bcc0ab6658ae57fdcc98ed12c0669e90aec91\
+             7487bc41a6e712eea2f2af24681b58b1cf1da278ea11fe4e8b78398965f13552"
+        )
+    );
+
+    let commitments = [n1.commitments(), n3.commitments()];
+    let s1 = sign(&keys[0], n1, &message, &commitments).unwrap();
+    let s3 = sign(&keys[2], n3, &message, &commitments).unwrap();
+
+    assert_eq!(
+        s1.to_bytes(),
+        hex32("001719ab5a53ee1a12095cd088fd149702c0720ce5fd2f29dbecf24b7281b603")
+    );
+    assert_eq!(
+        s3.to_bytes(),
+        hex32("bd86125de990acc5e1f13781d8e32c03a9bbd4c53539bbc106058bfd14326007")
+    );
+
+    assert!(verify_signature_share(&s1, &public, &message, &commitments));
+    assert!(verify_signature_share(&s3, &public, &message, &commitments));
+
+    let signature = aggregate(&message, &commitments, &[s1, s3], &public).unwrap();
+    assert_eq!(
+        signature.to_bytes().to_vec(),
+        hex(
+            "36282629c383bb820a88b71cae937d41f2f2adfcc3d02e55507e2fb9e2dd3cbe\
+             bd9d2b0844e49ae0f3fa935161e1419aab7b47d21a37ebeae1f17d4987b3160b"
+        )
+    );
+    assert!(verify(signature, &message, public.group_public_key()));
+}
+
+// -------------------------------------------------------
+// ROUND TRIP
+// -------------------------------------------------------
+
+#[test]
+fn test_frost_every_subset_signs() {
+    let (keys, public) = generate(3, 5).unwrap();
+    let message = b"threshold";
+
+    for mask in 0u32..32 {
+        if mask.count_ones() < 3 {
+            continue;
+        }
+        let signers: Vec<_> = keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| mask & (1 << i) != 0)
+            .map(|(_, key)| key)
+            .collect();
+
+        let nonces: Vec<_> = signers.iter().map(|key| commit(key)).collect();
+        let commitments: Vec<_> = nonces.iter().map(|n| n.commitments()).collect();
+
+        let shares: Vec<_> = signers
+            .iter()
+            .zip(nonces)
+            .map(|(key, n)| sign(key, n, message, &commitments).unwrap())
+            .collect();
+
+        let signature = aggregate(message, &commitments, &shares, &public).unwrap();
+        assert!(verify(signature, message, public.group_public_key()));
+    }
+}
+
+#[test]
+fn test_frost_split_keeps_public_key() {
+    let key = SigningKey::generate();
+    let (keys, public) = split(key.private_key(), 2, 3).unwrap();
+
+    assert_eq!(
+        public.group_public_key().to_bytes(),
+        key.public_key().to_bytes()
+    );
+
+    let signers = [&keys[1], &keys[2]];
+    let nonces: Vec<_> = signers.iter().map(|key| commit(key)).collect();
+    let commitments: Vec<_> = nonces.iter().map(|n| n.commitments()).collect();
+    let shares: Vec<_> = signers
+        .iter()
+        .zip(nonces)
+        .map(|(key, n)| sign(key, n, b"message", &commitments).unwrap())
+        .collect();
+
+    let signature = aggregate(b"message", &commitments, &shares, &public).unwrap();
+    assert!(verify(signature, b"message", key.public_key()));
+}
+
+#[test]
+fn test_frost_commitments_roundtrip() {
+    let (keys, _) = generate(2, 2).unwrap();
+    let commitments = commit(&keys[0]).commitments();
+
+    let decoded = SigningCommitments::from_bytes(1, &commitments.to_bytes()).unwrap();
+    assert_eq!(decoded, commitments);
+}
+
+// -------------------------------------------------------
+// REJECTION
+// -------------------------------------------------------
+
+#[test]
+fn test_frost_identifies_bad_share() {
+    let (keys, public) = generate(2, 3).unwrap();
+    let message = b"message";
+
+    let n1 = commit(&keys[0]);
+    let n2 = commit(&keys[1]);
+    let commitments = [n1.commitments(), n2.commitments()];
+
+    let s1 = sign(&keys[0], n1, message, &commitments).unwrap();
+    let s2 = sign(&keys[1], n2, message, &commitments).unwrap();
+
+    let mut bytes = s2.to_bytes();
+    bytes[0] ^= 1;
+    let forged = SignatureShare::from_bytes(2, &bytes).unwrap();
+
+    assert!(verify_signature_share(&s1, &public, message, &commitments));
+    assert!(!verify_signature_share(
+        &forged,
+        &public,
+        message,
+        &commitments
+    ));
+    assert!(!verify_signature_share(
+        &s1,
+        &public,
+        b"other",
+        &commitments
+    ));
+
+    assert_eq!(
+        aggregate(message, &commitments, &[s1, forged], &public).err(),
+        Some(FrostError::InvalidSignatureShare(2))
+    );
+}
+
+#[test]
+fn test_frost_rejects_malformed_commitment_lists() {
+    let (keys, public) = generate(2, 3).unwrap();
+
+    let n1 = commit(&keys[0]);
+    let c1 = n1.commitments();
+    let c2 = commit(&keys[1]).commitments();
+    let c3 = commit(&keys[2]).commitments();
+
+    assert_eq!(
+        sign(&keys[0], commit(&keys[0]), b"m", &[c1]).err(),
+        Some(FrostError::NotEnoughSigners)
+    );
+    assert_eq!(
+        sign(&keys[0], commit(&keys[0]), b"m", &[c1, c1]).err(),
+        Some(FrostError::DuplicateIdentifier)
+    );
+    assert_eq!(
+        sign(&keys[0], commit(&keys[0]), b"m", &[c2, c3]).err(),
+        Some(FrostError::UnknownIdentifier)
+    );
+    // Nonces that do not match the signer's commitment
+    assert_eq!(
+        sign(&keys[0], commit(&keys[0]), b"m", &[c1, c2]).err(),
+        Some(FrostError::InvalidCommitment)
+    );
+
+    let s1 = sign(&keys[0], n1, b"m", &[c1, c2]).unwrap();
+    assert_eq!(
+        aggregate(b"m", &[c1, c2], &[s1], &public).err(),
+        Some(FrostError::NotEnoughSigners)
+    );
+    assert_eq!(
+        aggregate(b"m", &[c1, c2], &[s1, s1], &public).err(),
+        Some(FrostError::DuplicateIdentifier)
+    );
+}
+
+#[test]
+fn test_frost_rejects_invalid_parameters() {
+    assert_eq!(generate(1, 3).err(), Some(FrostError::InvalidThreshold));
+    assert_eq!(generate(4, 3).err(), Some(FrostError::InvalidThreshold));
+    assert_eq!(
+        split_with_coefficients(&Scalar::ONE, &[], 3).err(),
+        Some(FrostError::InvalidThreshold)
+    );
+
+    // Identity and a point of order 8
+    let mut bytes = [0u8; 64];
+    bytes[0] = 1;
+    bytes[32..].copy_from_slice(&hex32(
+        "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a",
+    ));
+    assert_eq!(
+        SigningCommitments::from_bytes(1, &bytes).err(),
+        Some(FrostError::InvalidCommitment)
+    );
+    assert_eq!(
+        SigningCommitments::from_bytes(0, &bytes).err(),
+        Some(FrostError::InvalidIdentifier)
+    );
+
+    let q = hex32("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+    assert_eq!(
+        SignatureShare::from_bytes(1, &q).err(),
+        Some(FrostError::InvalidShare)
+    );
+}
+
+#[test]
+fn test_frost_rejects_inconsistent_public_key() {
+    let (keys, public) = generate(2, 3).unwrap();
+    let (_, other) = generate(2, 3).unwrap();
+
+    let shares: Vec<_> = keys
+        .iter()
+        .map(|key| (key.identifier(), key.verifying_share()))
+        .collect();
+
+    assert!(PublicKeyPackage::new(&shares, public.group_public_key(), 2).is_ok());
+    assert_eq!(
+        PublicKeyPackage::new(&shares, other.group_public_key(), 2).err(),
+        Some(FrostError::InconsistentPublicKey)
+    );
+
+    // Split with a threshold of 3, announced as 2
+    let (keys3, public3) = generate(3, 3).unwrap();
+    let shares3: Vec<_> = keys3
+        .iter()
+        .map(|key| (key.identifier(), key.verifying_share()))
+        .collect();
+    assert_eq!(
+        PublicKeyPackage::new(&shares3, public3.group_public_key(), 2).err(),
+        Some(FrostError::InconsistentPublicKey)
+    );
+
+    // A third share from another split: every share verifies on its own,
+    // but the signature does not.
+    let (stranger, _) = generate(2, 3).unwrap();
+    let third =
+        KeyPackage::new(3, stranger[2].signing_share(), public.group_public_key(), 2).unwrap();
+    let mixed = [shares[0], shares[1], (3, third.verifying_share())];
+    let package = PublicKeyPackage::new(&mixed, public.group_public_key(), 2).unwrap();
+
+    let n1 = commit(&keys[0]);
+    let n3 = commit(&third);
+    let commitments = [n1.commitments(), n3.commitments()];
+    let s1 = sign(&keys[0], n1, b"m", &commitments).unwrap();
+    let s3 = sign(&third, n3, b"m", &commitments).unwrap();
+
+    assert!(verify_signature_share(&s1, &package, b"m", &commitments));
+    assert!(verify_signature_share(&s3, &package, b"m", &commitments));
+    assert_eq!(
+        aggregate(b"m", &commitments, &[s1, s3], &package).err(),
+        Some(FrostError::InconsistentPublicKey)
+    );
+}
@@ -0,0 +1,159 @@
+use cryptal::keys::ed25519::{
+    EdwardsPoint, HashToCurveError, SUITE_NU, SUITE_RO, encode_to_curve, expand_message_xmd,
+    hash_to_curve,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().unwrap()
+}
+
+fn messages() -> [Vec<u8>; 5] {
+    [
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        [b"q128_".as_slice(), &[b'q'; 128]].concat(),
+        [b"a512_".as_slice(), &[b'a'; 512]].concat(),
+    ]
+}
+
+// -------------------------------------------------------
+// RFC 9380 APPENDIX K.3: expand_message_xmd(SHA-512)
+// -------------------------------------------------------
+
+const EXPANDER_DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA512-256";
+
+#[test]
+fn test_expand_message_xmd_32() {
+    let expected = [
+        "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+        "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+        "087e45a86e2939ee8b91100af1583c4938e0f5fc6c9db4b107b83346bc967f58",
+        "7336234ee9983902440f6bc35b348352013becd88938d2afec44311caf8356b3",
+        "57b5f7e766d5be68a6bfe1768e3c2b7f1228b3e4b3134956dd73a59b954c66f4",
+    ];
+
+    for (msg, expected) in messages().iter().zip(expected) {
+        assert_eq!(
+            expand_message_xmd(msg, EXPANDER_DST, 32).unwrap(),
+            hex(expected)
+        );
+    }
+}
+
+#[test]
+fn test_expand_message_xmd_128() {
+    let expected = hex(concat!(
+        "41b037d1734a5f8df225dd8c7de38f851efdb45c372887be655212d07251b921",
+        "b052b62eaed99b46f72f2ef4cc96bfaf254ebbbec091e1a3b9e4fb5e5b619d2e",
+        "0c5414800a1d882b62bb5cd1778f098b8eb6cb399d5d9d18f5d5842cf5d13d7e",
+        "b00a7cff859b605da678b318bd0e65ebff70bec88c753b159a805d2c89c55961",
+    ));
+
+    assert_eq!(
+        expand_message_xmd(b"", EXPANDER_DST, 128).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_expand_message_xmd_lengths() {
+    let long = expand_message_xmd(b"abc", EXPANDER_DST, 255 * 64).unwrap();
+    assert_eq!(long.len(), 255 * 64);
+
+    // Outputs of different lengths share no prefix: the length is bound
+    // into b_0.
+    let short = expand_message_xmd(b"abc", EXPANDER_DST, 33).unwrap();
+    assert_ne!(short[..32], long[..32]);
+
+    assert_eq!(
+        expand_message_xmd(b"abc", EXPANDER_DST, 255 * 64 + 1),
+        Err(HashToCurveError::InvalidLength)
+    );
+    assert_eq!(
+        expand_message_xmd(b"abc", b"", 32),
+        Err(HashToCurveError::InvalidDst)
+    );
+}
+
+#[test]
+fn test_expand_message_xmd_oversize_dst() {
+    let dst = [b'x'; 256];
+
+    // A DST over 255 bytes is replaced by H("H2C-OVERSIZE-DST-" || DST).
+    let mut hashed_input = b"H2C-OVERSIZE-DST-".to_vec();
+    hashed_input.extend_from_slice(&dst);
+    let hashed = cryptal::hash::sha512(&hashed_input);
+
+    assert_eq!(
+        expand_message_xmd(b"abc", &dst, 64).unwrap(),
+        expand_message_xmd(b"abc", &hashed, 64).unwrap()
+    );
+}
+
+// -------------------------------------------------------
+// RFC 9380 APPENDIX J.5: edwards25519 SUITES
+// -------------------------------------------------------
+
+#[test]
+fn test_hash_to_curve_ro() {
+    let dst = format!("QUUX-V01-CS02-with-{SUITE_RO}");
+    let expected = [
+        "21dc15e10253796df23a7699c8a383ea624cce88c52431f6be220b1a56c8a609",
+        "31558a26887f23fb8218f143e69d5f0af2e7831130bd5b432ef23883b895839a",
+        "a661c58eea707f2171dd1a8a641e41758ac842cfd31e64dabc7f0e143d0a0653",
+        "f7d2895eea2ef7b737ed56594f99e238a1eeb0dd672f98d239fafc55e315ca2e",
+        "95f9d827f3c0f8076af227f01fef51d0cc924fb1806a237fc2c566f204fcc26d",
+    ];
+
+    for (msg, expected) in messages().iter().zip(expected) {
+        let point = hash_to_curve(msg, dst.as_bytes()).unwrap();
+        assert_eq!(point.to_bytes(), hex32(expected));
+        assert!(point.is_torsion_free());
+    }
+}
+
+#[test]
+fn test_encode_to_curve_nu() {
+    let dst = format!("QUUX-V01-CS02-with-{SUITE_NU}");
+    let expected = [
+        "9b0f7f682dabce2190b14e21a175f39eb6a6b29fff2a9f5e72d5a4044d312e22",
+        "42fa27c8f5a1ae0aa38bb59d5938e5145622ba5dedd11d11736fa2f9502d7367",
+        "fb861a8e0a5a954a5c6836d379f1b07775134a6adaca0939e7dd1add246c8aaf",
+        "5034607af591cadcb883b05846079a27c2b46c29f474078b12baebf56efff6aa",
+        "371a8945427accbf317cc92c1607d3cd62325fb34134d391f28fb19ed3c390ac",
+    ];
+
+    for (msg, expected) in messages().iter().zip(expected) {
+        let point = encode_to_curve(msg, dst.as_bytes()).unwrap();
+        assert_eq!(point.to_bytes(), hex32(expected));
+        assert!(point.is_torsion_free());
+    }
+}
+
+#[test]
+fn test_domain_separation() {
+    let a = hash_to_curve(b"msg", b"APP-A").unwrap();
+    let b = hash_to_curve(b"msg", b"APP-B").unwrap();
+    let c = encode_to_curve(b"msg", b"APP-A").unwrap();
+
+    assert_ne!(a, b);
+    assert_ne!(a, c);
+    assert_ne!(a, EdwardsPoint::identity());
+
+    assert_eq!(
+        hash_to_curve(b"msg", b""),
+        Err(HashToCurveError::InvalidDst)
+    );
+    assert_eq!(
+        encode_to_curve(b"msg", b""),
+        Err(HashToCurveError::InvalidDst)
+    );
+}
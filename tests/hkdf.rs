@@ -0,0 +1,99 @@
+use cryptal::derivation::{HKDF_MAX_OUTPUT, HkdfError, hkdf, hkdf_expand, hkdf_extract};
+use cryptal::hash::hmac_sha256;
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// -------------------------------------------------------
+// RFC 4231 HMAC-SHA256 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn test_hmac_sha256_rfc4231() {
+    assert_eq!(
+        hmac_sha256(&[0x0b; 20], b"Hi There").to_vec(),
+        hex("b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7")
+    );
+
+    assert_eq!(
+        hmac_sha256(b"Jefe", b"what do ya want for nothing?").to_vec(),
+        hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+    );
+
+    assert_eq!(
+        hmac_sha256(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        )
+        .to_vec(),
+        hex("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+    );
+}
+
+// -------------------------------------------------------
+// RFC 5869 HKDF-SHA256 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn test_hkdf_rfc5869_case1() {
+    let ikm = [0x0b; 22];
+    let salt = hex("000102030405060708090a0b0c");
+    let info = hex("f0f1f2f3f4f5f6f7f8f9");
+
+    let prk = hkdf_extract(&salt, &ikm);
+    assert_eq!(
+        prk.to_vec(),
+        hex("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5")
+    );
+
+    let okm = hkdf_expand(&prk, &info, 42).unwrap();
+    assert_eq!(
+        okm,
+        hex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")
+    );
+}
+
+#[test]
+fn test_hkdf_rfc5869_case2_long_inputs() {
+    let ikm: Vec<u8> = (0x00..=0x4f).collect();
+    let salt: Vec<u8> = (0x60..=0xaf).collect();
+    let info: Vec<u8> = (0xb0..=0xff).collect();
+
+    let okm = hkdf(&salt, &ikm, &info, 82).unwrap();
+    assert_eq!(
+        okm,
+        hex(
+            "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c\
+             59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71\
+             cc30c58179ec3e87c14c01d5c1f3434f1d87"
+        )
+    );
+}
+
+#[test]
+fn test_hkdf_rfc5869_case3_empty_salt_and_info() {
+    let okm = hkdf(&[], &[0x0b; 22], &[], 42).unwrap();
+    assert_eq!(
+        okm,
+        hex("8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8")
+    );
+}
+
+#[test]
+fn test_hkdf_output_limit() {
+    let prk = [1u8; 32];
+
+    assert_eq!(
+        hkdf_expand(&prk, b"info", HKDF_MAX_OUTPUT).unwrap().len(),
+        HKDF_MAX_OUTPUT
+    );
+    assert_eq!(
+        hkdf_expand(&prk, b"info", HKDF_MAX_OUTPUT + 1),
+        Err(HkdfError::OutputTooLong)
+    );
+    assert!(hkdf_expand(&prk, b"info", 0).unwrap().is_empty());
+}
@@ -0,0 +1,420 @@
+use cryptal::encryption::hpke::{
+    HpkeError, Psk, derive_key_pair, generate_key_pair, open, seal, setup_receiver, setup_sender,
+    setup_sender_with_ikm,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().unwrap()
+}
+
+// -------------------------------------------------------
+// SHARED INPUTS (RFC 9180, APPENDIX A.2)
+// -------------------------------------------------------
+
+const INFO: &str = "4f6465206f6e2061204772656369616e2055726e";
+const PT: &str = "4265617574792069732074727574682c20747275746820626561757479";
+
+const PSK: &str = "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82";
+const PSK_ID: &str = "456e6e796e20447572696e206172616e204d6f726961";
+
+// A.2.1
+const IKM_E: &str = "909a9b35d3dc4713a5e72a4da274b55d3d3821a37e5d099e74a647db583a904b";
+const IKM_R: &str = "1ac01f181fdf9f352797655161c58b75c656a6cc2716dcb66372da835542e1df";
+const SK_R: &str = "8057991eef8f1f1af18f4a9491d16a1ce333f695d4db8e38da75975c4478e0fb";
+const PK_R: &str = "4310ee97d88cc1f088a5576c77ab0cf5c3ac797f3d95139c6c84b5429c59662a";
+const ENC: &str = "1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a";
+
+/// Key material of a vector.
+struct Setup {
+    ikm_e: &'static str,
+    ikm_r: &'static str,
+    sk_r: &'static str,
+    pk_r: &'static str,
+    enc: &'static str,
+}
+
+/// Runs a deterministic sender / receiver pair and checks the derived
+/// keys, the encapsulated key, the first ciphertexts and the
+/// `"TestContext"` export.
+///
+/// The key schedule (key, base nonce, exporter secret) is not exposed;
+/// it is covered by the ciphertexts and the export.
+fn check_mode(setup: &Setup, psk: Option<Psk<'_>>, cts: &[(u64, &str)], export: &str) {
+    let (pk, sk) = derive_key_pair(&hex(setup.ikm_r));
+    assert_eq!(sk.to_vec(), hex(setup.sk_r));
+    assert_eq!(pk.to_vec(), hex(setup.pk_r));
+
+    let info = hex(INFO);
+    let pt = hex(PT);
+
+    let (enc_bytes, mut sender) =
+        setup_sender_with_ikm(&hex(setup.ikm_e), &pk, &info, psk, None).unwrap();
+    assert_eq!(enc_bytes.to_vec(), hex(setup.enc));
+
+    let mut receiver = setup_receiver(&enc_bytes, &sk, &info, psk, None).unwrap();
+
+    let mut expected = cts.iter().peekable();
+    for seq in 0..=cts.last().unwrap().0 {
+        let aad = format!("Count-{seq}");
+        let ct = sender.seal(aad.as_bytes(), &pt).unwrap();
+
+        if let Some((_, want)) = expected.next_if(|(s, _)| *s == seq) {
+            assert_eq!(ct, hex(want), "ciphertext mismatch at seq {seq}");
+        }
+
+        assert_eq!(receiver.open(aad.as_bytes(), &ct).unwrap(), pt);
+    }
+
+    assert_eq!(sender.export(b"TestContext", 32).unwrap(), hex(export));
+    assert_eq!(receiver.export(b"TestContext", 32).unwrap(), hex(export));
+}
+
+// -------------------------------------------------------
+// RFC 9180 A.2.1 (BASE MODE)
+// -------------------------------------------------------
+
+#[test]
+fn test_derive_key_pair() {
+    let (pk, sk) = derive_key_pair(&hex(
+        "6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037",
+    ));
+
+    assert_eq!(
+        sk.to_vec(),
+        hex("4612c550263fc8ad58375df3f557aac531d26850903e55a9f23f21d8534e8ac8")
+    );
+    assert_eq!(
+        pk.to_vec(),
+        hex("3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d")
+    );
+}
+
+#[test]
+fn test_base_mode_vector() {
+    let setup = Setup {
+        ikm_e: IKM_E,
+        ikm_r: IKM_R,
+        sk_r: SK_R,
+        pk_r: PK_R,
+        enc: ENC,
+    };
+
+    check_mode(
+        &setup,
+        None,
+        &[
+            (
+                0,
+                "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db21993c62ce81883d2dd1b51a28",
+            ),
+            (
+                255,
+                "18ab939d63ddec9f6ac2b60d61d36a7375d2070c9b683861110757062c52b8880a5f6b3936da9cd6c23ef2a95c",
+            ),
+            (
+                256,
+                "7a4a13e9ef23978e2c520fd4d2e757514ae160cd0cd05e556ef692370ca53076214c0c40d4c728d6ed9e727a5b",
+            ),
+        ],
+        "5acb09211139c43b3090489a9da433e8a30ee7188ba8b0a9a1ccf0c229283e53",
+    );
+}
+
+#[test]
+fn test_base_mode_exports() {
+    let (_, sender) =
+        setup_sender_with_ikm(&hex(IKM_E), &hex32(PK_R), &hex(INFO), None, None).unwrap();
+
+    assert_eq!(
+        sender.export(b"", 32).unwrap(),
+        hex("4bbd6243b8bb54cec311fac9df81841b6fd61f56538a775e7c80a9f40160606e")
+    );
+    assert_eq!(
+        sender.export(&[0x00], 32).unwrap(),
+        hex("8c1df14732580e5501b00f82b10a1647b40713191b7c1240ac80e2b68808ba69")
+    );
+}
+
+// -------------------------------------------------------
+// RFC 9180 A.2.2 (PSK MODE)
+// -------------------------------------------------------
+
+const PSK_SETUP: Setup = Setup {
+    ikm_e: "35706a0b09fb26fb45c39c2f5079c709c7cf98e43afa973f14d88ece7e29c2e3",
+    ikm_r: "26b923eade72941c8a85b09986cdfa3f1296852261adedc52d58d2930269812b",
+    sk_r: "77d114e0212be51cb1d76fa99dd41cfd4d0166b08caa09074430a6c59ef17879",
+    pk_r: "13640af826b722fc04feaa4de2f28fbd5ecc03623b317834e7ff4120dbe73062",
+    enc: "2261299c3f40a9afc133b969a97f05e95be2c514e54f3de26cbe5644ac735b04",
+};
+
+#[test]
+fn test_psk_mode_vector() {
+    let psk = hex(PSK);
+    let psk_id = hex(PSK_ID);
+    let psk = Psk {
+        psk: &psk,
+        psk_id: &psk_id,
+    };
+
+    check_mode(
+        &PSK_SETUP,
+        Some(psk),
+        &[
+            (
+                0,
+                "4a177f9c0d6f15cfdf533fb65bf84aecdc6ab16b8b85b4cf65a370e07fc1d78d28fb073214525276f4a89608ff",
+            ),
+            (
+                1,
+                "5c3cabae2f0b3e124d8d864c116fd8f20f3f56fda988c3573b40b09997fd6c769e77c8eda6cda4f947f5b704a8",
+            ),
+            (
+                2,
+                "14958900b44bdae9cbe5a528bf933c5c990dbb8e282e6e495adf8205d19da9eb270e3a6f1e0613ab7e757962a4",
+            ),
+            (
+                4,
+                "c2a7bc09ddb853cf2effb6e8d058e346f7fe0fb3476528c80db6b698415c5f8c50b68a9a355609e96d2117f8d3",
+            ),
+            (
+                255,
+                "2414d0788e4bc39a59a26d7bd5d78e111c317d44c37bd5a4c2a1235f2ddc2085c487d406490e75210c958724a7",
+            ),
+            (
+                256,
+                "c567ae1c3f0f75abe1dd9e4532b422600ed4a6e5b9484dafb1e43ab9f5fd662b28c00e2e81d3cde955dae7e218",
+            ),
+        ],
+        "ad40e3ae14f21c99bfdebc20ae14ab86f4ca2dc9a4799d200f43a25f99fa78ae",
+    );
+}
+
+#[test]
+fn test_psk_mode_exports() {
+    let psk = hex(PSK);
+    let psk_id = hex(PSK_ID);
+    let psk = Psk {
+        psk: &psk,
+        psk_id: &psk_id,
+    };
+
+    let (_, sender) = setup_sender_with_ikm(
+        &hex(PSK_SETUP.ikm_e),
+        &hex32(PSK_SETUP.pk_r),
+        &hex(INFO),
+        Some(psk),
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        sender.export(b"", 32).unwrap(),
+        hex("813c1bfc516c99076ae0f466671f0ba5ff244a41699f7b2417e4c59d46d39f40")
+    );
+    assert_eq!(
+        sender.export(&[0x00], 32).unwrap(),
+        hex("2745cf3d5bb65c333658732954ee7af49eb895ce77f8022873a62a13c94cb4e1")
+    );
+}
+
+// -------------------------------------------------------
+// RFC 9180 A.2.3 (AUTH MODE)
+// -------------------------------------------------------
+
+#[test]
+fn test_auth_mode_sender_key() {
+    let (pk, sk) = derive_key_pair(&hex(
+        "9d8f94537d5a3ddef71234c0baedfad4ca6861634d0b94c3007fed557ad17df6",
+    ));
+
+    assert_eq!(
+        sk.to_vec(),
+        hex("2def0cb58ffcf83d1062dd085c8aceca7f4c0c3fd05912d847b61f3e54121f05")
+    );
+    assert_eq!(
+        pk.to_vec(),
+        hex("f0f4f9e96c54aeed3f323de8534fffd7e0577e4ce269896716bcb95643c8712b")
+    );
+}
+
+// -------------------------------------------------------
+// RFC 9180 A.2.4 (AUTH-PSK MODE)
+// -------------------------------------------------------
+//
+// Checked on the receiver side, from the vector's skRm, pkSm and enc.
+
+#[test]
+fn test_auth_psk_mode_vector() {
+    let psk = hex(PSK);
+    let psk_id = hex(PSK_ID);
+    let psk = Psk {
+        psk: &psk,
+        psk_id: &psk_id,
+    };
+
+    let enc = hex32("656a2e00dc9990fd189e6e473459392df556e9a2758754a09db3f51179a3fc02");
+    let sk_r = hex32("7b36a42822e75bf3362dfabbe474b3016236408becb83b859a6909e22803cb0c");
+    let pk_s = hex32("3ac5bd4dd66ff9f2740bef0d6ccb66daa77bff7849d7895182b07fb74d087c45");
+    let pt = hex(PT);
+
+    let mut receiver = setup_receiver(&enc, &sk_r, &hex(INFO), Some(psk), Some(&pk_s)).unwrap();
+
+    let cts = [
+        "9aa52e29274fc6172e38a4461361d2342585d3aeec67fb3b721ecd63f059577c7fe886be0ede01456ebc67d597",
+        "59460bacdbe7a920ef2806a74937d5a691d6d5062d7daafcad7db7e4d8c649adffe575c1889c5c2e3a49af8e3e",
+        "5688ff6a03ba26ae936044a5c800f286fb5d1eccdd2a0f268f6ff9773b51169318d1a1466bb36263415071db00",
+    ];
+    for (seq, ct) in cts.iter().enumerate() {
+        let aad = format!("Count-{seq}");
+        assert_eq!(receiver.open(aad.as_bytes(), &hex(ct)).unwrap(), pt);
+    }
+
+    assert_eq!(
+        receiver.export(b"", 32).unwrap(),
+        hex("c23ebd4e7a0ad06a5dddf779f65004ce9481069ce0f0e6dd51a04539ddcbd5cd")
+    );
+    assert_eq!(
+        receiver.export(&[0x00], 32).unwrap(),
+        hex("ed7ff5ca40a3d84561067ebc8e01702bc36cf1eb99d42a92004642b9dfaadd37")
+    );
+    assert_eq!(
+        receiver.export(b"TestContext", 32).unwrap(),
+        hex("d3bae066aa8da27d527d85c040f7dd6ccb60221c902ee36a82f70bcd62a60ee4")
+    );
+}
+
+// -------------------------------------------------------
+// ROUND TRIPS AND FAILURES
+// -------------------------------------------------------
+
+#[test]
+fn test_single_shot_round_trip() {
+    let (pk_r, sk_r) = generate_key_pair();
+
+    let (enc, ct) = seal(&pk_r, b"info", b"aad", b"hello hpke", None, None).unwrap();
+    assert_eq!(ct.len(), 10 + 16);
+
+    let pt = open(&enc, &sk_r, b"info", b"aad", &ct, None, None).unwrap();
+    assert_eq!(pt, b"hello hpke");
+}
+
+#[test]
+fn test_open_rejects_mismatched_inputs() {
+    let (pk_r, sk_r) = generate_key_pair();
+    let (pk_s, sk_s) = generate_key_pair();
+    let (_, other_sk) = generate_key_pair();
+    let psk = Psk {
+        psk: &[7u8; 32],
+        psk_id: b"id",
+    };
+
+    let (enc, ct) = seal(&pk_r, b"info", b"aad", b"msg", Some(psk), Some(&sk_s)).unwrap();
+
+    assert_eq!(
+        open(&enc, &sk_r, b"info", b"aad", &ct, Some(psk), Some(&pk_s)).unwrap(),
+        b"msg"
+    );
+
+    let failures = [
+        open(
+            &enc,
+            &other_sk,
+            b"info",
+            b"aad",
+            &ct,
+            Some(psk),
+            Some(&pk_s),
+        ),
+        open(&enc, &sk_r, b"other", b"aad", &ct, Some(psk), Some(&pk_s)),
+        open(&enc, &sk_r, b"info", b"other", &ct, Some(psk), Some(&pk_s)),
+        open(&enc, &sk_r, b"info", b"aad", &ct, None, Some(&pk_s)),
+        open(&enc, &sk_r, b"info", b"aad", &ct, Some(psk), None),
+        open(&enc, &sk_r, b"info", b"aad", &ct, Some(psk), Some(&pk_r)),
+        open(
+            &enc,
+            &sk_r,
+            b"info",
+            b"aad",
+            &ct[..15],
+            Some(psk),
+            Some(&pk_s),
+        ),
+    ];
+
+    for result in failures {
+        assert_eq!(result, Err(HpkeError::OpenFailed));
+    }
+}
+
+#[test]
+fn test_receiver_does_not_advance_on_failure() {
+    let (pk_r, sk_r) = generate_key_pair();
+    let (enc, mut sender) = setup_sender(&pk_r, b"", None, None).unwrap();
+    let mut receiver = setup_receiver(&enc, &sk_r, b"", None, None).unwrap();
+
+    let mut ct = sender.seal(b"", b"first").unwrap();
+    ct[0] ^= 1;
+    assert_eq!(receiver.open(b"", &ct), Err(HpkeError::OpenFailed));
+    assert_eq!(receiver.sequence_number(), 0);
+
+    ct[0] ^= 1;
+    assert_eq!(receiver.open(b"", &ct).unwrap(), b"first");
+    assert_eq!(receiver.sequence_number(), 1);
+}
+
+#[test]
+fn test_invalid_psk_rejected() {
+    let (pk_r, _) = generate_key_pair();
+
+    let empty_psk = Psk {
+        psk: &[],
+        psk_id: b"id",
+    };
+    let empty_id = Psk {
+        psk: &[1u8; 32],
+        psk_id: &[],
+    };
+
+    for psk in [empty_psk, empty_id] {
+        assert!(matches!(
+            setup_sender(&pk_r, b"", Some(psk), None),
+            Err(HpkeError::InvalidPsk)
+        ));
+    }
+}
+
+#[test]
+fn test_low_order_public_key_rejected() {
+    let (_, sk_r) = generate_key_pair();
+
+    assert!(matches!(
+        setup_sender(&[0u8; 32], b"", None, None),
+        Err(HpkeError::InvalidPublicKey)
+    ));
+
+    let mut one = [0u8; 32];
+    one[0] = 1;
+    assert!(matches!(
+        setup_receiver(&one, &sk_r, b"", None, None),
+        Err(HpkeError::InvalidPublicKey)
+    ));
+}
+
+#[test]
+fn test_export_length_limit() {
+    let (pk_r, _) = generate_key_pair();
+    let (_, sender) = setup_sender(&pk_r, b"", None, None).unwrap();
+
+    assert_eq!(sender.export(b"ctx", 255 * 32).unwrap().len(), 255 * 32);
+    assert_eq!(
+        sender.export(b"ctx", 255 * 32 + 1),
+        Err(HpkeError::ExportTooLong)
+    );
+}
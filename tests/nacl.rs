@@ -0,0 +1,207 @@
+use cryptal::encryption::nacl::NaclError;
+use cryptal::encryption::nacl::crypto_box::{
+    SEAL_OVERHEAD, beforenm, box_seal, box_seal_open, keypair, open, open_afternm, seal,
+    seal_afternm,
+};
+use cryptal::encryption::nacl::secretbox;
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn arr<const N: usize>(s: &str) -> [u8; N] {
+    hex(s).try_into().unwrap()
+}
+
+// -------------------------------------------------------
+// NaCl TEST VECTORS (tests/box.c, tests/secretbox.c)
+// -------------------------------------------------------
+
+const NONCE: &str = "69696ee955b62b73cd62bda875fc73d68219e0036b7a0b37";
+
+const PLAINTEXT: &str = "be075fc53c81f2d5cf141316ebeb0c7b5228c52a4c62cbd44b66849b64244ffce5ecbaaf33bd751a\
+                         1ac728d45e6c61296cdc3c01233561f41db66cce314adb310e3be8250c46f06dceea3a7fa1348057\
+                         e2f6556ad6b1318a024a838f21af1fde048977eb48f59ffd4924ca1c60902e52f0a089bc76897040\
+                         e082f937763848645e0705";
+
+const CIPHERTEXT: &str = "f3ffc7703f9400e52a7dfb4b3d3305d98e993b9f48681273c29650ba32fc76ce48332ea7164d96a4\
+                          476fb8c531a1186ac0dfc17c98dce87b4da7f011ec48c97271d2c20f9b928fe2270d6fb863d51738\
+                          b48eeee314a7cc8ab932164548e526ae90224368517acfeabd6bb3732bc0e9da99832b61ca01b6de\
+                          56244a9e88d5f9b37973f622a43d14a6599b1f654cb45a74e355a5";
+
+const ALICE_SK: &str = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+const ALICE_PK: &str = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+const BOB_SK: &str = "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb";
+const BOB_PK: &str = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+
+const SHARED: &str = "1b27556473e985d462cd51197a9a46c76009549eac6474f206c4ee0844f68389";
+
+#[test]
+fn test_secretbox_vector() {
+    let key = arr(SHARED);
+    let nonce = arr(NONCE);
+
+    let ciphertext = secretbox::seal(&key, &nonce, &hex(PLAINTEXT));
+    assert_eq!(ciphertext, hex(CIPHERTEXT));
+
+    assert_eq!(
+        secretbox::open(&key, &nonce, &ciphertext).unwrap(),
+        hex(PLAINTEXT)
+    );
+}
+
+#[test]
+fn test_beforenm_vector() {
+    let alice = beforenm(&arr(BOB_PK), &arr(ALICE_SK)).unwrap();
+    let bob = beforenm(&arr(ALICE_PK), &arr(BOB_SK)).unwrap();
+
+    assert_eq!(alice.to_vec(), hex(SHARED));
+    assert_eq!(bob.to_vec(), hex(SHARED));
+}
+
+#[test]
+fn test_box_vector() {
+    let nonce = arr(NONCE);
+
+    let ciphertext = seal(&arr(BOB_PK), &arr(ALICE_SK), &nonce, &hex(PLAINTEXT)).unwrap();
+    assert_eq!(ciphertext, hex(CIPHERTEXT));
+
+    assert_eq!(
+        open(&arr(ALICE_PK), &arr(BOB_SK), &nonce, &ciphertext).unwrap(),
+        hex(PLAINTEXT)
+    );
+
+    // Precomputed key gives the same result.
+    let shared = beforenm(&arr(BOB_PK), &arr(ALICE_SK)).unwrap();
+    assert_eq!(
+        seal_afternm(&shared, &nonce, &hex(PLAINTEXT)),
+        hex(CIPHERTEXT)
+    );
+    assert_eq!(
+        open_afternm(&shared, &nonce, &hex(CIPHERTEXT)).unwrap(),
+        hex(PLAINTEXT)
+    );
+}
+
+// -------------------------------------------------------
+// SODIUM COMPAT VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn test_box_sodium_compat_vector() {
+    let alice_sk = arr("68f208412d8dd5db9d0c6d18512e86f0ec75665ab841372d57b042b27ef89d4c");
+    let alice_pk = arr("ac3a70ba35df3c3fae427a7c72021d68f2c1e044040b75f17313c0c8b5d4241d");
+    let bob_sk = arr("b581fb5ae182a16f603f39270d4e3b95bc008310b727a11dd4e784a0044d461b");
+    let bob_pk = arr("e8980c86e032f1eb2975052e8d65bddd15c3b59641174ec9678a53789d92c754");
+
+    let expected = hex(
+        "c03f27d188ef650cd12936913137bb17ed4c98c2648939e2e1d2e855470a7b8c632cabfd5ab3b3c2\
+         d313dc8c9ecf5da173e1f9c318cdef1dced6d2519e695085e6b5c401a2bd5331442986c7076d4126\
+         25497c4cb2fd94c6f103961033b2c930d7e82e0341f29d3879bd6ab9d881ea3a1f365d634e653c6e\
+         171aac7fc1e76934d23be6f04a54010808dbf0f9bd30f63b68d026",
+    );
+
+    let nonce = arr(NONCE);
+
+    assert_eq!(
+        seal(&bob_pk, &alice_sk, &nonce, &hex(PLAINTEXT)).unwrap(),
+        expected
+    );
+    assert_eq!(
+        open(&alice_pk, &bob_sk, &nonce, &expected).unwrap(),
+        hex(PLAINTEXT)
+    );
+}
+
+#[test]
+fn test_box_seal_open_vector() {
+    let sk = arr("15b36cb00213373fb3fb03958fb0cc0012ecaca112fd249d3cf0961e311caac9");
+    let pk = arr("fb4cb34f74a928b79123333c1e63d991060244cda98affee14c3398c6d315574");
+
+    let sealed = hex(
+        "95eb5bf05ada25ee51f4158201c261a00bfb1955a9176c8c7f1a62f299a32e54f6ebccc8ab9d2ce1\
+         b1d3710ba37d8db17aeeec0b78fc3d32b39b79ed96f18948c5a574b8e3f8eccc2f132408c21646f3\
+         aedae4a67fde4f77153b5458b8a6bd712dd8365534c567ec",
+    );
+
+    assert_eq!(
+        box_seal_open(&pk, &sk, &sealed).unwrap(),
+        b"Lorem ipsum dolor sit amet, consectetur adipiscing elit."
+    );
+}
+
+// -------------------------------------------------------
+// ROUND TRIPS AND FAILURES
+// -------------------------------------------------------
+
+#[test]
+fn test_box_seal_round_trip() {
+    let (pk, sk) = keypair();
+
+    for len in [0, 1, 63, 64, 65, 1000] {
+        let plaintext = vec![0xa5; len];
+        let sealed = box_seal(&pk, &plaintext).unwrap();
+
+        assert_eq!(sealed.len(), SEAL_OVERHEAD + len);
+        assert_eq!(box_seal_open(&pk, &sk, &sealed).unwrap(), plaintext);
+    }
+
+    // Fresh ephemeral key every time.
+    assert_ne!(box_seal(&pk, b"x").unwrap(), box_seal(&pk, b"x").unwrap());
+
+    let (other_pk, other_sk) = keypair();
+    let sealed = box_seal(&pk, b"secret").unwrap();
+    assert_eq!(
+        box_seal_open(&other_pk, &other_sk, &sealed),
+        Err(NaclError::AuthenticationFailed)
+    );
+    assert_eq!(
+        box_seal_open(&pk, &sk, &sealed[..SEAL_OVERHEAD - 1]),
+        Err(NaclError::InvalidLength)
+    );
+}
+
+#[test]
+fn test_tampering_detected() {
+    let key = arr(SHARED);
+    let nonce = arr(NONCE);
+    let ciphertext = hex(CIPHERTEXT);
+
+    for i in [0, 15, 16, ciphertext.len() - 1] {
+        let mut bad = ciphertext.clone();
+        bad[i] ^= 0x01;
+
+        assert_eq!(
+            secretbox::open(&key, &nonce, &bad),
+            Err(NaclError::AuthenticationFailed)
+        );
+    }
+
+    let mut bad_nonce = nonce;
+    bad_nonce[23] ^= 1;
+    assert_eq!(
+        secretbox::open(&key, &bad_nonce, &ciphertext),
+        Err(NaclError::AuthenticationFailed)
+    );
+
+    assert_eq!(
+        secretbox::open(&key, &nonce, &ciphertext[..15]),
+        Err(NaclError::InvalidLength)
+    );
+
+    // Empty message: tag only.
+    let empty = secretbox::seal(&key, &nonce, b"");
+    assert_eq!(empty.len(), secretbox::MAC_LEN);
+    assert_eq!(secretbox::open(&key, &nonce, &empty).unwrap(), b"");
+}
+
+#[test]
+fn test_low_order_public_key_rejected() {
+    let (_, sk) = keypair();
+
+    assert_eq!(beforenm(&[0u8; 32], &sk), Err(NaclError::InvalidPublicKey));
+    assert_eq!(box_seal(&[0u8; 32], b"x"), Err(NaclError::InvalidPublicKey));
+}
@@ -0,0 +1,229 @@
+use cryptal::encryption::chacha20poly1305::decrypt_with_aad;
+use cryptal::encryption::nonce::{
+    DEFAULT_LIMIT, NONCE_LEN, NonceError, NonceSequence, PREFIX_LEN, STATE_LEN, SealingKey, open,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn key() -> [u8; 32] {
+    hex("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f")
+        .try_into()
+        .unwrap()
+}
+
+// -------------------------------------------------------
+// NONCE SEQUENCE
+// -------------------------------------------------------
+
+#[test]
+fn test_nonces_are_prefixed_counters() {
+    let mut seq = NonceSequence::new();
+    assert_eq!(seq.remaining(), DEFAULT_LIMIT);
+
+    let first = seq.advance().unwrap();
+    let second = seq.advance().unwrap();
+
+    assert_eq!(first[..PREFIX_LEN], second[..PREFIX_LEN]);
+    assert_eq!(first[PREFIX_LEN..], [0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(second[PREFIX_LEN..], [0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(seq.remaining(), DEFAULT_LIMIT - 2);
+}
+
+#[test]
+fn test_prefix_is_random_per_sequence() {
+    let a = NonceSequence::new().advance().unwrap();
+    let b = NonceSequence::new().advance().unwrap();
+
+    // 2^-32 chance of a false failure.
+    assert_ne!(a[..PREFIX_LEN], b[..PREFIX_LEN]);
+}
+
+#[test]
+fn test_limit_is_enforced() {
+    let mut seq = NonceSequence::with_limit(3);
+
+    for _ in 0..3 {
+        seq.advance().unwrap();
+    }
+
+    assert_eq!(seq.remaining(), 0);
+    assert_eq!(seq.advance(), Err(NonceError::Exhausted));
+    assert_eq!(seq.advance(), Err(NonceError::Exhausted));
+    assert_eq!(seq.reserve(1), Err(NonceError::Exhausted));
+}
+
+#[test]
+fn test_no_wrap_around_at_counter_max() {
+    let mut seq = NonceSequence::with_limit(u64::MAX);
+    let state = seq.reserve(u64::MAX).unwrap();
+
+    // Resume two nonces before the end of the counter space.
+    let mut state = state.to_vec();
+    state[1 + PREFIX_LEN..13].copy_from_slice(&(u64::MAX - 2).to_be_bytes());
+
+    let mut seq = NonceSequence::from_bytes(&state).unwrap();
+    seq.reserve(10).unwrap();
+
+    assert_eq!(
+        seq.advance().unwrap()[PREFIX_LEN..],
+        (u64::MAX - 2).to_be_bytes()
+    );
+    assert_eq!(
+        seq.advance().unwrap()[PREFIX_LEN..],
+        (u64::MAX - 1).to_be_bytes()
+    );
+    assert_eq!(seq.advance(), Err(NonceError::Exhausted));
+}
+
+// -------------------------------------------------------
+// PERSISTENCE
+// -------------------------------------------------------
+
+#[test]
+fn test_restored_sequence_never_repeats() {
+    let mut seq = NonceSequence::with_limit(1000);
+    let state = seq.reserve(10).unwrap();
+    assert_eq!(state.len(), STATE_LEN);
+
+    let mut used = Vec::new();
+    for _ in 0..4 {
+        used.push(seq.advance().unwrap());
+    }
+
+    // Crash: restart from the stored state.
+    let mut restored = NonceSequence::from_bytes(&state).unwrap();
+    assert_eq!(restored.advance(), Err(NonceError::NotReserved));
+
+    restored.reserve(10).unwrap();
+    let next = restored.advance().unwrap();
+
+    assert!(!used.contains(&next));
+    assert_eq!(next[..PREFIX_LEN], used[0][..PREFIX_LEN]);
+    assert_eq!(next[PREFIX_LEN..], 10u64.to_be_bytes());
+    assert_eq!(restored.remaining(), 1000 - 11);
+}
+
+#[test]
+fn test_reservation_is_enforced() {
+    let mut seq = NonceSequence::from_bytes(&NonceSequence::new().reserve(0).unwrap()).unwrap();
+
+    assert_eq!(seq.advance(), Err(NonceError::NotReserved));
+
+    seq.reserve(2).unwrap();
+    seq.advance().unwrap();
+    seq.advance().unwrap();
+    assert_eq!(seq.advance(), Err(NonceError::NotReserved));
+
+    // Reserving again continues where the sequence left off.
+    seq.reserve(1).unwrap();
+    assert_eq!(seq.advance().unwrap()[PREFIX_LEN..], 2u64.to_be_bytes());
+}
+
+#[test]
+fn test_invalid_state() {
+    let state = NonceSequence::with_limit(5).reserve(3).unwrap();
+
+    assert_eq!(
+        NonceSequence::from_bytes(&state[..STATE_LEN - 1]),
+        Err(NonceError::InvalidState)
+    );
+
+    let mut bad = state;
+    bad[0] = 2;
+    assert_eq!(
+        NonceSequence::from_bytes(&bad),
+        Err(NonceError::InvalidState)
+    );
+
+    // Counter above the limit.
+    let mut bad = state;
+    bad[12] = 6;
+    assert_eq!(
+        NonceSequence::from_bytes(&bad),
+        Err(NonceError::InvalidState)
+    );
+}
+
+// -------------------------------------------------------
+// SEALING KEY
+// -------------------------------------------------------
+
+#[test]
+fn test_sealing_key_round_trip() {
+    let mut sealing = SealingKey::new(&key());
+
+    let a = sealing.seal(b"aad", b"first message").unwrap();
+    let b = sealing.seal(b"aad", b"first message").unwrap();
+
+    assert_ne!(a[..NONCE_LEN], b[..NONCE_LEN]);
+    assert_ne!(a, b);
+
+    assert_eq!(open(&key(), b"aad", &a).unwrap(), b"first message");
+    assert_eq!(open(&key(), b"aad", &b).unwrap(), b"first message");
+}
+
+#[test]
+fn test_sealed_format_is_chacha20poly1305() {
+    let mut sealing = SealingKey::new(&key());
+    let sealed = sealing.seal(b"", b"plain").unwrap();
+
+    let (nonce, rest) = sealed.split_at(NONCE_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - 16);
+
+    let mut plaintext = vec![0u8; ciphertext.len()];
+    decrypt_with_aad(
+        &key(),
+        nonce.try_into().unwrap(),
+        b"",
+        ciphertext,
+        tag.try_into().unwrap(),
+        &mut plaintext,
+    )
+    .unwrap();
+
+    assert_eq!(plaintext, b"plain");
+}
+
+#[test]
+fn test_sealing_key_limit_and_reservation() {
+    let mut sealing = SealingKey::with_sequence(&key(), NonceSequence::with_limit(2));
+
+    sealing.seal(b"", b"1").unwrap();
+    sealing.seal(b"", b"2").unwrap();
+    assert_eq!(sealing.seal(b"", b"3"), Err(NonceError::Exhausted));
+
+    let state = NonceSequence::with_limit(100).reserve(1).unwrap();
+    let mut sealing = SealingKey::with_sequence(&key(), NonceSequence::from_bytes(&state).unwrap());
+
+    assert_eq!(sealing.seal(b"", b"x"), Err(NonceError::NotReserved));
+    sealing.sequence().reserve(5).unwrap();
+    assert!(sealing.seal(b"", b"x").is_ok());
+}
+
+#[test]
+fn test_open_failures() {
+    let mut sealing = SealingKey::new(&key());
+    let sealed = sealing.seal(b"aad", b"message").unwrap();
+
+    assert_eq!(
+        open(&key(), b"other", &sealed),
+        Err(NonceError::AuthenticationFailed)
+    );
+
+    let mut bad = sealed.clone();
+    bad[0] ^= 1;
+    assert_eq!(
+        open(&key(), b"aad", &bad),
+        Err(NonceError::AuthenticationFailed)
+    );
+
+    assert_eq!(
+        open(&key(), b"aad", &sealed[..NONCE_LEN + 15]),
+        Err(NonceError::Truncated)
+    );
+}
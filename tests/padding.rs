@@ -0,0 +1,150 @@
+use cryptal::encryption::padding::{Padding, PaddingError, pad, padded_len, unpad, unpadded_len};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// -------------------------------------------------------
+// PADDED SIZES
+// -------------------------------------------------------
+
+#[test]
+fn test_padme_sizes() {
+    // (data length, padded length); the padded length covers len + 1.
+    let cases = [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (8, 10),
+        (16, 18),
+        (99, 104),
+        (999, 1024),
+        (1024, 1088),
+        (4096, 4352),
+        (65536, 67584),
+        (999_999, 1_015_808),
+    ];
+
+    for (len, expected) in cases {
+        assert_eq!(padded_len(len, Padding::Padme).unwrap(), expected, "{len}");
+    }
+}
+
+#[test]
+fn test_padme_overhead_is_bounded() {
+    for len in (0..200_000).step_by(97) {
+        let size = padded_len(len, Padding::Padme).unwrap();
+
+        assert!(size > len);
+        assert!((size - len - 1) * 100 <= (len + 1) * 12, "{len} -> {size}");
+    }
+}
+
+#[test]
+fn test_power_of_two_sizes() {
+    let cases = [
+        (0, 1),
+        (1, 2),
+        (3, 4),
+        (4, 8),
+        (1000, 1024),
+        (1023, 1024),
+        (1024, 2048),
+    ];
+
+    for (len, expected) in cases {
+        assert_eq!(padded_len(len, Padding::PowerOfTwo).unwrap(), expected);
+    }
+
+    assert_eq!(
+        padded_len(usize::MAX / 2 + 1, Padding::PowerOfTwo),
+        Err(PaddingError::TooLong)
+    );
+    assert_eq!(
+        padded_len(usize::MAX, Padding::PowerOfTwo),
+        Err(PaddingError::TooLong)
+    );
+}
+
+#[test]
+fn test_bucket_sizes() {
+    let buckets = Padding::Buckets(&[256, 1024, 4096]);
+
+    assert_eq!(padded_len(0, buckets).unwrap(), 256);
+    assert_eq!(padded_len(255, buckets).unwrap(), 256);
+    assert_eq!(padded_len(256, buckets).unwrap(), 1024);
+    assert_eq!(padded_len(4095, buckets).unwrap(), 4096);
+    assert_eq!(padded_len(4096, buckets), Err(PaddingError::TooLong));
+
+    assert_eq!(
+        padded_len(1, Padding::Buckets(&[])),
+        Err(PaddingError::InvalidBuckets)
+    );
+    assert_eq!(
+        padded_len(1, Padding::Buckets(&[64, 64])),
+        Err(PaddingError::InvalidBuckets)
+    );
+    assert_eq!(
+        padded_len(1, Padding::Buckets(&[128, 64])),
+        Err(PaddingError::InvalidBuckets)
+    );
+}
+
+// -------------------------------------------------------
+// PAD / UNPAD
+// -------------------------------------------------------
+
+#[test]
+fn test_iso7816_layout() {
+    assert_eq!(
+        pad(&hex("0102"), Padding::Buckets(&[8])).unwrap(),
+        hex("0102800000000000")
+    );
+    assert_eq!(pad(&[], Padding::PowerOfTwo).unwrap(), hex("80"));
+
+    // Data that itself ends with the marker is still unambiguous.
+    let padded = pad(&hex("8080"), Padding::PowerOfTwo).unwrap();
+    assert_eq!(padded, hex("80808000"));
+    assert_eq!(unpad(&padded).unwrap(), hex("8080"));
+}
+
+#[test]
+fn test_round_trip_all_schemes() {
+    let schemes = [
+        Padding::Padme,
+        Padding::PowerOfTwo,
+        Padding::Buckets(&[16, 100, 5000]),
+    ];
+
+    for scheme in schemes {
+        for len in [0, 1, 15, 16, 17, 99, 1000, 4999] {
+            let data: Vec<u8> = (0..len).map(|i| (i % 256) as u8).collect();
+            let padded = pad(&data, scheme).unwrap();
+
+            assert_eq!(padded.len(), padded_len(len, scheme).unwrap());
+            assert_eq!(unpadded_len(&padded).unwrap(), len);
+            assert_eq!(unpad(&padded).unwrap(), data);
+        }
+    }
+}
+
+#[test]
+fn test_invalid_padding() {
+    let cases = [
+        "", "00", "000000", "0102", "010280ff", "01028001", "80000001",
+    ];
+
+    for case in cases {
+        assert_eq!(
+            unpad(&hex(case)),
+            Err(PaddingError::InvalidPadding),
+            "{case}"
+        );
+    }
+
+    // The last marker wins, with zeros after it.
+    assert_eq!(unpad(&hex("80800000")).unwrap(), hex("80"));
+}
@@ -0,0 +1,214 @@
+use cryptal::derivation::Argon2Params;
+use cryptal::encryption::passphrase::{
+    HEADER_LEN, PassphraseError, PassphraseLimits, TAG_LEN, decrypt, decrypt_with_limits, encrypt,
+    read_params, reencrypt,
+};
+
+/// Cheap parameters to keep the tests fast.
+fn params() -> Argon2Params {
+    Argon2Params {
+        mem_kib: 32,
+        time: 1,
+        lanes: 1,
+        tag_len: 32,
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for len in [0, 1, 64, 1000] {
+        let plaintext = vec![0x5au8; len];
+        let file = encrypt(b"correct horse", &plaintext, &params()).unwrap();
+
+        assert_eq!(file.len(), HEADER_LEN + len + TAG_LEN);
+        assert_eq!(decrypt(b"correct horse", &file).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn test_salt_and_nonce_are_fresh() {
+    let a = encrypt(b"pw", b"vault", &params()).unwrap();
+    let b = encrypt(b"pw", b"vault", &params()).unwrap();
+
+    assert_ne!(a[17..HEADER_LEN], b[17..HEADER_LEN]);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_params_are_stored() {
+    let params = Argon2Params {
+        mem_kib: 64,
+        time: 2,
+        lanes: 2,
+        tag_len: 64,
+    };
+    let file = encrypt(b"pw", b"vault", &params).unwrap();
+    let stored = read_params(&file).unwrap();
+
+    assert_eq!(stored.mem_kib, 64);
+    assert_eq!(stored.time, 2);
+    assert_eq!(stored.lanes, 2);
+    assert_eq!(stored.tag_len, 32);
+    assert_eq!(decrypt(b"pw", &file).unwrap(), b"vault");
+}
+
+#[test]
+fn test_wrong_passphrase() {
+    let file = encrypt(b"pw", b"vault", &params()).unwrap();
+
+    assert_eq!(
+        decrypt(b"wrong", &file),
+        Err(PassphraseError::AuthenticationFailed)
+    );
+}
+
+#[test]
+fn test_header_and_payload_are_authenticated() {
+    let file = encrypt(b"pw", b"vault", &params()).unwrap();
+
+    // Salt, nonce, ciphertext and tag.
+    for i in 17..file.len() {
+        let mut bad = file.clone();
+        bad[i] ^= 0x01;
+        assert_eq!(
+            decrypt(b"pw", &bad),
+            Err(PassphraseError::AuthenticationFailed),
+            "byte {i}"
+        );
+    }
+
+    // A still-valid but different cost parameter.
+    let mut bad = file.clone();
+    bad[12] = 2;
+    assert_eq!(
+        decrypt(b"pw", &bad),
+        Err(PassphraseError::AuthenticationFailed)
+    );
+}
+
+#[test]
+fn test_malformed_files() {
+    let file = encrypt(b"pw", b"vault", &params()).unwrap();
+
+    assert_eq!(
+        decrypt(b"pw", &file[..HEADER_LEN + TAG_LEN - 1]),
+        Err(PassphraseError::Truncated)
+    );
+
+    let mut bad = file.clone();
+    bad[0] = b'X';
+    assert_eq!(decrypt(b"pw", &bad), Err(PassphraseError::InvalidMagic));
+
+    let mut bad = file.clone();
+    bad[4] = 2;
+    assert_eq!(
+        decrypt(b"pw", &bad),
+        Err(PassphraseError::UnsupportedVersion)
+    );
+
+    // Zero lanes.
+    let mut bad = file.clone();
+    bad[13..17].copy_from_slice(&0u32.to_be_bytes());
+    assert_eq!(decrypt(b"pw", &bad), Err(PassphraseError::InvalidParams));
+}
+
+#[test]
+fn test_hostile_params_rejected_before_derivation() {
+    let file = encrypt(b"pw", b"vault", &params()).unwrap();
+
+    // 4 TiB of memory: would abort the process if Argon2id ran.
+    let mut bad = file.clone();
+    bad[5..9].copy_from_slice(&0xffff_fff0u32.to_be_bytes());
+    assert_eq!(
+        decrypt(b"pw", &bad),
+        Err(PassphraseError::ParamsTooExpensive)
+    );
+
+    let mut bad = file.clone();
+    bad[9..13].copy_from_slice(&1_000_000u32.to_be_bytes());
+    assert_eq!(
+        decrypt(b"pw", &bad),
+        Err(PassphraseError::ParamsTooExpensive)
+    );
+
+    let strict = PassphraseLimits {
+        max_mem_kib: 16,
+        max_time: 1,
+        max_lanes: 1,
+    };
+    assert_eq!(
+        decrypt_with_limits(b"pw", &file, &strict),
+        Err(PassphraseError::ParamsTooExpensive)
+    );
+}
+
+#[test]
+fn test_huge_lane_count_does_not_overflow() {
+    let file = encrypt(b"pw", b"vault", &params()).unwrap();
+
+    let mut bad = file[..HEADER_LEN + TAG_LEN].to_vec();
+    bad[13..17].copy_from_slice(&0x4000_0000u32.to_be_bytes());
+
+    assert_eq!(
+        read_params(&bad).err(),
+        Some(PassphraseError::InvalidParams)
+    );
+    assert_eq!(
+        decrypt(b"pw", &bad),
+        Err(PassphraseError::ParamsTooExpensive)
+    );
+
+    let unlimited = PassphraseLimits {
+        max_mem_kib: u32::MAX,
+        max_time: u32::MAX,
+        max_lanes: u32::MAX,
+    };
+    assert_eq!(
+        decrypt_with_limits(b"pw", &bad, &unlimited),
+        Err(PassphraseError::InvalidParams)
+    );
+}
+
+#[test]
+fn test_invalid_encryption_params() {
+    let bad = Argon2Params {
+        mem_kib: 32,
+        time: 0,
+        lanes: 1,
+        tag_len: 32,
+    };
+
+    assert_eq!(
+        encrypt(b"pw", b"vault", &bad),
+        Err(PassphraseError::InvalidParams)
+    );
+}
+
+#[test]
+fn test_reencrypt_with_new_params_and_passphrase() {
+    let file = encrypt(b"old", b"vault", &params()).unwrap();
+
+    let stronger = Argon2Params {
+        mem_kib: 64,
+        time: 2,
+        lanes: 1,
+        tag_len: 32,
+    };
+    let limits = PassphraseLimits::default();
+
+    let upgraded = reencrypt(b"old", b"old", &file, &stronger, &limits).unwrap();
+    assert_eq!(read_params(&upgraded).unwrap().mem_kib, 64);
+    assert_eq!(decrypt(b"old", &upgraded).unwrap(), b"vault");
+
+    let rekeyed = reencrypt(b"old", b"new", &upgraded, &params(), &limits).unwrap();
+    assert_eq!(decrypt(b"new", &rekeyed).unwrap(), b"vault");
+    assert_eq!(
+        decrypt(b"old", &rekeyed),
+        Err(PassphraseError::AuthenticationFailed)
+    );
+
+    assert_eq!(
+        reencrypt(b"wrong", b"new", &file, &stronger, &limits),
+        Err(PassphraseError::AuthenticationFailed)
+    );
+}
@@ -0,0 +1,86 @@
+use cryptal::derivation::{ScryptError, ScryptParams, scrypt};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn params(log_n: u8, r: u32, p: u32, len: usize) -> ScryptParams {
+    ScryptParams { log_n, r, p, len }
+}
+
+// -------------------------------------------------------
+// RFC 7914 SECTION 12 VECTORS
+// -------------------------------------------------------
+
+#[test]
+fn test_rfc7914_empty() {
+    assert_eq!(
+        scrypt(b"", b"", &params(4, 1, 1, 64)).unwrap(),
+        hex(
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+             fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        )
+    );
+}
+
+#[test]
+fn test_rfc7914_password_nacl() {
+    assert_eq!(
+        scrypt(b"password", b"NaCl", &params(10, 8, 16, 64)).unwrap(),
+        hex(
+            "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162\
+             2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+        )
+    );
+}
+
+#[test]
+fn test_rfc7914_pleaseletmein() {
+    assert_eq!(
+        scrypt(b"pleaseletmein", b"SodiumChloride", &params(14, 8, 1, 64)).unwrap(),
+        hex(
+            "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2\
+             d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"
+        )
+    );
+}
+
+// -------------------------------------------------------
+// PARAMETERS
+// -------------------------------------------------------
+
+#[test]
+fn test_odd_block_size_and_short_output() {
+    // Cross-checked with Python's hashlib.scrypt.
+    assert_eq!(
+        scrypt(b"pw", b"salt", &params(1, 3, 2, 40)).unwrap(),
+        hex("eeed251bab645a8b6fa5930d4e6ef65b6aa90c243cccc3edd030fc4ca240f32d4fbed3a315f3d966")
+    );
+}
+
+#[test]
+fn test_invalid_params() {
+    let invalid = [
+        params(0, 8, 1, 32),
+        params(10, 0, 1, 32),
+        params(10, 8, 0, 32),
+        params(10, 8, 1, 0),
+        // N must be below 2^(16 · r).
+        params(16, 1, 1, 32),
+        // r · p must be below 2^30.
+        params(1, 1 << 15, 1 << 15, 32),
+        // Table larger than the address space.
+        params(63, 8, 1, 32),
+    ];
+
+    for p in invalid {
+        assert_eq!(
+            scrypt(b"pw", b"salt", &p),
+            Err(ScryptError::InvalidParams),
+            "{p:?}"
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use cryptal::keys::ed25519::{PublicKey, SigningKey};
+use cryptal::keys::vrf::{Proof, Suite, VrfError, proof_to_hash, prove, verify};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn hex32(s: &str) -> [u8; 32] {
+    hex(s).try_into().unwrap()
+}
+
+fn hex64(s: &str) -> [u8; 64] {
+    hex(s).try_into().unwrap()
+}
+
+fn hex80(s: &str) -> [u8; 80] {
+    hex(s).try_into().unwrap()
+}
+
+struct Vector {
+    sk: &'static str,
+    pk: &'static str,
+    alpha: &'static str,
+    pi: &'static str,
+    beta: &'static str,
+}
+
+fn check(suite: Suite, vectors: &[Vector]) {
+    for v in vectors {
+        let key = SigningKey::from_seed(&hex32(v.sk));
+        assert_eq!(key.public_key().to_bytes(), hex32(v.pk));
+
+        let alpha = hex(v.alpha);
+        let proof = prove(suite, &alpha, key.private_key());
+        assert_eq!(proof.to_bytes(), hex80(v.pi));
+
+        let beta = hex64(v.beta);
+        assert_eq!(proof_to_hash(suite, &proof), Ok(beta));
+        assert_eq!(verify(suite, key.public_key(), &proof, &alpha), Ok(beta));
+    }
+}
+
+// -------------------------------------------------------
+// RFC 9381 APPENDIX B.3: ECVRF-EDWARDS25519-SHA512-TAI
+// -------------------------------------------------------
+
+const TAI: [Vector; 3] = [
+    Vector {
+        sk: "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        pk: "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        alpha: "",
+        pi: "8657106690b5526245a92b003bb079ccd1a92130477671f6fc01ad16f26f723f\
+             26f8a57ccaed74ee1b190bed1f479d9727d2d0f9b005a6e456a35d4fb0daab12\
+             68a1b0db10836d9826a528ca76567805",
+        beta: "90cf1df3b703cce59e2a35b925d411164068269d7b2d29f3301c03dd757876ff\
+               66b71dda49d2de59d03450451af026798e8f81cd2e333de5cdf4f3e140fdd8ae",
+    },
+    Vector {
+        sk: "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        pk: "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        alpha: "72",
+        pi: "f3141cd382dc42909d19ec5110469e4feae18300e94f304590abdced48aed593\
+             3bf0864a62558b3ed7f2fea45c92a465301b3bbf5e3e54ddf2d935be3b67926d\
+             a3ef39226bbc355bdc9850112c8f4b02",
+        beta: "eb4440665d3891d668e7e0fcaf587f1b4bd7fbfe99d0eb2211ccec90496310eb\
+               5e33821bc613efb94db5e5b54c70a848a0bef4553a41befc57663b56373a5031",
+    },
+    Vector {
+        sk: "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+        pk: "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        alpha: "af82",
+        pi: "9bc0f79119cc5604bf02d23b4caede71393cedfbb191434dd016d30177ccbf80\
+             96bb474e53895c362d8628ee9f9ea3c0e52c7a5c691b6c18c9979866568add7a\
+             2d41b00b05081ed0f58ee5e31b3a970e",
+        beta: "645427e5d00c62a23fb703732fa5d892940935942101e456ecca7bb217c61c45\
+               2118fec1219202a0edcf038bb6373241578be7217ba85a2687f7a0310b2df19f",
+    },
+];
+
+#[test]
+fn test_vrf_tai_rfc9381_vectors() {
+    check(Suite::Tai, &TAI);
+}
+
+// -------------------------------------------------------
+// RFC 9381 APPENDIX B.4: ECVRF-EDWARDS25519-SHA512-ELL2
+// -------------------------------------------------------
+
+const ELL2: [Vector; 3] = [
+    Vector {
+        sk: "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        pk: "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        alpha: "",
+        pi: "7d9c633ffeee27349264cf5c667579fc583b4bda63ab71d001f89c10003ab46f\
+             14adf9a3cd8b8412d9038531e865c341cafa73589b023d14311c331a9ad15ff2\
+             fb37831e00f0acaa6d73bc9997b06501",
+        beta: "9d574bf9b8302ec0fc1e21c3ec5368269527b87b462ce36dab2d14ccf80c53cc\
+               cf6758f058c5b1c856b116388152bbe509ee3b9ecfe63d93c3b4346c1fbc6c54",
+    },
+    Vector {
+        sk: "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        pk: "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        alpha: "72",
+        pi: "47b327393ff2dd81336f8a2ef10339112401253b3c714eeda879f12c509072ef\
+             055b48372bb82efbdce8e10c8cb9a2f9d60e93908f93df1623ad78a86a028d6b\
+             c064dbfc75a6a57379ef855dc6733801",
+        beta: "38561d6b77b71d30eb97a062168ae12b667ce5c28caccdf76bc88e093e463598\
+               7cd96814ce55b4689b3dd2947f80e59aac7b7675f8083865b46c89b2ce9cc735",
+    },
+    Vector {
+        sk: "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+        pk: "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+        alpha: "af82",
+        pi: "926e895d308f5e328e7aa159c06eddbe56d06846abf5d98c2512235eaa57fdce\
+             35b46edfc655bc828d44ad09d1150f31374e7ef73027e14760d42e77341fe054\
+             67bb286cc2c9d7fde29120a0b2320d04",
+        beta: "121b7f9b9aaaa29099fc04a94ba52784d44eac976dd1a3cca458733be5cd090a\
+               7b5fbd148444f17f8daf1fb55cb04b1ae85a626e30a54b4b0f8abf4a43314a58",
+    },
+];
+
+#[test]
+fn test_vrf_ell2_rfc9381_vectors() {
+    check(Suite::Ell2, &ELL2);
+}
+
+// -------------------------------------------------------
+// REJECTION
+// -------------------------------------------------------
+
+#[test]
+fn test_vrf_rejects_wrong_input_key_or_suite() {
+    let key = SigningKey::generate();
+    let other = SigningKey::generate();
+
+    for suite in [Suite::Tai, Suite::Ell2] {
+        let proof = prove(suite, b"input", key.private_key());
+        assert!(verify(suite, key.public_key(), &proof, b"input").is_ok());
+
+        assert_eq!(
+            verify(suite, key.public_key(), &proof, b"other input"),
+            Err(VrfError::InvalidProof)
+        );
+        assert_eq!(
+            verify(suite, other.public_key(), &proof, b"input"),
+            Err(VrfError::InvalidProof)
+        );
+    }
+
+    let proof = prove(Suite::Tai, b"input", key.private_key());
+    assert_eq!(
+        verify(Suite::Ell2, key.public_key(), &proof, b"input"),
+        Err(VrfError::InvalidProof)
+    );
+}
+
+#[test]
+fn test_vrf_rejects_tampered_proof() {
+    let key = SigningKey::generate();
+    let proof = prove(Suite::Ell2, b"input", key.private_key()).to_bytes();
+
+    for i in [0, 31, 32, 47, 48, 79] {
+        let mut tampered = proof;
+        tampered[i] ^= 0x01;
+
+        assert!(
+            verify(
+                Suite::Ell2,
+                key.public_key(),
+                &Proof::from_bytes(&tampered),
+                b"input"
+            )
+            .is_err()
+        );
+    }
+}
+
+#[test]
+fn test_vrf_rejects_non_canonical_s() {
+    let key = SigningKey::generate();
+    let mut proof = prove(Suite::Tai, b"input", key.private_key()).to_bytes();
+
+    // s + q is below 2²⁵⁴, so there is no final carry.
+    let q = hex32("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+    let mut carry = 0u16;
+    for (s, q) in proof[48..].iter_mut().zip(q) {
+        let sum = *s as u16 + q as u16 + carry;
+        *s = sum as u8;
+        carry = sum >> 8;
+    }
+
+    let proof = Proof::from_bytes(&proof);
+    assert_eq!(
+        verify(Suite::Tai, key.public_key(), &proof, b"input"),
+        Err(VrfError::InvalidProof)
+    );
+    assert_eq!(
+        proof_to_hash(Suite::Tai, &proof),
+        Err(VrfError::InvalidProof)
+    );
+}
+
+#[test]
+fn test_vrf_rejects_small_order_public_key() {
+    let key = SigningKey::generate();
+    let proof = prove(Suite::Tai, b"input", key.private_key());
+
+    // The identity and a point of order 8
+    for pk in [
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a",
+    ] {
+        let public = PublicKey::from_bytes(&hex32(pk)).unwrap();
+        assert_eq!(
+            verify(Suite::Tai, public, &proof, b"input"),
+            Err(VrfError::InvalidPublicKey)
+        );
+    }
+}
+
+#[test]
+fn test_vrf_invalid_gamma() {
+    let mut proof = [0u8; 80];
+    proof[0] = 2; // y = 2 is not on the curve
+
+    assert_eq!(
+        proof_to_hash(Suite::Tai, &Proof::from_bytes(&proof)),
+        Err(VrfError::InvalidProof)
+    );
+}
@@ -1,5 +1,8 @@
 use cryptal::keys::ed25519::generate_keypair;
-use cryptal::keys::x25519::exchange;
+use cryptal::keys::x25519::{
+    BASEPOINT, EphemeralSecret, LOW_ORDER_POINTS, PublicKey, Representative, StaticSecret,
+    X25519Error, checked_exchange, checked_x25519, exchange, x25519, x25519_base,
+};
 
 #[test]
 fn test_x25519_key_exchange() {
@@ -11,3 +14,345 @@ fn test_x25519_key_exchange() {
 
     assert_eq!(alice_shared, bob_shared);
 }
+
+fn hex32(s: &str) -> [u8; 32] {
+    core::array::from_fn(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+}
+
+#[test]
+fn test_x25519_rfc7748_vectors() {
+    let scalar = hex32("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+    let u = hex32("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+    let expected = hex32("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+    assert_eq!(x25519(&scalar, &u), expected);
+
+    let scalar = hex32("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d");
+    let u = hex32("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493");
+    let expected = hex32("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957");
+    assert_eq!(x25519(&scalar, &u), expected);
+}
+
+#[test]
+fn test_x25519_rfc7748_diffie_hellman() {
+    let alice_sk = hex32("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let alice_pk = hex32("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a");
+    let bob_sk = hex32("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+    let bob_pk = hex32("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+    let shared = hex32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+
+    assert_eq!(x25519_base(&alice_sk), alice_pk);
+    assert_eq!(x25519_base(&bob_sk), bob_pk);
+    assert_eq!(x25519(&alice_sk, &bob_pk), shared);
+    assert_eq!(x25519(&bob_sk, &alice_pk), shared);
+}
+
+/// RFC 7748 §6.1 through [`exchange`], which takes Edwards-encoded peer
+/// keys. Guards the ladder's `z3` update, which was once shadowed and
+/// produced wrong shared secrets.
+#[test]
+fn test_x25519_rfc7748_exchange_edwards_keys() {
+    let alice_sk = hex32("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let bob_sk = hex32("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+    let shared = hex32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+
+    // y = (u - 1) / (u + 1) of the RFC public keys
+    let alice_y = hex32("8120f299c37ae1ca64a179f638a6c6fafde968f1c33705e28c413c7579d9884f");
+    let bob_y = hex32("ef4e197de29e38eae689f2f3c2954d14dd70cbcd5a14f8003a12def08174c67a");
+
+    assert_eq!(exchange(&alice_sk, &bob_y), shared);
+    assert_eq!(exchange(&bob_sk, &alice_y), shared);
+}
+
+#[test]
+fn test_x25519_rfc7748_typed_keys() {
+    let alice = StaticSecret::from_bytes(&hex32(
+        "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+    ));
+    let bob = StaticSecret::from_bytes(&hex32(
+        "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb",
+    ));
+
+    let alice_pk = alice.public_key();
+    let bob_pk = bob.public_key();
+    assert_eq!(
+        alice_pk.to_bytes(),
+        hex32("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+    );
+    assert_eq!(
+        bob_pk.to_bytes(),
+        hex32("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+    );
+
+    let shared = hex32("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+    assert_eq!(alice.diffie_hellman(&bob_pk).to_bytes(), shared);
+    assert_eq!(bob.diffie_hellman(&alice_pk).as_bytes(), &shared);
+}
+
+#[test]
+fn test_x25519_static_secret_roundtrip() {
+    let secret = StaticSecret::generate();
+    let restored = StaticSecret::from_bytes(&secret.to_bytes());
+
+    assert_eq!(secret.public_key(), restored.public_key());
+
+    let public = PublicKey::from_bytes(secret.public_key().as_bytes());
+    assert_eq!(public, secret.public_key());
+}
+
+#[test]
+fn test_x25519_ephemeral_exchange() {
+    let alice = EphemeralSecret::generate();
+    let bob = StaticSecret::generate();
+
+    let alice_pk = alice.public_key();
+    let bob_pk = bob.public_key();
+
+    let alice_shared = alice.diffie_hellman(&bob_pk);
+    let bob_shared = bob.diffie_hellman(&alice_pk);
+
+    assert_eq!(alice_shared.to_bytes(), bob_shared.to_bytes());
+}
+
+#[test]
+fn test_x25519_base_matches_ladder() {
+    let mut scalar = [0u8; 32];
+    let mut basepoint = [0u8; 32];
+    basepoint[0] = 9;
+
+    for i in 0..64u8 {
+        scalar.iter_mut().enumerate().for_each(|(j, b)| {
+            *b = b.wrapping_mul(31).wrapping_add(i ^ j as u8);
+        });
+
+        assert_eq!(x25519_base(&scalar), x25519(&scalar, &basepoint));
+    }
+
+    assert_eq!(x25519_base(&[0u8; 32]), x25519(&[0u8; 32], &BASEPOINT));
+    assert_eq!(x25519_base(&[0xff; 32]), x25519(&[0xff; 32], &BASEPOINT));
+}
+
+/// RFC 7748 §5.2: iterate `k, u = x25519(k, u), k` starting from
+/// `k = u = 9`.
+fn iterate(n: usize) -> [u8; 32] {
+    let mut k = BASEPOINT;
+    let mut u = BASEPOINT;
+
+    for _ in 0..n {
+        let next = x25519(&k, &u);
+        u = k;
+        k = next;
+    }
+
+    k
+}
+
+#[test]
+fn test_x25519_rfc7748_iterated() {
+    assert_eq!(
+        iterate(1),
+        hex32("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+    );
+    assert_eq!(
+        iterate(1_000),
+        hex32("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+    );
+}
+
+#[test]
+#[ignore = "slow; run with `cargo test --release -- --ignored`"]
+fn test_x25519_rfc7748_iterated_million() {
+    assert_eq!(
+        iterate(1_000_000),
+        hex32("7c3911e0ab2586fd864497297e575e6f3bc601c0883c30df5f4dd2d24f665424")
+    );
+}
+
+#[test]
+fn test_x25519_low_order_points() {
+    let secret = StaticSecret::generate().to_bytes();
+
+    for point in LOW_ORDER_POINTS {
+        assert_eq!(x25519(&secret, &point), [0u8; 32]);
+        assert_eq!(
+            checked_x25519(&secret, &point).err(),
+            Some(X25519Error::NonContributory)
+        );
+
+        // The top bit is ignored.
+        let mut high = point;
+        high[31] |= 0x80;
+        assert_eq!(
+            checked_x25519(&secret, &high).err(),
+            Some(X25519Error::NonContributory)
+        );
+    }
+}
+
+#[test]
+fn test_x25519_checked_rfc7748() {
+    let scalar = hex32("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+    let u = hex32("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c");
+    let expected = hex32("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552");
+
+    assert_eq!(checked_x25519(&scalar, &u).unwrap().to_bytes(), expected);
+}
+
+#[test]
+fn test_x25519_checked_diffie_hellman() {
+    let alice = StaticSecret::generate();
+    let bob = EphemeralSecret::generate();
+    let bob_pk = bob.public_key();
+
+    let alice_shared = alice.checked_diffie_hellman(&bob_pk).unwrap();
+    let bob_shared = bob.checked_diffie_hellman(&alice.public_key()).unwrap();
+    assert_eq!(alice_shared.to_bytes(), bob_shared.to_bytes());
+
+    for point in LOW_ORDER_POINTS {
+        let peer = PublicKey::from_bytes(&point);
+        assert_eq!(
+            alice.checked_diffie_hellman(&peer).err(),
+            Some(X25519Error::NonContributory)
+        );
+        assert_eq!(
+            EphemeralSecret::generate()
+                .checked_diffie_hellman(&peer)
+                .err(),
+            Some(X25519Error::NonContributory)
+        );
+    }
+}
+
+#[test]
+fn test_x25519_checked_exchange_edwards_identity() {
+    let secret = StaticSecret::generate().to_bytes();
+
+    // Edwards identity (y = 1) maps to u = 0.
+    let mut identity = [0u8; 32];
+    identity[0] = 1;
+
+    assert_eq!(exchange(&secret, &identity), [0u8; 32]);
+    assert_eq!(
+        checked_exchange(&secret, &identity).err(),
+        Some(X25519Error::NonContributory)
+    );
+}
+
+#[test]
+fn test_elligator_representative_decoding() {
+    // Expected u-coordinates were computed with an independent Python
+    // transcription of `map_to_curve_elligator2` (RFC 9380, Section 6.7.1,
+    // A = 486662, Z = 2) over plain integers mod 2²⁵⁵ − 19, after clearing
+    // the two high bits of the representative r:
+    //
+    //     x1 = −A / (1 + 2r²)    (x1 = −A if the denominator is 0)
+    //     u  = x1 if x1³ + A·x1² + x1 is a square, else −x1 − A
+    let vectors = [
+        (
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        ),
+        (
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "9cdb525555555555555555555555555555555555555555555555555555555555",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "80e5132b658f7f451b2b658f7f451b2b658f7f451b2b658f7f451b2b658f7f45",
+        ),
+        (
+            "3f2e1d0c0b0a09080706050403020100f0e1d2c3b4a5968778695a4b3c2d1e3f",
+            "edfd3a44c28b0f80da06db26a394efc37ffe18242f234901606ad6ca8a3d075c",
+        ),
+    ];
+
+    for (representative, public) in vectors {
+        let representative = Representative::from_bytes(&hex32(representative));
+        assert_eq!(representative.to_public_key().to_bytes(), hex32(public));
+    }
+}
+
+#[test]
+fn test_elligator_representative_ignores_high_bits() {
+    let low = hex32("3f2e1d0c0b0a09080706050403020100f0e1d2c3b4a5968778695a4b3c2d1e3f");
+
+    for high in [0x00, 0x40, 0x80, 0xc0] {
+        let mut bytes = low;
+        bytes[31] |= high;
+
+        assert_eq!(
+            Representative::from_bytes(&bytes).to_public_key(),
+            Representative::from_bytes(&low).to_public_key()
+        );
+    }
+}
+
+#[test]
+fn test_elligator_generate_roundtrip() {
+    for _ in 0..16 {
+        let (_, public, representative) = StaticSecret::generate_representable();
+        assert_eq!(representative.to_public_key(), public);
+
+        let (_, public, representative) = EphemeralSecret::generate_representable();
+        assert_eq!(representative.to_public_key(), public);
+    }
+}
+
+#[test]
+fn test_elligator_shared_secret_matches() {
+    for _ in 0..8 {
+        let (alice, _, representative) = StaticSecret::generate_representable();
+        let bob = StaticSecret::generate();
+
+        // Bob only sees the representative.
+        let alice_public = representative.to_public_key();
+
+        assert_eq!(
+            bob.diffie_hellman(&alice_public).to_bytes(),
+            alice.diffie_hellman(&bob.public_key()).to_bytes()
+        );
+        assert_eq!(
+            bob.diffie_hellman(&alice_public).to_bytes(),
+            bob.diffie_hellman(&alice.public_key()).to_bytes()
+        );
+    }
+
+    let (alice, _, representative) = EphemeralSecret::generate_representable();
+    let bob = EphemeralSecret::generate();
+    let bob_public = bob.public_key();
+
+    let bob_shared = bob.diffie_hellman(&representative.to_public_key());
+    let alice_shared = alice.diffie_hellman(&bob_public);
+    assert_eq!(bob_shared.to_bytes(), alice_shared.to_bytes());
+}
+
+#[test]
+fn test_elligator_public_key_has_low_order_component() {
+    let mut with_torsion = 0;
+
+    for _ in 0..64 {
+        let (secret, public, _) = StaticSecret::generate_representable();
+
+        // The low-order component is selected by the three low bits of
+        // the secret; without it, the key is the regular public key.
+        if secret.to_bytes()[0] & 7 == 0 {
+            assert_eq!(public, secret.public_key());
+        } else {
+            assert_ne!(public, secret.public_key());
+            with_torsion += 1;
+        }
+    }
+
+    assert!(with_torsion > 0);
+}
+
+#[test]
+fn test_elligator_high_bits_are_random() {
+    let mut seen = [false; 4];
+
+    for _ in 0..64 {
+        let (_, _, representative) = StaticSecret::generate_representable();
+        seen[(representative.to_bytes()[31] >> 6) as usize] = true;
+    }
+
+    assert_eq!(seen, [true; 4]);
+}